@@ -0,0 +1,345 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+
+use fuser::ReplyData;
+use parking_lot::Mutex;
+
+use ipc::generated::Dimension;
+use ipc::GameEvent;
+
+/// Events are dropped once this many bytes are waiting to be read by a slow reader
+const MAX_BUFFERED: usize = 64 * 1024;
+
+/// Open handles to event stream files
+#[derive(Default)]
+pub struct EventSubscribers {
+    /// Keyed by file handle
+    subscribers: HashMap<u64, Subscriber>,
+}
+
+#[derive(Default)]
+struct Subscriber {
+    /// Only events in this world, or all if None
+    world: Option<Dimension>,
+    /// Rendered events waiting to be read, each ending in a newline
+    lines: VecDeque<Vec<u8>>,
+    buffered: usize,
+    /// Blocked read waiting for the next event
+    parked: Option<ParkedRead<ReplyData>>,
+    /// Kernel poll handle to notify on the next event
+    poll_handle: Option<u64>,
+}
+
+struct ParkedRead<R> {
+    reply: R,
+    size: u32,
+    /// Thread that's blocked on the read
+    pid: u32,
+}
+
+/// Something to do after events have been published
+pub enum Wakeup {
+    Reply(ReplyData, Vec<u8>),
+    Interrupted(ReplyData),
+    Poll(u64),
+}
+
+pub type SharedEvents = Arc<Mutex<EventSubscribers>>;
+
+impl EventSubscribers {
+    pub fn subscribe(&mut self, fh: u64, world: Option<Dimension>) {
+        self.subscribers.insert(
+            fh,
+            Subscriber {
+                world,
+                ..Subscriber::default()
+            },
+        );
+    }
+
+    /// Returns the parked read, if any, which should be given EOF
+    pub fn unsubscribe(&mut self, fh: u64) -> Option<ReplyData> {
+        self.subscribers
+            .remove(&fh)
+            .and_then(|s| s.parked)
+            .map(|parked| parked.reply)
+    }
+
+    pub fn has_subscribers(&self) -> bool {
+        !self.subscribers.is_empty()
+    }
+
+    /// Replies immediately if events are waiting, otherwise the read blocks until the next one, or
+    /// until [interrupt_parked](Self::interrupt_parked) finds its reader has been signalled
+    pub fn read(&mut self, fh: u64, size: u32, pid: u32, reply: ReplyData) {
+        let subscriber = match self.subscribers.get_mut(&fh) {
+            Some(s) => s,
+            None => return reply.error(libc::EBADF),
+        };
+
+        match subscriber.take_data(size) {
+            Some(data) => reply.data(&data),
+            None => {
+                let parked = ParkedRead { reply, size, pid };
+                if let Some(old) = subscriber.parked.replace(parked) {
+                    // shouldn't happen, reads on the same handle are serialised
+                    old.reply.error(libc::EINTR);
+                }
+            }
+        }
+    }
+
+    /// Returns true if events are waiting to be read. If not, the given poll handle is notified on
+    /// the next event
+    pub fn poll(&mut self, fh: u64, poll_handle: Option<u64>) -> bool {
+        match self.subscribers.get_mut(&fh) {
+            Some(s) if !s.lines.is_empty() => true,
+            Some(s) => {
+                if poll_handle.is_some() {
+                    s.poll_handle = poll_handle;
+                }
+                false
+            }
+            None => true,
+        }
+    }
+
+    pub fn publish(&mut self, events: &[GameEvent]) -> Vec<Wakeup> {
+        let mut wakeups = vec![];
+        for subscriber in self.subscribers.values_mut() {
+            let mut any = false;
+            for event in events {
+                any |= subscriber.push(event);
+            }
+
+            if !any {
+                continue;
+            }
+
+            if let Some(parked) = subscriber.parked.take() {
+                let data = subscriber.take_data(parked.size).unwrap_or_default();
+                wakeups.push(Wakeup::Reply(parked.reply, data));
+            }
+
+            if let Some(kh) = subscriber.poll_handle.take() {
+                wakeups.push(Wakeup::Poll(kh));
+            }
+        }
+
+        wakeups
+    }
+
+    /// Releases blocked reads whose reader has a signal waiting, e.g. from Ctrl-C. Others stay
+    /// blocked for as long as it takes for an event to arrive
+    pub fn interrupt_parked(&mut self) -> Vec<Wakeup> {
+        self.subscribers
+            .values_mut()
+            .filter(|s| {
+                s.parked
+                    .as_ref()
+                    .is_some_and(|parked| parked.interrupted(has_pending_signal))
+            })
+            .filter_map(|s| s.parked.take())
+            .map(|parked| Wakeup::Interrupted(parked.reply))
+            .collect()
+    }
+}
+
+impl<R> ParkedRead<R> {
+    fn interrupted(&self, has_pending_signal: impl Fn(u32) -> bool) -> bool {
+        has_pending_signal(self.pid)
+    }
+}
+
+/// The kernel waits for the reply to a read that's already been handed to us, even when the reader
+/// is interrupted, so signals have to be spotted here instead
+fn has_pending_signal(pid: u32) -> bool {
+    std::fs::read_to_string(format!("/proc/{}/status", pid))
+        .map(|status| status_has_pending_signal(&status))
+        .unwrap_or(false)
+}
+
+/// Checks the thread and process pending signal masks against the blocked mask
+fn status_has_pending_signal(status: &str) -> bool {
+    let mask = |name: &str| {
+        status
+            .lines()
+            .find_map(|line| line.strip_prefix(name))
+            .and_then(|hex| u64::from_str_radix(hex.trim(), 16).ok())
+            .unwrap_or(0)
+    };
+
+    (mask("SigPnd:") | mask("ShdPnd:")) & !mask("SigBlk:") != 0
+}
+
+impl Subscriber {
+    /// Returns false if filtered out
+    fn push(&mut self, event: &GameEvent) -> bool {
+        if self.world.is_some() && self.world != event.world {
+            return false;
+        }
+
+        let mut line = event.to_string().into_bytes();
+        line.push(b'\n');
+
+        self.buffered += line.len();
+        self.lines.push_back(line);
+
+        while self.buffered > MAX_BUFFERED {
+            match self.lines.pop_front() {
+                Some(dropped) => self.buffered -= dropped.len(),
+                None => break,
+            }
+        }
+
+        true
+    }
+
+    /// As many whole lines as fit in `size`, or the start of the first line if it doesn't fit at
+    /// all. None if nothing is waiting
+    fn take_data(&mut self, size: u32) -> Option<Vec<u8>> {
+        let size = size as usize;
+        let first = self.lines.front_mut()?;
+        if first.len() > size {
+            let rest = first.split_off(size);
+            let data = std::mem::replace(first, rest);
+            self.buffered -= data.len();
+            return Some(data);
+        }
+
+        let mut data = Vec::new();
+        while let Some(line) = self.lines.front() {
+            if data.len() + line.len() > size {
+                break;
+            }
+
+            data.extend_from_slice(line);
+            self.buffered -= line.len();
+            self.lines.pop_front();
+        }
+
+        Some(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ipc::generated::EventType;
+
+    fn event(ty: EventType, world: Option<Dimension>) -> GameEvent {
+        GameEvent {
+            ty,
+            world,
+            entity: None,
+            block: None,
+            message: None,
+            amount: None,
+        }
+    }
+
+    #[test]
+    fn world_filter() {
+        let mut all = Subscriber::default();
+        let mut nether = Subscriber {
+            world: Some(Dimension::Nether),
+            ..Subscriber::default()
+        };
+
+        for sub in [&mut all, &mut nether] {
+            sub.push(&event(EventType::Death, Some(Dimension::Overworld)));
+            sub.push(&event(EventType::Chat, None));
+            sub.push(&event(EventType::Damage, Some(Dimension::Nether)));
+        }
+
+        assert_eq!(
+            all.take_data(4096).unwrap(),
+            b"death world=overworld\nchat\ndamage world=nether\n"
+        );
+        assert_eq!(nether.take_data(4096).unwrap(), b"damage world=nether\n");
+        assert!(nether.take_data(4096).is_none());
+    }
+
+    #[test]
+    fn whole_lines() {
+        let mut sub = Subscriber::default();
+        sub.push(&event(EventType::Death, None));
+        sub.push(&event(EventType::Chat, None));
+
+        // only whole lines if possible
+        assert_eq!(sub.take_data(8).unwrap(), b"death\n");
+
+        // otherwise split the line
+        assert_eq!(sub.take_data(2).unwrap(), b"ch");
+        assert_eq!(sub.take_data(100).unwrap(), b"at\n");
+        assert!(sub.take_data(100).is_none());
+        assert_eq!(sub.buffered, 0);
+    }
+
+    #[test]
+    fn buffer_limit() {
+        let mut sub = Subscriber::default();
+        let line_len = "entity_spawn\n".len();
+        let n = (MAX_BUFFERED / line_len) + 10;
+        for _ in 0..n {
+            sub.push(&event(EventType::EntitySpawn, None));
+        }
+
+        assert!(sub.buffered <= MAX_BUFFERED);
+        assert_eq!(sub.buffered, sub.lines.len() * line_len);
+        assert!(sub.lines.len() < n);
+    }
+
+    #[test]
+    fn parked_read_interrupted() {
+        let parked = ParkedRead {
+            reply: (),
+            size: 4096,
+            pid: 1234,
+        };
+
+        // no timeout, only a signal to the blocked reader releases it
+        assert!(!parked.interrupted(|_| false));
+        assert!(!parked.interrupted(|pid| pid == 5678));
+        assert!(parked.interrupted(|pid| pid == 1234));
+    }
+
+    #[test]
+    fn pending_signals() {
+        let status = |pending: &str, shared: &str, blocked: &str| {
+            format!(
+                "Name:\tcat\nSigQ:\t0/63471\nSigPnd:\t{}\nShdPnd:\t{}\nSigBlk:\t{}\nSigIgn:\t0000000000000000\n",
+                pending, shared, blocked
+            )
+        };
+
+        let none = "0000000000000000";
+        let sigint = "0000000000000002";
+        assert!(!status_has_pending_signal(&status(none, none, none)));
+        assert!(status_has_pending_signal(&status(none, sigint, none)));
+        assert!(status_has_pending_signal(&status(sigint, none, none)));
+        assert!(!status_has_pending_signal(&status(none, sigint, sigint)));
+        assert!(!status_has_pending_signal(""));
+    }
+
+    #[test]
+    fn subscribers() {
+        let mut subs = EventSubscribers::default();
+        assert!(!subs.has_subscribers());
+        subs.subscribe(1, None);
+        subs.subscribe(2, Some(Dimension::End));
+        assert!(subs.has_subscribers());
+
+        assert!(!subs.poll(1, Some(100)));
+        assert!(!subs.poll(2, Some(200)));
+
+        let wakeups = subs.publish(&[event(EventType::BlockBreak, Some(Dimension::Overworld))]);
+        assert!(matches!(wakeups.as_slice(), [Wakeup::Poll(100)]));
+        assert!(subs.poll(1, None));
+        assert!(!subs.poll(2, None));
+
+        assert!(subs.unsubscribe(1).is_none());
+        assert!(subs.unsubscribe(2).is_none());
+        assert!(!subs.has_subscribers());
+    }
+}
@@ -1,72 +1,68 @@
 use std::borrow::Cow;
 use std::ffi::OsStr;
-use std::fmt::Write;
+use std::os::unix::ffi::OsStrExt;
+use std::sync::Arc;
 use std::time::{Duration, SystemTime};
 
+use fuser::consts::{FOPEN_DIRECT_IO, FOPEN_NONSEEKABLE, FUSE_POLL_SCHEDULE_NOTIFY};
 use fuser::{
-    FileAttr, FileType, ReplyAttr, ReplyData, ReplyDirectory, ReplyEntry, ReplyWrite, Request,
-    TimeOrNow,
+    FileAttr, FileType, ReplyAttr, ReplyData, ReplyDirectory, ReplyEmpty, ReplyEntry, ReplyOpen,
+    ReplyPoll, ReplyWrite, Request, TimeOrNow,
 };
 use log::*;
+use parking_lot::Mutex;
 
 use ipc::generated::CommandType;
 use ipc::{BodyType, IpcChannel, IpcError};
 
+use crate::events::SharedEvents;
+use crate::handle::{slice_data, OpenFiles};
+use crate::mount::MountOptions;
 use crate::state::CachedGameState;
 use crate::structure::{
-    create_structure, Entry, EntryFilterResult, FileBehaviour, FilesystemStructure,
+    create_structure, Entry, EntryFilterResult, FileBehaviour, FilesystemStructure, StateRenderFn,
 };
+use crate::watch::SharedWatched;
 
 pub struct MinecraftFs {
     uid: u32,
     gid: u32,
-    ipc: IpcChannel,
+    /// Shared with the poller thread
+    ipc: Arc<Mutex<IpcChannel>>,
     state: CachedGameState,
     structure: FilesystemStructure,
-    response_buffer: String,
+    open_files: OpenFiles,
+    watched: SharedWatched,
+    events: SharedEvents,
+    opts: MountOptions,
 }
 
 const TTL: Duration = Duration::from_secs(1);
 
-/// Arbitrary size returned for all files
-const MAX_FILE_SIZE: u64 = 256;
-
 impl fuser::Filesystem for MinecraftFs {
     fn lookup(&mut self, _req: &Request<'_>, parent: u64, name: &OsStr, reply: ReplyEntry) {
         trace!("lookup(parent={}, name={:?})", parent, name);
-        let (inode, entry) = match self.structure.lookup_child(parent, name) {
-            Some(tup) => tup,
-            None => {
-                let interest = self.structure.interest_for_inode(parent, Some(name));
-                let state = match self.state.get(&mut self.ipc, interest.as_interest()) {
-                    Ok(state) => state,
-                    Err(err) => {
-                        log::error!("failed to fetch game state: {}", err);
-                        return reply.error(libc::EIO);
-                    }
-                };
-
-                self.structure.ensure_generated(state, interest);
-
-                // try again now that dynamic children have been generated
-                match self.structure.lookup_child(parent, name) {
-                    Some(tup) => tup,
-                    None => return reply.error(libc::ENOENT),
-                }
+        match self.lookup_or_generate(parent, name) {
+            Ok(inode) => {
+                let attr = self.mk_attr(inode);
+                reply.entry(&TTL, &attr, 0);
             }
-        };
+            Err(errno) => reply.error(errno),
+        }
+    }
 
-        let attr = self.mk_attr(inode, entry);
-        reply.entry(&TTL, &attr, 0);
+    fn forget(&mut self, _req: &Request<'_>, ino: u64, nlookup: u64) {
+        trace!("forget(inode={}, nlookup={})", ino, nlookup);
+        self.structure.forget(ino, nlookup);
     }
 
     fn getattr(&mut self, _req: &Request<'_>, ino: u64, reply: ReplyAttr) {
         trace!("getattr({})", ino);
-        let attr = match self.structure.lookup_inode(ino) {
-            Some(entry) => self.mk_attr(ino, entry),
-            None => return reply.error(libc::ENOENT),
-        };
+        if self.structure.lookup_inode(ino).is_none() {
+            return reply.error(libc::ENOENT);
+        }
 
+        let attr = self.mk_attr(ino);
         reply.attr(&TTL, &attr);
     }
 
@@ -81,7 +77,7 @@ impl fuser::Filesystem for MinecraftFs {
         _atime: Option<TimeOrNow>,
         _mtime: Option<TimeOrNow>,
         _ctime: Option<SystemTime>,
-        _fh: Option<u64>,
+        fh: Option<u64>,
         _crtime: Option<SystemTime>,
         _chgtime: Option<SystemTime>,
         _bkuptime: Option<SystemTime>,
@@ -95,9 +91,14 @@ impl fuser::Filesystem for MinecraftFs {
             None => return reply.error(libc::ENOENT),
         };
 
-        if let (Some(0), Entry::File(_)) = (size, entry) {
+        if let (Some(0), Entry::File(file)) = (size, entry) {
             trace!("truncating file");
-            return reply.attr(&TTL, &self.mk_attr(ino, entry));
+            if file.behaviour().is_writable() {
+                if let Some(f) = fh.and_then(|fh| self.open_files.get_mut(fh)) {
+                    f.truncate();
+                }
+            }
+            return reply.attr(&TTL, &self.mk_attr(ino));
         }
 
         reply.error(libc::ENOSYS)
@@ -109,7 +110,7 @@ impl fuser::Filesystem for MinecraftFs {
         match self.structure.lookup_inode(ino) {
             Some(Entry::Link(link)) => {
                 let interest = self.structure.interest_for_inode(ino, None);
-                let state = match self.state.get(&mut self.ipc, interest.as_interest()) {
+                let state = match self.state.get(&mut self.ipc.lock(), interest.as_interest()) {
                     Ok(state) => state,
                     Err(err) => {
                         log::error!("failed to fetch game state: {}", err);
@@ -129,9 +130,72 @@ impl fuser::Filesystem for MinecraftFs {
         }
     }
 
+    fn access(&mut self, _req: &Request<'_>, ino: u64, mask: i32, reply: ReplyEmpty) {
+        trace!("access(inode={}, mask={:#o})", ino, mask);
+
+        let perm = match self.structure.lookup_inode(ino) {
+            Some(entry) => entry_perm(entry),
+            None => return reply.error(libc::ENOENT),
+        };
+
+        // everything is owned by the mounting user, so only the owner bits matter
+        let owner = (perm >> 6) as i32;
+        let wanted = mask & (libc::R_OK | libc::W_OK | libc::X_OK);
+        if owner & wanted == wanted {
+            reply.ok()
+        } else {
+            reply.error(libc::EACCES)
+        }
+    }
+
+    fn open(&mut self, _req: &Request<'_>, ino: u64, flags: i32, reply: ReplyOpen) {
+        trace!("open(inode={}, flags={:#o})", ino, flags);
+
+        let file = match self.structure.lookup_inode(ino) {
+            Some(Entry::File(f)) => f,
+            Some(_) => return reply.error(libc::EISDIR),
+            None => return reply.error(libc::ENOENT),
+        };
+
+        let behaviour = file.behaviour();
+        let allowed = match flags & libc::O_ACCMODE {
+            libc::O_RDONLY => behaviour.is_readable(),
+            libc::O_WRONLY => behaviour.is_writable(),
+            _ => behaviour.is_readable() && behaviour.is_writable(),
+        };
+
+        if !allowed {
+            return reply.error(libc::EACCES);
+        }
+
+        // the size given in getattr isn't necessarily accurate for command-backed files, so bypass
+        // the page cache to ensure they're always read up to EOF
+        let flags = match behaviour {
+            FileBehaviour::Static(_) | FileBehaviour::CommandProxy { .. } => 0,
+            FileBehaviour::EventStream | FileBehaviour::WriteWithResult { .. } => {
+                FOPEN_DIRECT_IO | FOPEN_NONSEEKABLE
+            }
+            _ => FOPEN_DIRECT_IO,
+        };
+
+        let fh = self.open_files.open();
+        if let FileBehaviour::EventStream = behaviour {
+            let world = self.structure.command_state_for_file(ino).target_world;
+            self.events.lock().subscribe(fh, world);
+        }
+
+        if let FileBehaviour::ReadOnly(cmd, resp) | FileBehaviour::ReadWrite(cmd, resp) = behaviour
+        {
+            let state = self.structure.command_state_for_file(ino);
+            self.watched.lock().watch_file(ino, fh, *cmd, *resp, state);
+        }
+
+        reply.opened(fh, flags);
+    }
+
     fn read(
         &mut self,
-        _req: &Request<'_>,
+        req: &Request<'_>,
         ino: u64,
         fh: u64,
         offset: i64,
@@ -140,7 +204,6 @@ impl fuser::Filesystem for MinecraftFs {
         _lock_owner: Option<u64>,
         reply: ReplyData,
     ) {
-        // TODO actually check readability in fh? requires implementing open()
         trace!(
             "read(inode={}, fh={}, offset={}, size={})",
             ino,
@@ -156,38 +219,66 @@ impl fuser::Filesystem for MinecraftFs {
             }
         };
 
-        let (cmd, resp) = match file.behaviour() {
-            FileBehaviour::ReadOnly(cmd, resp) | FileBehaviour::ReadWrite(cmd, resp) => (cmd, resp),
+        let (cmd, resp, format_fn) = match file.behaviour() {
+            FileBehaviour::ReadOnly(cmd, resp) | FileBehaviour::ReadWrite(cmd, resp) => {
+                (cmd, resp, None)
+            }
+            FileBehaviour::Reformatted { cmd, format_fn, .. } => {
+                (cmd, &BodyType::String, Some(*format_fn))
+            }
             FileBehaviour::Static(msg) | FileBehaviour::CommandProxy { readme: msg, .. } => {
-                let msg = msg.as_bytes();
-                let start = offset as usize;
-                let end = (start + size as usize).min(msg.len());
-                let data = msg.get(start..end).unwrap_or(&[]);
-                return reply.data(data);
+                return reply.data(slice_data(msg.as_bytes(), offset, size));
+            }
+            FileBehaviour::EventStream => {
+                // blocks until the next event if there are none waiting
+                return self.events.lock().read(fh, size, req.pid(), reply);
+            }
+            FileBehaviour::WriteWithResult { .. } => {
+                return match self.read_write_result(ino, fh, size) {
+                    Ok(result) => reply.data(&result),
+                    Err(errno) => reply.error(errno),
+                };
+            }
+            FileBehaviour::FromState(render) => {
+                let render = *render;
+                return match self.read_from_state(ino, fh, offset, size, render) {
+                    Ok(data) => reply.data(&data),
+                    Err(errno) => reply.error(errno),
+                };
             }
             _ => return reply.error(libc::EOPNOTSUPP),
         };
 
+        let open_file = match self.open_files.get_mut(fh) {
+            Some(f) => f,
+            None => return reply.error(libc::EBADF),
+        };
+
+        if offset <= 0 {
+            self.watched.lock().mark_read(ino, fh);
+        }
+
+        let mut ipc = self.ipc.lock();
         let state = self.structure.command_state_for_file(ino);
-        let resp = match self.ipc.send_read_command(*cmd, *resp, state) {
-            Ok(resp) => resp,
-            Err(err) => {
+        let result = open_file.read(offset, size, || {
+            let resp = ipc.send_read_command(*cmd, *resp, state).map_err(|err| {
                 error!("command failed: {}", err);
-                return reply.error(ipc_error_code(&err));
+                ipc_error_code(&err)
+            })?;
+
+            let resp = resp.to_string();
+            match format_fn {
+                Some(format) => format(&resp).map(String::into_bytes).ok_or_else(|| {
+                    error!("couldn't reformat response: {:?}", resp);
+                    libc::EIO
+                }),
+                None => Ok(resp.into_bytes()),
             }
-        };
+        });
 
-        // TODO respect offset and size
-        self.response_buffer.clear();
-        match write!(&mut self.response_buffer, "{}", resp) {
-            Ok(_) => {
-                debug_assert!(
-                    self.response_buffer.len() <= MAX_FILE_SIZE as usize,
-                    "max file size is too low"
-                );
-                reply.data(self.response_buffer.as_bytes());
-            }
-            Err(_) => reply.error(libc::ENOMEM),
+        match result {
+            Ok(data) => reply.data(data),
+            Err(errno) => reply.error(errno),
         }
     }
 
@@ -195,7 +286,7 @@ impl fuser::Filesystem for MinecraftFs {
         &mut self,
         _req: &Request<'_>,
         ino: u64,
-        _fh: u64,
+        fh: u64,
         offset: i64,
         data: &[u8],
         _write_flags: u32,
@@ -204,49 +295,219 @@ impl fuser::Filesystem for MinecraftFs {
         reply: ReplyWrite,
     ) {
         trace!(
-            "write(inode={}, offset={}, data=<{} bytes>)",
+            "write(inode={}, fh={}, offset={}, data=<{} bytes>)",
             ino,
+            fh,
             offset,
             data.len()
         );
 
-        let file = match self.structure.lookup_inode(ino) {
-            Some(Entry::File(f)) => f,
-            _ => return reply.error(libc::ENOENT),
+        match self.open_files.get_mut(fh) {
+            // sent on flush
+            Some(f) => match f.write(offset, data) {
+                Ok(()) => reply.written(data.len() as u32),
+                Err(_) => reply.error(libc::EFBIG),
+            },
+            None => reply.error(libc::EBADF),
+        }
+    }
+
+    fn flush(
+        &mut self,
+        _req: &Request<'_>,
+        ino: u64,
+        fh: u64,
+        _lock_owner: u64,
+        reply: ReplyEmpty,
+    ) {
+        trace!("flush(inode={}, fh={})", ino, fh);
+
+        let data = match self.open_files.get_mut(fh) {
+            Some(f) => f.take_pending_write(),
+            None => return reply.error(libc::EBADF),
         };
 
-        let (cmd, body_type, data_to_send) = match file.behaviour() {
-            FileBehaviour::WriteOnly(cmd, body) | FileBehaviour::ReadWrite(cmd, body) => {
-                (*cmd, *body, Cow::Borrowed(data))
+        match data.map(|data| self.send_write(ino, &data)) {
+            Some(Err(errno)) => reply.error(errno),
+            Some(Ok(Some(result))) => {
+                if let Some(f) = self.open_files.get_mut(fh) {
+                    f.set_write_result(result);
+                }
+                reply.ok()
             }
-            FileBehaviour::CommandProxy {
-                produce_cmd_fn: write,
-                ..
-            } => {
-                // must be utf8
-                let input_str = match std::str::from_utf8(data) {
-                    Ok(s) => s,
-                    Err(_) => return reply.error(libc::EINVAL),
-                };
+            _ => reply.ok(),
+        }
+    }
 
-                let server_cmd = match write(input_str.trim_end()) {
-                    Some(s) => Cow::Owned(s.into_boxed_str().into_boxed_bytes().into_vec()),
-                    None => return reply.error(libc::EINVAL),
-                };
-                (CommandType::ServerCommand, BodyType::String, server_cmd)
+    fn release(
+        &mut self,
+        _req: &Request<'_>,
+        ino: u64,
+        fh: u64,
+        _flags: i32,
+        _lock_owner: Option<u64>,
+        _flush: bool,
+        reply: ReplyEmpty,
+    ) {
+        trace!("release(inode={}, fh={})", ino, fh);
+        self.watched.lock().unwatch_file(ino, fh);
+        if let Some(parked) = self.events.lock().unsubscribe(fh) {
+            parked.data(&[]);
+        }
+
+        // normally already sent by flush, but it's not guaranteed to be called
+        let data = self
+            .open_files
+            .release(fh)
+            .and_then(|mut f| f.take_pending_write());
+        match data.map(|data| self.send_write(ino, &data)) {
+            Some(Err(errno)) => reply.error(errno),
+            _ => reply.ok(),
+        }
+    }
+
+    fn poll(
+        &mut self,
+        _req: &Request<'_>,
+        ino: u64,
+        fh: u64,
+        kh: u64,
+        events: u32,
+        flags: u32,
+        reply: ReplyPoll,
+    ) {
+        trace!(
+            "poll(inode={}, fh={}, kh={}, events={:#x}, flags={:#x})",
+            ino,
+            fh,
+            kh,
+            events,
+            flags
+        );
+
+        let kh = if flags & FUSE_POLL_SCHEDULE_NOTIFY != 0 {
+            Some(kh)
+        } else {
+            None
+        };
+
+        let is_event_stream = matches!(
+            self.structure.lookup_inode(ino),
+            Some(Entry::File(f)) if matches!(f.behaviour(), FileBehaviour::EventStream)
+        );
+
+        // always writable, but only readable once the value has changed or there are events
+        let mut revents = (libc::POLLOUT | libc::POLLWRNORM) as u32;
+        let readable = if is_event_stream {
+            self.events.lock().poll(fh, kh)
+        } else {
+            self.watched.lock().poll(ino, fh, kh)
+        };
+        if readable {
+            revents |= (libc::POLLIN | libc::POLLRDNORM) as u32;
+        }
+
+        reply.poll(revents & events);
+    }
+
+    fn opendir(&mut self, _req: &Request<'_>, ino: u64, _flags: i32, reply: ReplyOpen) {
+        trace!("opendir({})", ino);
+
+        // watch dirs that list entities for changes
+        let interest = self.structure.interest_for_inode(ino, None).as_interest();
+        if interest.entities_by_id {
+            self.watched.lock().watch_dir(ino, interest);
+        }
+
+        reply.opened(0, 0);
+    }
+
+    fn releasedir(
+        &mut self,
+        _req: &Request<'_>,
+        ino: u64,
+        _fh: u64,
+        _flags: i32,
+        reply: ReplyEmpty,
+    ) {
+        trace!("releasedir({})", ino);
+        self.watched.lock().unwatch_dir(ino);
+        reply.ok();
+    }
+
+    fn mkdir(
+        &mut self,
+        _req: &Request<'_>,
+        parent: u64,
+        name: &OsStr,
+        _mode: u32,
+        _umask: u32,
+        reply: ReplyEntry,
+    ) {
+        trace!("mkdir(parent={}, name={:?})", parent, name);
+
+        let cmd = match self.structure.lookup_inode(parent) {
+            Some(Entry::Dir(dir)) => match dir.create_cmd() {
+                Some(cmd) => cmd,
+                None => return reply.error(libc::EPERM),
+            },
+            Some(_) => return reply.error(libc::ENOTDIR),
+            None => return reply.error(libc::ENOENT),
+        };
+
+        if self.structure.lookup_child(parent, name).is_some() {
+            return reply.error(libc::EEXIST);
+        }
+
+        let state = self.structure.command_state_for_file(parent);
+        let sent =
+            self.ipc
+                .lock()
+                .send_write_command(cmd, BodyType::String, name.as_bytes(), state);
+        if let Err(err) = sent {
+            error!("mkdir failed: {}", err);
+            return reply.error(ipc_error_code(&err));
+        }
+
+        // the new dir is generated from the game's state like any other
+        self.state.invalidate();
+        self.structure.invalidate_dynamic(parent);
+        match self.lookup_or_generate(parent, name) {
+            Ok(inode) => {
+                let attr = self.mk_attr(inode);
+                reply.entry(&TTL, &attr, 0);
             }
-            _ => return reply.error(libc::EOPNOTSUPP),
+            Err(errno) => reply.error(errno),
+        }
+    }
+
+    fn rmdir(&mut self, _req: &Request<'_>, parent: u64, name: &OsStr, reply: ReplyEmpty) {
+        trace!("rmdir(parent={}, name={:?})", parent, name);
+
+        let (ino, cmd) = match self.structure.lookup_child(parent, name) {
+            Some((ino, Entry::Dir(dir))) => match dir.remove_cmd() {
+                Some(cmd) => (ino, cmd),
+                None => return reply.error(libc::EPERM),
+            },
+            Some(_) => return reply.error(libc::ENOTDIR),
+            None => return reply.error(libc::ENOENT),
         };
 
         let state = self.structure.command_state_for_file(ino);
-        match self
-            .ipc
-            .send_write_command(cmd, body_type, &data_to_send, state)
-        {
-            Ok(_) => reply.written(data.len() as u32),
+        let sent =
+            self.ipc
+                .lock()
+                .send_write_command(cmd, BodyType::String, name.as_bytes(), state);
+
+        match sent {
+            Ok(_) => {
+                self.state.invalidate();
+                self.structure.invalidate_dynamic(parent);
+                reply.ok()
+            }
             Err(err) => {
-                error!("write failed: {}", err);
-                reply.error(ipc_error_code(&err));
+                error!("rmdir failed: {}", err);
+                reply.error(ipc_error_code(&err))
             }
         }
     }
@@ -267,7 +528,7 @@ impl fuser::Filesystem for MinecraftFs {
         };
 
         let interest = self.structure.interest_for_inode(ino, None);
-        let state = match self.state.get(&mut self.ipc, interest.as_interest()) {
+        let state = match self.state.get(&mut self.ipc.lock(), interest.as_interest()) {
             Ok(state) => state,
             Err(err) => {
                 log::error!("failed to fetch game state: {}", err);
@@ -311,6 +572,14 @@ impl fuser::Filesystem for MinecraftFs {
     }
 }
 
+fn entry_perm(entry: &Entry) -> u16 {
+    match entry {
+        Entry::File(f) => f.behaviour().perm(),
+        Entry::Dir(d) => d.perm(),
+        Entry::Link(_) => 0o777,
+    }
+}
+
 fn ipc_error_code(err: &IpcError) -> i32 {
     match err {
         IpcError::NoCurrentGame | IpcError::ClientError(_) => libc::EOPNOTSUPP,
@@ -328,7 +597,12 @@ fn ipc_error_code(err: &IpcError) -> i32 {
 }
 
 impl MinecraftFs {
-    pub fn new(ipc: IpcChannel) -> Self {
+    pub fn new(
+        ipc: Arc<Mutex<IpcChannel>>,
+        watched: SharedWatched,
+        events: SharedEvents,
+        opts: MountOptions,
+    ) -> Self {
         let uid;
         let gid;
 
@@ -345,23 +619,208 @@ impl MinecraftFs {
             ipc,
             state: CachedGameState::default(),
             structure,
-            response_buffer: String::new(),
+            open_files: OpenFiles::default(),
+            watched,
+            events,
+            opts,
+        }
+    }
+
+    /// Looks up a child to be returned to the kernel, generating it if needed
+    fn lookup_or_generate(&mut self, parent: u64, name: &OsStr) -> Result<u64, i32> {
+        let inode = self.generate_child(parent, name)?;
+
+        // the kernel holds on to whatever is returned until it forgets it
+        self.structure.count_lookup(inode);
+        Ok(inode)
+    }
+
+    /// Generates the dynamic children of the parent from the game state if the child isn't
+    /// already known
+    fn generate_child(&mut self, parent: u64, name: &OsStr) -> Result<u64, i32> {
+        if let Some((inode, _)) = self.structure.lookup_child(parent, name) {
+            return Ok(inode);
+        }
+
+        let interest = self.structure.interest_for_inode(parent, Some(name));
+        let state = self
+            .state
+            .get(&mut self.ipc.lock(), interest.as_interest())
+            .map_err(|err| {
+                log::error!("failed to fetch game state: {}", err);
+                libc::EIO
+            })?;
+
+        self.structure.ensure_generated(state, interest);
+
+        // try again now that dynamic children have been generated
+        self.structure
+            .lookup_child(parent, name)
+            .map(|(inode, _)| inode)
+            .ok_or(libc::ENOENT)
+    }
+
+    /// Sends everything written to a file handle as a single command. Returns the rendered
+    /// response for files that have one
+    fn send_write(&mut self, ino: u64, data: &[u8]) -> Result<Option<Vec<u8>>, i32> {
+        let file = match self.structure.lookup_inode(ino) {
+            Some(Entry::File(f)) => f,
+            _ => return Err(libc::ENOENT),
+        };
+
+        let (cmd, body_type, data_to_send, result) = match file.behaviour() {
+            FileBehaviour::WriteOnly(cmd, body) | FileBehaviour::ReadWrite(cmd, body) => {
+                (*cmd, *body, Cow::Borrowed(data), None)
+            }
+            FileBehaviour::CommandProxy {
+                produce_cmd_fn: write,
+                ..
+            } => {
+                // must be utf8
+                let input_str = std::str::from_utf8(data).map_err(|_| libc::EINVAL)?;
+
+                let server_cmd = write(input_str.trim_end()).ok_or(libc::EINVAL)?;
+                (
+                    CommandType::ServerCommand,
+                    BodyType::String,
+                    Cow::Owned(server_cmd.into_bytes()),
+                    None,
+                )
+            }
+            FileBehaviour::WriteWithResult {
+                cmd,
+                result,
+                validate_fn,
+            } => {
+                let input_str = std::str::from_utf8(data).map_err(|_| libc::EINVAL)?;
+                let input = validate_fn(input_str.trim_end()).ok_or(libc::EINVAL)?;
+                (
+                    *cmd,
+                    BodyType::String,
+                    Cow::Owned(input.into_bytes()),
+                    Some(*result),
+                )
+            }
+            FileBehaviour::Reformatted {
+                cmd,
+                validate_fn: Some(validate_fn),
+                ..
+            } => {
+                let input_str = std::str::from_utf8(data).map_err(|_| libc::EINVAL)?;
+                let input = validate_fn(input_str.trim_end()).ok_or(libc::EINVAL)?;
+                (*cmd, BodyType::String, Cow::Owned(input.into_bytes()), None)
+            }
+            FileBehaviour::WriteDecoded { cmd, decode_fn } => {
+                let decoded = decode_fn(data).ok_or(libc::EINVAL)?;
+                (
+                    *cmd,
+                    BodyType::String,
+                    Cow::Owned(decoded.into_bytes()),
+                    None,
+                )
+            }
+            _ => return Err(libc::EOPNOTSUPP),
+        };
+
+        let state = self.structure.command_state_for_file(ino);
+        let mut ipc = self.ipc.lock();
+        let sent = match result {
+            Some(resp) => ipc
+                .send_write_command_with_result(cmd, body_type, &data_to_send, resp, state)
+                .map(|body| Some(body.to_string().into_bytes())),
+            None => ipc
+                .send_write_command(cmd, body_type, &data_to_send, state)
+                .map(|_| None),
+        };
+
+        sent.map_err(|err| {
+            error!("write failed: {}", err);
+            ipc_error_code(&err)
+        })
+    }
+
+    /// Sends anything written to the handle but not yet flushed first, so the result is up to date
+    fn read_write_result(&mut self, ino: u64, fh: u64, size: u32) -> Result<Vec<u8>, i32> {
+        let pending = self
+            .open_files
+            .get_mut(fh)
+            .ok_or(libc::EBADF)?
+            .take_pending_write();
+
+        let result = match pending {
+            Some(data) => self.send_write(ino, &data)?,
+            None => None,
+        };
+
+        let open_file = self.open_files.get_mut(fh).ok_or(libc::EBADF)?;
+        if let Some(result) = result {
+            open_file.set_write_result(result);
+        }
+        Ok(open_file.take_write_result(size))
+    }
+
+    /// Snapshots the rendered state on the first read, so the whole file is consistent
+    fn read_from_state(
+        &mut self,
+        ino: u64,
+        fh: u64,
+        offset: i64,
+        size: u32,
+        render: StateRenderFn,
+    ) -> Result<Vec<u8>, i32> {
+        let interest = self.structure.interest_for_inode(ino, None).as_interest();
+        let open_file = self.open_files.get_mut(fh).ok_or(libc::EBADF)?;
+
+        let mut ipc = self.ipc.lock();
+        let state = &mut self.state;
+        let data = open_file.read(offset, size, || {
+            let state = state.get(&mut ipc, interest).map_err(|err| {
+                error!("failed to fetch game state: {}", err);
+                ipc_error_code(&err)
+            })?;
+
+            render(state).ok_or(libc::EIO)
+        })?;
+
+        Ok(data.to_vec())
+    }
+
+    /// Exact for static files. Command-backed files are 0 unless [MountOptions::exact_file_sizes]
+    /// is set, in which case the command is sent to find out
+    fn file_size(&mut self, ino: u64) -> u64 {
+        let file = match self.structure.lookup_inode(ino) {
+            Some(Entry::File(f)) => f,
+            _ => return 0,
+        };
+
+        match file.behaviour() {
+            FileBehaviour::Static(msg) | FileBehaviour::CommandProxy { readme: msg, .. } => {
+                msg.len() as u64
+            }
+            FileBehaviour::ReadOnly(cmd, resp) | FileBehaviour::ReadWrite(cmd, resp)
+                if self.opts.exact_file_sizes =>
+            {
+                let state = self.structure.command_state_for_file(ino);
+                match self.ipc.lock().send_read_command(*cmd, *resp, state) {
+                    Ok(resp) => resp.to_string().len() as u64,
+                    Err(err) => {
+                        debug!("failed to get size of file {}: {}", ino, err);
+                        0
+                    }
+                }
+            }
+            _ => 0,
         }
     }
 
-    fn mk_attr(&self, ino: u64, entry: &Entry) -> FileAttr {
+    fn mk_attr(&mut self, ino: u64) -> FileAttr {
         let time = SystemTime::now();
-        let (kind, size) = match entry {
-            Entry::File(f) => (
-                FileType::RegularFile,
-                if f.behaviour().is_readable() {
-                    MAX_FILE_SIZE
-                } else {
-                    0
-                },
-            ),
-            Entry::Dir(_) => (FileType::Directory, 0),
-            Entry::Link(_) => (FileType::Symlink, 0),
+        let size = self.file_size(ino);
+        let (kind, perm) = match self.structure.lookup_inode(ino) {
+            Some(entry @ Entry::File(_)) => (FileType::RegularFile, entry_perm(entry)),
+            Some(entry @ Entry::Dir(_)) => (FileType::Directory, entry_perm(entry)),
+            Some(entry @ Entry::Link(_)) => (FileType::Symlink, entry_perm(entry)),
+            None => (FileType::RegularFile, 0),
         };
         FileAttr {
             ino,
@@ -372,7 +831,7 @@ impl MinecraftFs {
             ctime: time,
             crtime: time,
             kind,
-            perm: 0o755,
+            perm,
             nlink: 1,
             uid: self.uid,
             gid: self.gid,
@@ -0,0 +1,305 @@
+use std::collections::HashMap;
+
+/// Most bytes that can be written to a handle before it's flushed. This comfortably fits the
+/// largest gzipped schematic that can be pasted, while stopping a write at a huge offset from
+/// allocating the whole way up to it
+const MAX_PENDING_WRITE: usize = 4 * 1024 * 1024;
+
+/// A write would take a handle's pending data past [MAX_PENDING_WRITE]
+#[derive(Debug, PartialEq, Eq)]
+pub struct WriteTooLarge;
+
+/// State for each file handle given out by `open`
+#[derive(Default)]
+pub struct OpenFiles {
+    next_fh: u64,
+    files: HashMap<u64, OpenFile>,
+}
+
+#[derive(Default)]
+pub struct OpenFile {
+    /// Rendered response, taken on the first read from the start of the file
+    snapshot: Option<Vec<u8>>,
+    /// Written bytes that haven't been sent to the game yet. None if nothing has been written
+    pending_write: Option<Vec<u8>>,
+    /// Rendered response to the last write, for files that have one
+    write_result: Option<Vec<u8>>,
+}
+
+impl OpenFiles {
+    pub fn open(&mut self) -> u64 {
+        // 0 is never handed out, so a missing handle is easy to spot
+        self.next_fh += 1;
+        let fh = self.next_fh;
+        self.files.insert(fh, OpenFile::default());
+        fh
+    }
+
+    pub fn get_mut(&mut self, fh: u64) -> Option<&mut OpenFile> {
+        self.files.get_mut(&fh)
+    }
+
+    pub fn release(&mut self, fh: u64) -> Option<OpenFile> {
+        self.files.remove(&fh)
+    }
+}
+
+impl OpenFile {
+    /// Reads `size` bytes from `offset` of this handle's snapshot. A new snapshot is rendered
+    /// with `render` when reading from the start of the file, or if there isn't one yet, so
+    /// subsequent partial reads all see the same value.
+    pub fn read<E>(
+        &mut self,
+        offset: i64,
+        size: u32,
+        render: impl FnOnce() -> Result<Vec<u8>, E>,
+    ) -> Result<&[u8], E> {
+        if offset <= 0 || self.snapshot.is_none() {
+            self.snapshot = Some(render()?);
+        }
+
+        let snapshot = self.snapshot.as_deref().unwrap_or_default();
+        Ok(slice_data(snapshot, offset, size))
+    }
+
+    /// Buffers `data` at `offset` until the handle is flushed. Nothing is buffered if it would end
+    /// past [MAX_PENDING_WRITE]
+    pub fn write(&mut self, offset: i64, data: &[u8]) -> Result<(), WriteTooLarge> {
+        let start = usize::try_from(offset.max(0)).map_err(|_| WriteTooLarge)?;
+        let end = start
+            .checked_add(data.len())
+            .filter(|end| *end <= MAX_PENDING_WRITE)
+            .ok_or(WriteTooLarge)?;
+
+        let buf = self.pending_write.get_or_insert_with(Vec::new);
+        if buf.len() < end {
+            buf.resize(end, 0);
+        }
+        buf[start..end].copy_from_slice(data);
+        Ok(())
+    }
+
+    /// Discards anything written so far. This still counts as a write, so opening with O_TRUNC
+    /// and writing nothing, e.g. `echo -n > ride`, sends an empty body on flush
+    pub fn truncate(&mut self) {
+        self.pending_write = Some(Vec::new());
+    }
+
+    /// Everything written since the last call, if anything
+    pub fn take_pending_write(&mut self) -> Option<Vec<u8>> {
+        self.pending_write.take()
+    }
+
+    pub fn set_write_result(&mut self, result: Vec<u8>) {
+        self.write_result = Some(result);
+    }
+
+    /// Up to `size` bytes of the response to the last write. It's consumed like a stream rather
+    /// than read at an offset, as the offset has already moved past whatever was written through
+    /// the same handle
+    pub fn take_write_result(&mut self, size: u32) -> Vec<u8> {
+        let result = match self.write_result.as_mut() {
+            Some(r) => r,
+            None => return Vec::new(),
+        };
+
+        let rest = result.split_off(result.len().min(size as usize));
+        std::mem::replace(result, rest)
+    }
+}
+
+/// Empty if offset is past the end of the data, i.e. EOF
+pub fn slice_data(data: &[u8], offset: i64, size: u32) -> &[u8] {
+    let start = offset.max(0) as usize;
+    let end = start.saturating_add(size as usize).min(data.len());
+    data.get(start..end).unwrap_or(&[])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ipc::{Body, BodyType};
+    use std::cell::Cell;
+
+    /// Reads the whole file in chunks of `size`, like `dd bs=<size>`
+    fn read_all(file: &mut OpenFile, size: u32, render: impl Fn() -> Vec<u8>) -> Vec<u8> {
+        let mut out = Vec::new();
+        loop {
+            let chunk = file
+                .read::<()>(out.len() as i64, size, || Ok(render()))
+                .unwrap();
+            if chunk.is_empty() {
+                break out;
+            }
+            out.extend_from_slice(chunk);
+        }
+    }
+
+    #[test]
+    fn slicing() {
+        let data = b"hello";
+        assert_eq!(slice_data(data, 0, 256), b"hello");
+        assert_eq!(slice_data(data, 0, 2), b"he");
+        assert_eq!(slice_data(data, 3, 256), b"lo");
+        assert_eq!(slice_data(data, 4, 1), b"o");
+        assert_eq!(slice_data(data, 5, 1), b"");
+        assert_eq!(slice_data(data, 500, 1), b"");
+        assert_eq!(slice_data(data, -1, 2), b"he");
+        assert_eq!(slice_data(data, 1, u32::MAX), b"ello");
+    }
+
+    #[test]
+    fn partial_reads_are_consistent() {
+        let renders = Cell::new(0);
+        let render = || {
+            // different value every time
+            renders.set(renders.get() + 1);
+            format!("value {}", renders.get()).into_bytes()
+        };
+
+        let mut file = OpenFile::default();
+        assert_eq!(read_all(&mut file, 1, render), b"value 1");
+        assert_eq!(renders.get(), 1);
+
+        // reading from the start again takes a new snapshot
+        assert_eq!(read_all(&mut file, 3, render), b"value 2");
+        assert_eq!(renders.get(), 2);
+    }
+
+    #[test]
+    fn render_error() {
+        let mut file = OpenFile::default();
+        assert_eq!(file.read(0, 10, || Err("oh no")), Err("oh no"));
+
+        // nothing was captured, so a read further in tries again
+        let data = file.read::<()>(2, 10, || Ok(b"abcd".to_vec())).unwrap();
+        assert_eq!(data, b"cd");
+    }
+
+    #[test]
+    fn all_body_types() {
+        let bodies = [
+            (BodyType::Integer, Body::Integer(-1234)),
+            (BodyType::Float, Body::Float(0.25)),
+            (BodyType::String, Body::String("minecraft:dirt".into())),
+            (
+                BodyType::Position,
+                Body::Vec {
+                    x: 1.5,
+                    y: -60.0,
+                    z: 100.125,
+                },
+            ),
+            (
+                BodyType::Position,
+                Body::Block {
+                    x: 1,
+                    y: -60,
+                    z: 100,
+                },
+            ),
+            (
+                BodyType::Rotation,
+                Body::Rotation {
+                    yaw: -90.5,
+                    pitch: 45.0,
+                },
+            ),
+            (BodyType::Bool, Body::Bool(true)),
+            (BodyType::Lines, Body::String("first\nsecond\n".into())),
+            (
+                BodyType::Keyword(&["clear", "rain"]),
+                Body::String("rain 300".into()),
+            ),
+        ];
+
+        for (ty, body) in bodies {
+            let rendered = body.to_string().into_bytes();
+            for size in [1, 2, 3, 256] {
+                let mut file = OpenFile::default();
+                let read = read_all(&mut file, size, || rendered.clone());
+                assert_eq!(read, rendered, "{:?} read in chunks of {}", ty, size);
+
+                // what's read back parses as the original type
+                assert!(ty.create_from_data(&read).is_some(), "{:?}", ty);
+            }
+        }
+    }
+
+    #[test]
+    fn buffered_writes() {
+        let mut file = OpenFile::default();
+        assert!(file.take_pending_write().is_none());
+
+        // split across multiple syscalls
+        file.write(0, b"100,").unwrap();
+        file.write(4, b"70,").unwrap();
+        file.write(7, b"100\n").unwrap();
+        assert_eq!(
+            file.take_pending_write().as_deref(),
+            Some(&b"100,70,100\n"[..])
+        );
+        assert!(file.take_pending_write().is_none());
+
+        // overwriting
+        file.write(0, b"hello").unwrap();
+        file.write(1, b"ipp").unwrap();
+        assert_eq!(file.take_pending_write().as_deref(), Some(&b"hippo"[..]));
+
+        // an empty write still counts
+        file.write(0, b"").unwrap();
+        assert_eq!(file.take_pending_write().as_deref(), Some(&b""[..]));
+
+        file.write(0, b"old").unwrap();
+        file.truncate();
+        file.write(0, b"new").unwrap();
+        assert_eq!(file.take_pending_write().as_deref(), Some(&b"new"[..]));
+
+        // truncating alone is an empty write, as O_TRUNC never calls write
+        file.truncate();
+        assert_eq!(file.take_pending_write(), Some(vec![]));
+        assert!(file.take_pending_write().is_none());
+
+        // only the latest result is kept
+        assert!(file.take_write_result(10).is_empty());
+        file.set_write_result(b"8000".to_vec());
+        file.set_write_result(b"2168".to_vec());
+        assert_eq!(file.take_write_result(3), b"216");
+        assert_eq!(file.take_write_result(10), b"8");
+        assert!(file.take_write_result(10).is_empty());
+    }
+
+    #[test]
+    fn huge_write_offsets() {
+        let mut file = OpenFile::default();
+        assert_eq!(file.write(1 << 62, b"x"), Err(WriteTooLarge));
+        assert_eq!(file.write(i64::MAX, b"x"), Err(WriteTooLarge));
+        assert_eq!(
+            file.write(MAX_PENDING_WRITE as i64, b"x"),
+            Err(WriteTooLarge)
+        );
+        assert!(file.take_pending_write().is_none());
+
+        // right up to the limit is fine
+        file.write(MAX_PENDING_WRITE as i64 - 1, b"x").unwrap();
+        let buf = file.take_pending_write().unwrap();
+        assert_eq!(buf.len(), MAX_PENDING_WRITE);
+        assert_eq!(buf.last(), Some(&b'x'));
+    }
+
+    #[test]
+    fn handles() {
+        let mut files = OpenFiles::default();
+        let a = files.open();
+        let b = files.open();
+        assert_ne!(a, b);
+        assert_ne!(a, 0);
+
+        files.get_mut(a).unwrap().snapshot = Some(b"a".to_vec());
+        assert!(files.get_mut(b).unwrap().snapshot.is_none());
+
+        assert!(files.release(a).is_some());
+        assert!(files.get_mut(a).is_none());
+        assert!(files.release(a).is_none());
+    }
+}
@@ -1,6 +1,13 @@
+mod events;
 mod fuse;
+mod handle;
+mod map;
 mod mount;
+mod nbt;
+mod schematic;
+mod snbt;
 mod state;
 mod structure;
+mod watch;
 
-pub use mount::mount;
+pub use mount::{mount, MountOptions};
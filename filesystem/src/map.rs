@@ -0,0 +1,162 @@
+//! Top-down maps of an area as binary netpbm images, one pixel per column with north at the top
+
+use crate::state::ColumnDetails;
+
+/// Lowest and highest blocks in the overworld, mapped to black and white in heightmaps
+const MIN_Y: i32 = -64;
+const MAX_Y: i32 = 319;
+
+/// Roughly the game's own map colours
+const BLOCK_COLOURS: &[(&str, [u8; 3])] = &[
+    ("minecraft:grass_block", [127, 178, 56]),
+    ("minecraft:water", [64, 64, 255]),
+    ("minecraft:sand", [247, 233, 163]),
+    ("minecraft:sandstone", [247, 233, 163]),
+    ("minecraft:gravel", [112, 112, 112]),
+    ("minecraft:stone", [112, 112, 112]),
+    ("minecraft:dirt", [151, 109, 77]),
+    ("minecraft:snow", [255, 255, 255]),
+    ("minecraft:snow_block", [255, 255, 255]),
+    ("minecraft:ice", [160, 160, 255]),
+    ("minecraft:packed_ice", [160, 160, 255]),
+    ("minecraft:clay", [164, 168, 184]),
+    ("minecraft:lava", [255, 0, 0]),
+    ("minecraft:netherrack", [112, 2, 0]),
+    ("minecraft:bedrock", [112, 112, 112]),
+    ("minecraft:end_stone", [247, 233, 163]),
+    ("minecraft:air", [0, 0, 0]),
+];
+
+/// Matched against the end of block types without their own colour
+const SUFFIX_COLOURS: &[(&str, [u8; 3])] = &[
+    ("_leaves", [0, 124, 0]),
+    ("_log", [143, 119, 72]),
+    ("_planks", [143, 119, 72]),
+    ("grass", [0, 124, 0]),
+    ("_ore", [112, 112, 112]),
+    ("terracotta", [216, 127, 51]),
+];
+
+const UNKNOWN_COLOUR: [u8; 3] = [128, 128, 128];
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum MapKind {
+    /// Greyscale pgm of the height of the top block
+    Height,
+    /// Colour ppm of the type of the top block
+    Colour,
+}
+
+impl MapKind {
+    pub fn from_extension(ext: &str) -> Option<Self> {
+        match ext {
+            "pgm" => Some(Self::Height),
+            "ppm" => Some(Self::Colour),
+            _ => None,
+        }
+    }
+}
+
+pub fn render_map(columns: &ColumnDetails, kind: MapKind) -> Option<Vec<u8>> {
+    let (width, height) = columns.size();
+    let tops = columns.top_blocks()?;
+
+    Some(match kind {
+        MapKind::Height => {
+            let pixels = tops
+                .iter()
+                .map(|(_, y)| height_shade(*y as i32))
+                .collect::<Vec<_>>();
+            encode_pgm(width, height, &pixels)
+        }
+        MapKind::Colour => {
+            let pixels = tops
+                .iter()
+                .flat_map(|(ty, _)| block_colour(ty))
+                .collect::<Vec<_>>();
+            encode_ppm(width, height, &pixels)
+        }
+    })
+}
+
+fn height_shade(y: i32) -> u8 {
+    let y = y.clamp(MIN_Y, MAX_Y);
+    ((y - MIN_Y) * 255 / (MAX_Y - MIN_Y)) as u8
+}
+
+fn block_colour(ty: &str) -> [u8; 3] {
+    BLOCK_COLOURS
+        .iter()
+        .find(|(name, _)| *name == ty)
+        .or_else(|| SUFFIX_COLOURS.iter().find(|(end, _)| ty.ends_with(end)))
+        .map_or(UNKNOWN_COLOUR, |(_, colour)| *colour)
+}
+
+/// Binary greyscale, one byte per pixel
+fn encode_pgm(width: usize, height: usize, pixels: &[u8]) -> Vec<u8> {
+    debug_assert_eq!(pixels.len(), width * height);
+    let mut out = format!("P5\n{} {}\n255\n", width, height).into_bytes();
+    out.extend_from_slice(pixels);
+    out
+}
+
+/// Binary rgb, three bytes per pixel
+fn encode_ppm(width: usize, height: usize, pixels: &[u8]) -> Vec<u8> {
+    debug_assert_eq!(pixels.len(), width * height * 3);
+    let mut out = format!("P6\n{} {}\n255\n", width, height).into_bytes();
+    out.extend_from_slice(pixels);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use ipc::generated::ColumnPos;
+
+    use super::*;
+
+    /// A beach going into the sea along x, with a hill behind it
+    fn beach() -> ColumnDetails {
+        ColumnDetails {
+            from: ColumnPos::new(10, -3),
+            to: ColumnPos::new(12, -2),
+            palette: vec![
+                "minecraft:water".to_owned(),
+                "minecraft:sand".to_owned(),
+                "minecraft:oak_leaves".to_owned(),
+                "minecraft:mystery".to_owned(),
+            ],
+            tops: vec![2, 1, 0, 3, 1, 0],
+            heights: vec![80, 63, 62, -64, 63, 400],
+        }
+    }
+
+    #[test]
+    fn heightmap_golden() {
+        let image = render_map(&beach(), MapKind::Height).expect("render failed");
+        let mut expected = b"P5\n3 2\n255\n".to_vec();
+        expected.extend_from_slice(&[95, 84, 83, 0, 84, 255]);
+        assert_eq!(image, expected);
+    }
+
+    #[test]
+    fn colour_map_golden() {
+        let image = render_map(&beach(), MapKind::Colour).expect("render failed");
+        let mut expected = b"P6\n3 2\n255\n".to_vec();
+        expected.extend_from_slice(&[
+            0, 124, 0, 247, 233, 163, 64, 64, 255, // hill, beach, sea
+            128, 128, 128, 247, 233, 163, 64, 64, 255, // unknown, beach, sea
+        ]);
+        assert_eq!(image, expected);
+    }
+
+    #[test]
+    fn missing_columns() {
+        let mut columns = beach();
+        columns.heights.pop();
+        assert!(render_map(&columns, MapKind::Height).is_none());
+
+        let mut columns = beach();
+        columns.tops[0] = 4;
+        assert!(render_map(&columns, MapKind::Colour).is_none());
+    }
+}
@@ -1,20 +1,35 @@
 use std::error::Error;
 use std::path::Path;
+use std::sync::Arc;
 
 use fuser::{BackgroundSession, MountOption, Session};
 use parking_lot::{Condvar, Mutex};
 
 use ipc::IpcChannel;
 
+use crate::events::SharedEvents;
 use crate::fuse::MinecraftFs;
+use crate::watch::{spawn_poller, SharedWatched};
 
-struct Mounter(BackgroundSession);
+/// Unmounts on drop
+struct Mounter(#[allow(dead_code)] BackgroundSession);
 pub struct MountStatus;
 
+#[derive(Default)]
+pub struct MountOptions {
+    /// Send the command for each command-backed file in getattr to report its real size, instead
+    /// of 0
+    pub exact_file_sizes: bool,
+}
+
 static MOUNTER: Mutex<Option<Mounter>> = parking_lot::const_mutex(None);
 static CVAR: Condvar = Condvar::new();
 
-pub fn mount(ipc: IpcChannel, path: &Path) -> Result<MountStatus, Box<dyn Error>> {
+pub fn mount(
+    ipc: IpcChannel,
+    path: &Path,
+    mount_opts: MountOptions,
+) -> Result<MountStatus, Box<dyn Error>> {
     if MOUNTER.lock().is_some() {
         return Err("Only 1 fs can be mounted at a time".into());
     }
@@ -33,7 +48,13 @@ pub fn mount(ipc: IpcChannel, path: &Path) -> Result<MountStatus, Box<dyn Error>
         MountOption::FSName("minecraft-fs".to_owned()),
         MountOption::RW,
     ];
-    let mnt = Session::new(MinecraftFs::new(ipc), path, &opts).and_then(|se| se.spawn())?;
+    let ipc = Arc::new(Mutex::new(ipc));
+    let watched = SharedWatched::default();
+    let events = SharedEvents::default();
+    let fs = MinecraftFs::new(ipc.clone(), watched.clone(), events.clone(), mount_opts);
+    let session = Session::new(fs, path, &opts)?;
+    spawn_poller(ipc, &watched, &events, session.notifier());
+    let mnt = session.spawn()?;
     {
         let mut guard = MOUNTER.lock();
         *guard = Some(Mounter(mnt));
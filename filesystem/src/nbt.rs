@@ -0,0 +1,278 @@
+//! Binary NBT, the game's format for structure files and other saved data
+
+use thiserror::Error;
+
+/// Same as the game's own limit
+pub const MAX_DEPTH: usize = 512;
+
+#[derive(Debug, Error)]
+pub enum NbtError {
+    #[error("Unexpected end of data")]
+    Truncated,
+
+    #[error("Unknown tag type {0}")]
+    UnknownTag(u8),
+
+    #[error("Tags are nested too deeply")]
+    TooDeep,
+}
+
+/// Every NBT tag type besides End, which only terminates compounds
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Tag {
+    Byte(i8),
+    Short(i16),
+    Int(i32),
+    Long(i64),
+    Float(f32),
+    Double(f64),
+    ByteArray(Vec<u8>),
+    String(String),
+    List(Vec<Tag>),
+    Compound(Vec<(String, Tag)>),
+    IntArray(Vec<i32>),
+    LongArray(Vec<i64>),
+}
+
+/// A named root tag, usually an unnamed compound. Anything after it is ignored
+pub fn read(data: &[u8]) -> Result<(String, Tag), NbtError> {
+    let mut reader = Reader(data);
+    let id = reader.u8()?;
+    let name = reader.string()?;
+    let tag = reader.payload(id, 0)?;
+    Ok((name, tag))
+}
+
+pub fn write(out: &mut Vec<u8>, name: &str, tag: &Tag) {
+    out.push(tag.id());
+    write_string(out, name);
+    write_payload(out, tag);
+}
+
+impl Tag {
+    fn id(&self) -> u8 {
+        match self {
+            Tag::Byte(_) => 1,
+            Tag::Short(_) => 2,
+            Tag::Int(_) => 3,
+            Tag::Long(_) => 4,
+            Tag::Float(_) => 5,
+            Tag::Double(_) => 6,
+            Tag::ByteArray(_) => 7,
+            Tag::String(_) => 8,
+            Tag::List(_) => 9,
+            Tag::Compound(_) => 10,
+            Tag::IntArray(_) => 11,
+            Tag::LongArray(_) => 12,
+        }
+    }
+
+    pub fn field(&self, name: &str) -> Option<&Tag> {
+        match self {
+            Tag::Compound(fields) => fields.iter().find(|(k, _)| k == name).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    pub fn as_list(&self) -> Option<&Vec<Tag>> {
+        match self {
+            Tag::List(list) => Some(list),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Tag::String(s) => Some(s),
+            _ => None,
+        }
+    }
+}
+
+fn write_string(out: &mut Vec<u8>, s: &str) {
+    // ids and property names are ascii, so this is the same as java's modified utf8
+    out.extend_from_slice(&(s.len() as u16).to_be_bytes());
+    out.extend_from_slice(s.as_bytes());
+}
+
+fn write_payload(out: &mut Vec<u8>, tag: &Tag) {
+    match tag {
+        Tag::Byte(b) => out.extend_from_slice(&b.to_be_bytes()),
+        Tag::Short(s) => out.extend_from_slice(&s.to_be_bytes()),
+        Tag::Int(i) => out.extend_from_slice(&i.to_be_bytes()),
+        Tag::Long(l) => out.extend_from_slice(&l.to_be_bytes()),
+        Tag::Float(f) => out.extend_from_slice(&f.to_be_bytes()),
+        Tag::Double(d) => out.extend_from_slice(&d.to_be_bytes()),
+        Tag::ByteArray(bytes) => {
+            out.extend_from_slice(&(bytes.len() as i32).to_be_bytes());
+            out.extend_from_slice(bytes);
+        }
+        Tag::String(s) => write_string(out, s),
+        Tag::List(list) => {
+            // empty lists are of End tags
+            out.push(list.first().map(Tag::id).unwrap_or(0));
+            out.extend_from_slice(&(list.len() as i32).to_be_bytes());
+            for elem in list {
+                write_payload(out, elem);
+            }
+        }
+        Tag::Compound(fields) => {
+            for (name, tag) in fields {
+                write(out, name, tag);
+            }
+            out.push(0);
+        }
+        Tag::IntArray(ints) => {
+            out.extend_from_slice(&(ints.len() as i32).to_be_bytes());
+            for i in ints {
+                out.extend_from_slice(&i.to_be_bytes());
+            }
+        }
+        Tag::LongArray(longs) => {
+            out.extend_from_slice(&(longs.len() as i32).to_be_bytes());
+            for l in longs {
+                out.extend_from_slice(&l.to_be_bytes());
+            }
+        }
+    }
+}
+
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn take<const N: usize>(&mut self) -> Result<[u8; N], NbtError> {
+        let bytes = self.take_slice(N)?;
+        Ok(bytes.try_into().expect("slice is the right length"))
+    }
+
+    fn take_slice(&mut self, n: usize) -> Result<&'a [u8], NbtError> {
+        if self.0.len() < n {
+            return Err(NbtError::Truncated);
+        }
+        let (taken, rest) = self.0.split_at(n);
+        self.0 = rest;
+        Ok(taken)
+    }
+
+    fn u8(&mut self) -> Result<u8, NbtError> {
+        self.take::<1>().map(|[b]| b)
+    }
+
+    /// Every element takes at least one byte, so a corrupt length can't allocate much
+    fn len(&mut self, elem_size: usize) -> Result<usize, NbtError> {
+        let len = i32::from_be_bytes(self.take()?).max(0) as usize;
+        if len.saturating_mul(elem_size) > self.0.len() {
+            return Err(NbtError::Truncated);
+        }
+        Ok(len)
+    }
+
+    fn string(&mut self) -> Result<String, NbtError> {
+        let len = u16::from_be_bytes(self.take()?) as usize;
+        let bytes = self.take_slice(len)?;
+        Ok(String::from_utf8_lossy(bytes).into_owned())
+    }
+
+    fn payload(&mut self, id: u8, depth: usize) -> Result<Tag, NbtError> {
+        if depth > MAX_DEPTH {
+            return Err(NbtError::TooDeep);
+        }
+
+        Ok(match id {
+            1 => Tag::Byte(i8::from_be_bytes(self.take()?)),
+            2 => Tag::Short(i16::from_be_bytes(self.take()?)),
+            3 => Tag::Int(i32::from_be_bytes(self.take()?)),
+            4 => Tag::Long(i64::from_be_bytes(self.take()?)),
+            5 => Tag::Float(f32::from_be_bytes(self.take()?)),
+            6 => Tag::Double(f64::from_be_bytes(self.take()?)),
+            7 => {
+                let len = self.len(1)?;
+                Tag::ByteArray(self.take_slice(len)?.to_vec())
+            }
+            8 => Tag::String(self.string()?),
+            9 => {
+                let elem_id = self.u8()?;
+                let len = self.len(1)?;
+                if elem_id == 0 {
+                    // the length of an empty list isn't always 0
+                    Tag::List(vec![])
+                } else {
+                    let list = (0..len)
+                        .map(|_| self.payload(elem_id, depth + 1))
+                        .collect::<Result<_, _>>()?;
+                    Tag::List(list)
+                }
+            }
+            10 => {
+                let mut fields = Vec::new();
+                loop {
+                    let id = self.u8()?;
+                    if id == 0 {
+                        break;
+                    }
+                    let name = self.string()?;
+                    fields.push((name, self.payload(id, depth + 1)?));
+                }
+                Tag::Compound(fields)
+            }
+            11 => {
+                let len = self.len(4)?;
+                let ints = (0..len)
+                    .map(|_| self.take().map(i32::from_be_bytes))
+                    .collect::<Result<_, _>>()?;
+                Tag::IntArray(ints)
+            }
+            12 => {
+                let len = self.len(8)?;
+                let longs = (0..len)
+                    .map(|_| self.take().map(i64::from_be_bytes))
+                    .collect::<Result<_, _>>()?;
+                Tag::LongArray(longs)
+            }
+            id => return Err(NbtError::UnknownTag(id)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tag_round_trip() {
+        let root = Tag::Compound(vec![
+            ("byte".to_owned(), Tag::Byte(-2)),
+            ("short".to_owned(), Tag::Short(300)),
+            ("long".to_owned(), Tag::Long(-1 << 40)),
+            ("float".to_owned(), Tag::Float(0.5)),
+            ("double".to_owned(), Tag::Double(-2.25)),
+            ("bytes".to_owned(), Tag::ByteArray(vec![1, 2, 255])),
+            (
+                "ints".to_owned(),
+                Tag::IntArray(vec![i32::MIN, 0, i32::MAX]),
+            ),
+            ("longs".to_owned(), Tag::LongArray(vec![5])),
+            ("empty".to_owned(), Tag::List(vec![])),
+            (
+                "nested".to_owned(),
+                Tag::List(vec![Tag::Compound(vec![(
+                    "name".to_owned(),
+                    Tag::String("hello".to_owned()),
+                )])]),
+            ),
+        ]);
+
+        let mut out = Vec::new();
+        write(&mut out, "root", &root);
+
+        let (name, tag) = read(&out).unwrap();
+        assert_eq!(name, "root");
+        assert_eq!(tag, root);
+
+        assert!(matches!(
+            read(&out[..out.len() - 1]),
+            Err(NbtError::Truncated)
+        ));
+        assert!(matches!(read(&[0, 0, 0]), Err(NbtError::UnknownTag(0))));
+    }
+}
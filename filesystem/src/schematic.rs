@@ -0,0 +1,342 @@
+//! Vanilla structure block `.nbt` files: gzipped NBT holding a palette of block states and the
+//! blocks using them, positioned relative to the minimum corner
+
+use std::io::{Read, Write};
+
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use thiserror::Error;
+
+use crate::nbt::{self, NbtError, Tag};
+
+/// Minecraft 1.18.2
+const DATA_VERSION: i32 = 2975;
+
+/// Decompressed NBT can't be larger than this. A full 64 block cube of blocks with no block entities
+/// is under 10MiB
+const MAX_DECOMPRESSED: u64 = 16 * 1024 * 1024;
+
+#[derive(Debug, PartialEq)]
+pub struct Schematic {
+    /// x, y, z
+    pub size: [i32; 3],
+    /// Block states, e.g. `minecraft:oak_stairs[facing=east,half=bottom]`
+    pub palette: Vec<String>,
+    /// Position relative to the minimum corner and index into `palette`
+    pub blocks: Vec<([i32; 3], usize)>,
+}
+
+#[derive(Debug, Error)]
+pub enum SchematicError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Invalid NBT: {0}")]
+    Nbt(#[from] NbtError),
+
+    #[error("Decompressed data is over {} bytes", MAX_DECOMPRESSED)]
+    TooLarge,
+
+    #[error("Missing or mistyped field '{0}'")]
+    BadField(&'static str),
+
+    #[error("Invalid block state '{0}'")]
+    BadBlockState(String),
+
+    #[error("Block at {0:?} is outside of the structure or uses a missing palette entry")]
+    BadBlock([i32; 3]),
+}
+
+impl Schematic {
+    /// Gzipped NBT
+    pub fn encode(&self) -> Vec<u8> {
+        let palette = self
+            .palette
+            .iter()
+            .map(|state| {
+                let (name, properties) = split_block_state(state);
+                let mut fields = vec![("Name".to_owned(), Tag::String(name.to_owned()))];
+                if !properties.is_empty() {
+                    let properties = properties
+                        .into_iter()
+                        .map(|(k, v)| (k.to_owned(), Tag::String(v.to_owned())))
+                        .collect();
+                    fields.push(("Properties".to_owned(), Tag::Compound(properties)));
+                }
+                Tag::Compound(fields)
+            })
+            .collect();
+
+        let blocks = self
+            .blocks
+            .iter()
+            .map(|(pos, state)| {
+                Tag::Compound(vec![
+                    ("pos".to_owned(), int_list(*pos)),
+                    ("state".to_owned(), Tag::Int(*state as i32)),
+                ])
+            })
+            .collect();
+
+        let root = Tag::Compound(vec![
+            ("DataVersion".to_owned(), Tag::Int(DATA_VERSION)),
+            ("size".to_owned(), int_list(self.size)),
+            ("palette".to_owned(), Tag::List(palette)),
+            ("blocks".to_owned(), Tag::List(blocks)),
+            ("entities".to_owned(), Tag::List(vec![])),
+        ]);
+
+        let mut nbt = Vec::new();
+        nbt::write(&mut nbt, "", &root);
+
+        let mut gz = GzEncoder::new(Vec::new(), Compression::default());
+        gz.write_all(&nbt).expect("writing to a vec can't fail");
+        gz.finish().expect("writing to a vec can't fail")
+    }
+
+    /// Gzipped or plain NBT. Block entity nbt and entities are ignored
+    pub fn decode(data: &[u8]) -> Result<Self, SchematicError> {
+        let mut unzipped = Vec::new();
+        let nbt = if data.starts_with(&[0x1f, 0x8b]) {
+            // one byte over the limit is enough to know it's too large
+            GzDecoder::new(data)
+                .take(MAX_DECOMPRESSED + 1)
+                .read_to_end(&mut unzipped)?;
+            if unzipped.len() as u64 > MAX_DECOMPRESSED {
+                return Err(SchematicError::TooLarge);
+            }
+            unzipped.as_slice()
+        } else {
+            data
+        };
+
+        let root = match nbt::read(nbt)? {
+            (_, root @ Tag::Compound(_)) => root,
+            _ => return Err(SchematicError::BadField("root")),
+        };
+
+        let size = root
+            .field("size")
+            .and_then(as_pos)
+            .ok_or(SchematicError::BadField("size"))?;
+
+        // structures with random variants have several palettes, just use the first
+        let palette = root
+            .field("palette")
+            .or_else(|| root.field("palettes")?.as_list()?.first())
+            .and_then(Tag::as_list)
+            .ok_or(SchematicError::BadField("palette"))?
+            .iter()
+            .map(|entry| {
+                let name = entry
+                    .field("Name")
+                    .and_then(Tag::as_str)
+                    .ok_or(SchematicError::BadField("Name"))?;
+                let properties = match entry.field("Properties") {
+                    Some(Tag::Compound(props)) => props
+                        .iter()
+                        .map(|(k, v)| match v {
+                            Tag::String(v) => Ok(format!("{}={}", k, v)),
+                            _ => Err(SchematicError::BadField("Properties")),
+                        })
+                        .collect::<Result<Vec<_>, _>>()?,
+                    None => vec![],
+                    _ => return Err(SchematicError::BadField("Properties")),
+                };
+
+                let state = if properties.is_empty() {
+                    name.to_owned()
+                } else {
+                    format!("{}[{}]", name, properties.join(","))
+                };
+
+                if is_valid_block_state(&state) {
+                    Ok(state)
+                } else {
+                    Err(SchematicError::BadBlockState(state))
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let blocks = root
+            .field("blocks")
+            .and_then(Tag::as_list)
+            .ok_or(SchematicError::BadField("blocks"))?
+            .iter()
+            .map(|block| {
+                let pos = block
+                    .field("pos")
+                    .and_then(as_pos)
+                    .ok_or(SchematicError::BadField("pos"))?;
+                let state = match block.field("state") {
+                    Some(Tag::Int(i)) => *i as usize,
+                    _ => return Err(SchematicError::BadField("state")),
+                };
+
+                let in_bounds = pos
+                    .iter()
+                    .zip(size.iter())
+                    .all(|(p, s)| (0..*s).contains(p));
+                if in_bounds && state < palette.len() {
+                    Ok((pos, state))
+                } else {
+                    Err(SchematicError::BadBlock(pos))
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            size,
+            palette,
+            blocks,
+        })
+    }
+}
+
+/// Lowercase ids and properties only, e.g. `minecraft:oak_stairs[facing=east,half=bottom]`
+fn is_valid_block_state(state: &str) -> bool {
+    let valid = |s: &str| {
+        !s.is_empty()
+            && s.chars()
+                .all(|c| matches!(c, 'a'..='z' | '0'..='9' | '_' | '-' | '.' | ':' | '/'))
+    };
+
+    let (name, properties) = match state.split_once('[') {
+        Some((name, rest)) => match rest.strip_suffix(']') {
+            Some(props) => (name, Some(props)),
+            None => return false,
+        },
+        None => (state, None),
+    };
+
+    valid(name)
+        && name.matches(':').count() <= 1
+        && properties.is_none_or(|props| {
+            props.split(',').all(|prop| {
+                prop.split_once('=')
+                    .is_some_and(|(k, v)| valid(k) && valid(v) && !k.contains(':'))
+            })
+        })
+}
+
+/// Name and (key, value) properties
+fn split_block_state(state: &str) -> (&str, Vec<(&str, &str)>) {
+    match state.split_once('[') {
+        Some((name, props)) => {
+            let props = props
+                .trim_end_matches(']')
+                .split(',')
+                .filter_map(|prop| prop.split_once('='))
+                .collect();
+            (name, props)
+        }
+        None => (state, vec![]),
+    }
+}
+
+fn int_list([x, y, z]: [i32; 3]) -> Tag {
+    Tag::List(vec![Tag::Int(x), Tag::Int(y), Tag::Int(z)])
+}
+
+/// A list of 3 ints, e.g. a block position
+fn as_pos(tag: &Tag) -> Option<[i32; 3]> {
+    match tag.as_list()?.as_slice() {
+        [Tag::Int(x), Tag::Int(y), Tag::Int(z)] => Some([*x, *y, *z]),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stairs() -> Schematic {
+        Schematic {
+            size: [2, 1, 2],
+            palette: vec![
+                "minecraft:air".to_owned(),
+                "minecraft:oak_stairs[facing=east,half=bottom]".to_owned(),
+            ],
+            blocks: vec![
+                ([0, 0, 0], 1),
+                ([1, 0, 0], 0),
+                ([0, 0, 1], 0),
+                ([1, 0, 1], 1),
+            ],
+        }
+    }
+
+    #[test]
+    fn schematic_round_trip() {
+        let schematic = stairs();
+        let encoded = schematic.encode();
+        assert!(encoded.starts_with(&[0x1f, 0x8b]));
+        assert_eq!(Schematic::decode(&encoded).unwrap(), schematic);
+    }
+
+    #[test]
+    fn bad_schematics() {
+        let encoded = stairs().encode();
+        assert!(Schematic::decode(&encoded[..encoded.len() / 2]).is_err());
+        assert!(Schematic::decode(b"").is_err());
+        assert!(Schematic::decode(b"definitely not nbt").is_err());
+
+        let out_of_bounds = Schematic {
+            blocks: vec![([2, 0, 0], 0)],
+            ..stairs()
+        };
+        assert!(matches!(
+            Schematic::decode(&out_of_bounds.encode()),
+            Err(SchematicError::BadBlock([2, 0, 0]))
+        ));
+
+        let bad_state = Schematic {
+            blocks: vec![([0, 0, 0], 2)],
+            ..stairs()
+        };
+        assert!(Schematic::decode(&bad_state.encode()).is_err());
+
+        let bad_name = Schematic {
+            palette: vec!["minecraft:stone\nkill".to_owned()],
+            blocks: vec![],
+            ..stairs()
+        };
+        assert!(matches!(
+            Schematic::decode(&bad_name.encode()),
+            Err(SchematicError::BadBlockState(_))
+        ));
+    }
+
+    #[test]
+    fn gzip_bomb() {
+        let mut gz = GzEncoder::new(Vec::new(), Compression::best());
+        let zeroes = vec![0; 1024 * 1024];
+        for _ in 0..=MAX_DECOMPRESSED / zeroes.len() as u64 {
+            gz.write_all(&zeroes).unwrap();
+        }
+        let bomb = gz.finish().unwrap();
+        assert!(bomb.len() < 64 * 1024);
+
+        assert!(matches!(
+            Schematic::decode(&bomb),
+            Err(SchematicError::TooLarge)
+        ));
+    }
+
+    #[test]
+    fn block_state_validation() {
+        assert!(is_valid_block_state("minecraft:stone"));
+        assert!(is_valid_block_state("stone"));
+        assert!(is_valid_block_state(
+            "minecraft:oak_stairs[facing=east,half=bottom]"
+        ));
+        assert!(!is_valid_block_state(""));
+        assert!(!is_valid_block_state("minecraft:Stone"));
+        assert!(!is_valid_block_state("a:b:c"));
+        assert!(!is_valid_block_state("minecraft:oak_stairs[facing=east"));
+        assert!(!is_valid_block_state("minecraft:oak_stairs[facing]"));
+        assert!(!is_valid_block_state("minecraft:oak_stairs[]"));
+        assert!(!is_valid_block_state("minecraft:stone 1 2 3"));
+    }
+}
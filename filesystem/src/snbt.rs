@@ -0,0 +1,798 @@
+//! Stringified NBT, as accepted by commands and output by the game. Parsed here so malformed
+//! input is rejected before reaching the game, and so it can be pretty-printed or offered as JSON
+
+use std::fmt::Write;
+use std::mem::discriminant;
+
+use thiserror::Error;
+
+use crate::nbt::{Tag, MAX_DEPTH};
+
+const INDENT: &str = "    ";
+
+#[derive(Debug, Error, PartialEq)]
+pub enum SnbtError {
+    #[error("Unexpected end of input")]
+    Truncated,
+
+    #[error("Expected {expected} at {pos}")]
+    Expected { expected: &'static str, pos: usize },
+
+    #[error("Invalid escape sequence at {0}")]
+    BadEscape(usize),
+
+    #[error("Invalid array type at {0}")]
+    BadArrayType(usize),
+
+    #[error("Mixed element types in list or array at {0}")]
+    MixedTypes(usize),
+
+    #[error("Tags are nested too deeply")]
+    TooDeep,
+}
+
+#[derive(Copy, Clone, PartialEq)]
+enum Style {
+    /// Single line, as sent to the game
+    Compact,
+    /// Indented with a field per line
+    Pretty,
+    /// Laid out like `Pretty`, losing the distinction between number types
+    Json,
+}
+
+/// Parses a single tag, following the game's rules for unquoted values, e.g. `1b` is a byte and
+/// `1.5` a double, falling back to a string
+pub fn parse(input: &str) -> Result<Tag, SnbtError> {
+    let mut parser = Parser { input, pos: 0 };
+    let tag = parser.value(0)?;
+
+    parser.skip_whitespace();
+    if parser.pos != input.len() {
+        return Err(SnbtError::Expected {
+            expected: "end of input",
+            pos: parser.pos,
+        });
+    }
+
+    Ok(tag)
+}
+
+/// Single line SNBT that the game can parse back
+pub fn to_compact(tag: &Tag) -> String {
+    let mut out = String::new();
+    write_tag(&mut out, tag, Style::Compact, 0);
+    out
+}
+
+/// Multi-line SNBT that the game can parse back, ending with a newline
+pub fn to_pretty(tag: &Tag) -> String {
+    let mut out = String::new();
+    write_tag(&mut out, tag, Style::Pretty, 0);
+    out.push('\n');
+    out
+}
+
+/// Multi-line JSON, ending with a newline. Numbers of all types become plain JSON numbers, and
+/// non-finite floats become null
+pub fn to_json(tag: &Tag) -> String {
+    let mut out = String::new();
+    write_tag(&mut out, tag, Style::Json, 0);
+    out.push('\n');
+    out
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    /// Byte offset into `input`
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<char> {
+        self.input[self.pos..].chars().next()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(c) = self.peek().filter(|c| c.is_whitespace()) {
+            self.pos += c.len_utf8();
+        }
+    }
+
+    fn expect(&mut self, c: char, expected: &'static str) -> Result<(), SnbtError> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(found) if found == c => {
+                self.pos += c.len_utf8();
+                Ok(())
+            }
+            Some(_) => Err(SnbtError::Expected {
+                expected,
+                pos: self.pos,
+            }),
+            None => Err(SnbtError::Truncated),
+        }
+    }
+
+    /// Consumes a separating comma if there is one. Like the game, a trailing comma before the
+    /// closing bracket is allowed
+    fn comma(&mut self) -> bool {
+        self.skip_whitespace();
+        let found = self.peek() == Some(',');
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    fn value(&mut self, depth: usize) -> Result<Tag, SnbtError> {
+        if depth > MAX_DEPTH {
+            return Err(SnbtError::TooDeep);
+        }
+
+        self.skip_whitespace();
+        match self.peek() {
+            None => Err(SnbtError::Truncated),
+            Some('{') => self.compound(depth),
+            Some('[') => self.list_or_array(depth),
+            Some('"' | '\'') => self.quoted().map(Tag::String),
+            Some(_) => {
+                let pos = self.pos;
+                match self.unquoted() {
+                    "" => Err(SnbtError::Expected {
+                        expected: "value",
+                        pos,
+                    }),
+                    word => Ok(parse_scalar(word)),
+                }
+            }
+        }
+    }
+
+    fn unquoted(&mut self) -> &'a str {
+        let start = self.pos;
+        while self.peek().is_some_and(is_unquoted_char) {
+            self.pos += 1;
+        }
+        &self.input[start..self.pos]
+    }
+
+    /// Only the quote itself and backslashes can be escaped
+    fn quoted(&mut self) -> Result<String, SnbtError> {
+        let quote = self.peek().ok_or(SnbtError::Truncated)?;
+        self.pos += 1;
+
+        let mut out = String::new();
+        let mut chars = self.input[self.pos..].char_indices();
+        loop {
+            match chars.next().ok_or(SnbtError::Truncated)? {
+                (i, '\\') => match chars.next().ok_or(SnbtError::Truncated)? {
+                    (_, c) if c == '\\' || c == quote => out.push(c),
+                    _ => return Err(SnbtError::BadEscape(self.pos + i)),
+                },
+                (i, c) if c == quote => {
+                    self.pos += i + 1;
+                    return Ok(out);
+                }
+                (_, c) => out.push(c),
+            }
+        }
+    }
+
+    fn key(&mut self) -> Result<String, SnbtError> {
+        self.skip_whitespace();
+        match self.peek() {
+            None => Err(SnbtError::Truncated),
+            Some('"' | '\'') => self.quoted(),
+            Some(_) => {
+                let pos = self.pos;
+                match self.unquoted() {
+                    "" => Err(SnbtError::Expected {
+                        expected: "key",
+                        pos,
+                    }),
+                    key => Ok(key.to_owned()),
+                }
+            }
+        }
+    }
+
+    fn compound(&mut self, depth: usize) -> Result<Tag, SnbtError> {
+        self.pos += 1; // {
+
+        let mut fields: Vec<(String, Tag)> = Vec::new();
+        loop {
+            self.skip_whitespace();
+            if self.peek() == Some('}') {
+                break;
+            }
+
+            let key = self.key()?;
+            self.expect(':', "':'")?;
+            let value = self.value(depth + 1)?;
+
+            // later duplicates win, like the game
+            match fields.iter_mut().find(|(k, _)| *k == key) {
+                Some((_, existing)) => *existing = value,
+                None => fields.push((key, value)),
+            }
+
+            if !self.comma() {
+                break;
+            }
+        }
+
+        self.expect('}', "',' or '}'")?;
+        Ok(Tag::Compound(fields))
+    }
+
+    /// Arrays are typed by a prefix directly after the bracket, e.g. `[I;1,2]`
+    fn list_or_array(&mut self, depth: usize) -> Result<Tag, SnbtError> {
+        self.pos += 1; // [
+
+        let array_type = match self.input.as_bytes()[self.pos..] {
+            [ty, b';', ..] if !matches!(ty, b'"' | b'\'') => {
+                if !matches!(ty, b'B' | b'I' | b'L') {
+                    return Err(SnbtError::BadArrayType(self.pos));
+                }
+                self.pos += 2;
+                Some(ty)
+            }
+            _ => None,
+        };
+
+        let mut elements = Vec::new();
+        loop {
+            self.skip_whitespace();
+            if self.peek() == Some(']') {
+                break;
+            }
+
+            let pos = self.pos;
+            let value = self.value(depth + 1)?;
+            if let Some((_, first)) = elements.first() {
+                if discriminant(first) != discriminant(&value) {
+                    return Err(SnbtError::MixedTypes(pos));
+                }
+            }
+            elements.push((pos, value));
+
+            if !self.comma() {
+                break;
+            }
+        }
+
+        self.expect(']', "',' or ']'")?;
+
+        // elements are all the same type, so only the first needs checking
+        let mismatch = |elements: &[(usize, Tag)]| SnbtError::MixedTypes(elements[0].0);
+        let values = elements.iter().map(|(_, tag)| tag);
+        let tag = match array_type {
+            None => Tag::List(elements.into_iter().map(|(_, tag)| tag).collect()),
+            Some(b'B') => Tag::ByteArray(
+                values
+                    .map(|tag| match tag {
+                        Tag::Byte(b) => Ok(*b as u8),
+                        _ => Err(mismatch(&elements)),
+                    })
+                    .collect::<Result<_, _>>()?,
+            ),
+            Some(b'I') => Tag::IntArray(
+                values
+                    .map(|tag| match tag {
+                        Tag::Int(i) => Ok(*i),
+                        _ => Err(mismatch(&elements)),
+                    })
+                    .collect::<Result<_, _>>()?,
+            ),
+            Some(_) => Tag::LongArray(
+                values
+                    .map(|tag| match tag {
+                        Tag::Long(l) => Ok(*l),
+                        _ => Err(mismatch(&elements)),
+                    })
+                    .collect::<Result<_, _>>()?,
+            ),
+        };
+
+        Ok(tag)
+    }
+}
+
+fn is_unquoted_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.' | '+')
+}
+
+/// `[-+]?(0|[1-9][0-9]*)`
+fn is_integer(s: &str) -> bool {
+    let digits = s.strip_prefix(['-', '+']).unwrap_or(s);
+    match digits.as_bytes() {
+        [b'0'] => true,
+        [b'1'..=b'9', rest @ ..] => rest.iter().all(u8::is_ascii_digit),
+        _ => false,
+    }
+}
+
+/// `[-+]?([0-9]+[.]?|[0-9]*[.][0-9]+)(e[-+]?[0-9]+)?`, where the dot is only optional if
+/// `needs_dot` is false
+fn is_decimal(s: &str, needs_dot: bool) -> bool {
+    let s = s.strip_prefix(['-', '+']).unwrap_or(s);
+    let (mantissa, exponent) = match s.find(['e', 'E']) {
+        Some(idx) => (&s[..idx], Some(&s[idx + 1..])),
+        None => (s, None),
+    };
+
+    let all_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+    let mantissa_ok = match mantissa.split_once('.') {
+        Some((int, frac)) => {
+            all_digits(int) && all_digits(frac) && !(int.is_empty() && frac.is_empty())
+        }
+        None => !needs_dot && !mantissa.is_empty() && all_digits(mantissa),
+    };
+    let exponent_ok = exponent.is_none_or(|exp| {
+        let exp = exp.strip_prefix(['-', '+']).unwrap_or(exp);
+        !exp.is_empty() && all_digits(exp)
+    });
+
+    mantissa_ok && exponent_ok
+}
+
+/// Unquoted values that aren't valid numbers, including those out of range, are strings
+fn parse_scalar(word: &str) -> Tag {
+    match word {
+        "true" => return Tag::Byte(1),
+        "false" => return Tag::Byte(0),
+        _ => {}
+    }
+
+    let (body, suffix) = word.split_at(word.len() - 1);
+    let number = match suffix {
+        "b" | "B" if is_integer(body) => body.parse().ok().map(Tag::Byte),
+        "s" | "S" if is_integer(body) => body.parse().ok().map(Tag::Short),
+        "l" | "L" if is_integer(body) => body.parse().ok().map(Tag::Long),
+        "f" | "F" if is_decimal(body, false) => body.parse().ok().map(Tag::Float),
+        "d" | "D" if is_decimal(body, false) => body.parse().ok().map(Tag::Double),
+        _ if is_decimal(word, true) => word.parse().ok().map(Tag::Double),
+        _ if is_integer(word) => word.parse().ok().map(Tag::Int),
+        _ => None,
+    };
+
+    number.unwrap_or_else(|| Tag::String(word.to_owned()))
+}
+
+fn write_tag(out: &mut String, tag: &Tag, style: Style, depth: usize) {
+    let json = style == Style::Json;
+    match tag {
+        Tag::Byte(b) if json => write!(out, "{}", b),
+        Tag::Short(s) if json => write!(out, "{}", s),
+        Tag::Long(l) if json => write!(out, "{}", l),
+        Tag::Float(f) if json => write_json_float(out, f, f.is_finite()),
+        Tag::Double(d) if json => write_json_float(out, d, d.is_finite()),
+        Tag::String(s) if json => {
+            write_json_string(out, s);
+            Ok(())
+        }
+        Tag::Byte(b) => write!(out, "{}b", b),
+        Tag::Short(s) => write!(out, "{}s", s),
+        Tag::Int(i) => write!(out, "{}", i),
+        Tag::Long(l) => write!(out, "{}L", l),
+        Tag::Float(f) => write!(out, "{:?}f", f),
+        Tag::Double(d) => write!(out, "{:?}d", d),
+        Tag::String(s) => {
+            write_snbt_string(out, s);
+            Ok(())
+        }
+        Tag::ByteArray(bytes) => {
+            let bytes = bytes.iter().map(|b| *b as i8);
+            write_array(out, "B", if json { "" } else { "B" }, bytes, style);
+            Ok(())
+        }
+        Tag::IntArray(ints) => {
+            write_array(out, "I", "", ints.iter(), style);
+            Ok(())
+        }
+        Tag::LongArray(longs) => {
+            write_array(out, "L", if json { "" } else { "L" }, longs.iter(), style);
+            Ok(())
+        }
+        Tag::List(list) => {
+            // lists of numbers or strings stay on one line
+            let expand = list
+                .iter()
+                .any(|tag| matches!(tag, Tag::Compound(_) | Tag::List(_)));
+            let expand = expand && style != Style::Compact;
+
+            out.push('[');
+            for (i, tag) in list.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                    if !expand && style != Style::Compact {
+                        out.push(' ');
+                    }
+                }
+                if expand {
+                    write_newline(out, depth + 1);
+                }
+                write_tag(out, tag, style, depth + 1);
+            }
+            if expand {
+                write_newline(out, depth);
+            }
+            out.push(']');
+            Ok(())
+        }
+        Tag::Compound(fields) => {
+            let expand = !fields.is_empty() && style != Style::Compact;
+
+            out.push('{');
+            for (i, (key, tag)) in fields.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                if expand {
+                    write_newline(out, depth + 1);
+                }
+
+                match style {
+                    Style::Json => write_json_string(out, key),
+                    _ if !key.is_empty() && key.chars().all(is_unquoted_char) => out.push_str(key),
+                    _ => write_snbt_string(out, key),
+                }
+                out.push_str(if style == Style::Compact { ":" } else { ": " });
+                write_tag(out, tag, style, depth + 1);
+            }
+            if expand {
+                write_newline(out, depth);
+            }
+            out.push('}');
+            Ok(())
+        }
+    }
+    .expect("writing to a string can't fail");
+}
+
+fn write_newline(out: &mut String, depth: usize) {
+    out.push('\n');
+    for _ in 0..depth {
+        out.push_str(INDENT);
+    }
+}
+
+/// e.g. `[I;1,2]`, or `[1, 2]` for JSON
+fn write_array<T: std::fmt::Display>(
+    out: &mut String,
+    prefix: &str,
+    suffix: &str,
+    values: impl Iterator<Item = T>,
+    style: Style,
+) {
+    out.push('[');
+    match style {
+        Style::Json => {}
+        Style::Compact => write!(out, "{};", prefix).unwrap(),
+        Style::Pretty => write!(out, "{}; ", prefix).unwrap(),
+    }
+
+    for (i, value) in values.enumerate() {
+        if i > 0 {
+            out.push_str(if style == Style::Compact { "," } else { ", " });
+        }
+        write!(out, "{}{}", value, suffix).unwrap();
+    }
+    out.push(']');
+}
+
+/// Quoted with whichever quote needs escaping less, like the game
+fn write_snbt_string(out: &mut String, s: &str) {
+    let quote = match s.chars().find(|c| matches!(c, '"' | '\'')) {
+        Some('"') => '\'',
+        _ => '"',
+    };
+
+    out.push(quote);
+    for c in s.chars() {
+        if c == quote || c == '\\' {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out.push(quote);
+}
+
+fn write_json_string(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+fn write_json_float(out: &mut String, f: impl std::fmt::Debug, finite: bool) -> std::fmt::Result {
+    if finite {
+        write!(out, "{:?}", f)
+    } else {
+        out.push_str("null");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn compound(fields: &[(&str, Tag)]) -> Tag {
+        Tag::Compound(
+            fields
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.clone()))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn scalars() {
+        assert_eq!(parse("1b"), Ok(Tag::Byte(1)));
+        assert_eq!(parse("-128B"), Ok(Tag::Byte(-128)));
+        assert_eq!(parse("true"), Ok(Tag::Byte(1)));
+        assert_eq!(parse("false"), Ok(Tag::Byte(0)));
+        assert_eq!(parse("300s"), Ok(Tag::Short(300)));
+        assert_eq!(parse("+5"), Ok(Tag::Int(5)));
+        assert_eq!(parse("-5"), Ok(Tag::Int(-5)));
+        assert_eq!(parse("0"), Ok(Tag::Int(0)));
+        assert_eq!(parse("9000000000L"), Ok(Tag::Long(9_000_000_000)));
+        assert_eq!(parse("0.5f"), Ok(Tag::Float(0.5)));
+        assert_eq!(parse("2F"), Ok(Tag::Float(2.0)));
+        assert_eq!(parse("1e3f"), Ok(Tag::Float(1000.0)));
+        assert_eq!(parse("1.5d"), Ok(Tag::Double(1.5)));
+        assert_eq!(parse("3d"), Ok(Tag::Double(3.0)));
+        assert_eq!(parse("1.5"), Ok(Tag::Double(1.5)));
+        assert_eq!(parse(".5"), Ok(Tag::Double(0.5)));
+        assert_eq!(parse("2."), Ok(Tag::Double(2.0)));
+        assert_eq!(parse("-1.5E2"), Ok(Tag::Double(-150.0)));
+        assert_eq!(parse("  7  "), Ok(Tag::Int(7)));
+    }
+
+    #[test]
+    fn unquoted_strings() {
+        let string = |s: &str| Ok(Tag::String(s.to_owned()));
+
+        assert_eq!(parse("minecraft:stone"), Err(trailing(9)));
+        assert_eq!(parse("zombie"), string("zombie"));
+        assert_eq!(parse("True"), string("True"));
+        // out of range
+        assert_eq!(parse("128b"), string("128b"));
+        assert_eq!(parse("99999999999"), string("99999999999"));
+        // not numbers
+        assert_eq!(parse("01"), string("01"));
+        assert_eq!(parse("1.2.3"), string("1.2.3"));
+        assert_eq!(parse("1e"), string("1e"));
+        assert_eq!(parse("."), string("."));
+        assert_eq!(parse("-"), string("-"));
+        assert_eq!(parse("1.5b"), string("1.5b"));
+        assert_eq!(parse("nand"), string("nand"));
+        assert_eq!(parse("inf"), string("inf"));
+    }
+
+    fn trailing(pos: usize) -> SnbtError {
+        SnbtError::Expected {
+            expected: "end of input",
+            pos,
+        }
+    }
+
+    #[test]
+    fn quoted_strings() {
+        let string = |s: &str| Ok(Tag::String(s.to_owned()));
+
+        assert_eq!(parse(r#""hello world""#), string("hello world"));
+        assert_eq!(parse("'minecraft:stone'"), string("minecraft:stone"));
+        assert_eq!(parse(r#""say \"hi\"""#), string(r#"say "hi""#));
+        assert_eq!(parse(r#"'it\'s'"#), string("it's"));
+        assert_eq!(parse(r#"'"quoted"'"#), string(r#""quoted""#));
+        assert_eq!(parse(r#""back\\slash""#), string(r"back\slash"));
+        assert_eq!(parse(r#""1b""#), string("1b"));
+        assert_eq!(parse(r#""ünïcödé ☃""#), string("ünïcödé ☃"));
+        assert_eq!(parse(r#""""#), string(""));
+
+        assert_eq!(parse(r#""\n""#), Err(SnbtError::BadEscape(1)));
+        assert_eq!(parse(r#""oops"#), Err(SnbtError::Truncated));
+        assert_eq!(parse(r#""oops\"#), Err(SnbtError::Truncated));
+    }
+
+    #[test]
+    fn compounds() {
+        assert_eq!(parse("{}"), Ok(compound(&[])));
+        assert_eq!(parse(" { } "), Ok(compound(&[])));
+        assert_eq!(
+            parse(r#"{Health: 20.0f, CustomName: '{"text":"Bob"}', "weird key": 1b,}"#),
+            Ok(compound(&[
+                ("Health", Tag::Float(20.0)),
+                ("CustomName", Tag::String(r#"{"text":"Bob"}"#.to_owned())),
+                ("weird key", Tag::Byte(1)),
+            ]))
+        );
+        assert_eq!(
+            parse("{a:{b:{c:1}}}"),
+            Ok(compound(&[(
+                "a",
+                compound(&[("b", compound(&[("c", Tag::Int(1))]))])
+            )]))
+        );
+        assert_eq!(
+            parse("{a:1,a:2}"),
+            Ok(compound(&[("a", Tag::Int(2))])),
+            "later duplicates should win"
+        );
+    }
+
+    #[test]
+    fn lists_and_arrays() {
+        assert_eq!(parse("[]"), Ok(Tag::List(vec![])));
+        assert_eq!(
+            parse("[1.5d, 64d, -2.5d]"),
+            Ok(Tag::List(vec![
+                Tag::Double(1.5),
+                Tag::Double(64.0),
+                Tag::Double(-2.5)
+            ]))
+        );
+        assert_eq!(
+            parse("[{id:a},{},]"),
+            Ok(Tag::List(vec![
+                compound(&[("id", Tag::String("a".to_owned()))]),
+                compound(&[])
+            ]))
+        );
+        assert_eq!(
+            parse("[[],[1]]"),
+            Ok(Tag::List(vec![
+                Tag::List(vec![]),
+                Tag::List(vec![Tag::Int(1)])
+            ]))
+        );
+        assert_eq!(
+            parse("[B;1b,-1b,true]"),
+            Ok(Tag::ByteArray(vec![1, 255, 1]))
+        );
+        assert_eq!(parse("[I; 1, 2, 3]"), Ok(Tag::IntArray(vec![1, 2, 3])));
+        assert_eq!(parse("[L;]"), Ok(Tag::LongArray(vec![])));
+        assert_eq!(parse("[L;5L]"), Ok(Tag::LongArray(vec![5])));
+        assert_eq!(
+            parse(r#"["I;x"]"#),
+            Ok(Tag::List(vec![Tag::String("I;x".to_owned())]))
+        );
+    }
+
+    #[test]
+    fn malformed() {
+        let expected = |expected, pos| Err(SnbtError::Expected { expected, pos });
+
+        assert_eq!(parse(""), Err(SnbtError::Truncated));
+        assert_eq!(parse("   "), Err(SnbtError::Truncated));
+        assert_eq!(parse("{"), Err(SnbtError::Truncated));
+        assert_eq!(parse("{a:"), Err(SnbtError::Truncated));
+        assert_eq!(parse("{a:1"), Err(SnbtError::Truncated));
+        assert_eq!(parse("[1,"), Err(SnbtError::Truncated));
+        assert_eq!(parse("{a 1}"), expected("':'", 3));
+        assert_eq!(parse("{a:1 b:2}"), expected("',' or '}'", 5));
+        assert_eq!(parse("{:1}"), expected("key", 1));
+        assert_eq!(parse("{a:}"), expected("value", 3));
+        assert_eq!(parse("[1 2]"), expected("',' or ']'", 3));
+        assert_eq!(parse("[,]"), expected("value", 1));
+        assert_eq!(parse("}"), expected("value", 0));
+        assert_eq!(parse("{}}"), Err(trailing(2)));
+        assert_eq!(parse("1 2"), Err(trailing(2)));
+
+        assert_eq!(parse("[1,2b]"), Err(SnbtError::MixedTypes(3)));
+        assert_eq!(parse("[{},[]]"), Err(SnbtError::MixedTypes(4)));
+        assert_eq!(parse("[I;1,2L]"), Err(SnbtError::MixedTypes(5)));
+        assert_eq!(parse("[B;1,2]"), Err(SnbtError::MixedTypes(3)));
+        assert_eq!(parse("[L;1]"), Err(SnbtError::MixedTypes(3)));
+        assert_eq!(parse("[X;1]"), Err(SnbtError::BadArrayType(1)));
+    }
+
+    #[test]
+    fn too_deep() {
+        let nested = |n| "[".repeat(n) + &"]".repeat(n);
+        assert!(parse(&nested(MAX_DEPTH)).is_ok());
+        assert_eq!(parse(&nested(MAX_DEPTH + 2)), Err(SnbtError::TooDeep));
+    }
+
+    fn example() -> Tag {
+        compound(&[
+            ("Air", Tag::Short(300)),
+            ("Health", Tag::Float(20.0)),
+            ("Pos", Tag::List(vec![Tag::Double(0.5), Tag::Double(64.0)])),
+            (
+                "Tags",
+                Tag::List(vec![Tag::String("it's \"fake\"".to_owned())]),
+            ),
+            (
+                "Passengers",
+                Tag::List(vec![compound(&[("id", Tag::String("pig".to_owned()))])]),
+            ),
+            ("UUID", Tag::IntArray(vec![1, -2])),
+            ("Bytes", Tag::ByteArray(vec![1, 255])),
+            ("Time", Tag::Long(5)),
+            ("Scale", Tag::Double(f64::INFINITY)),
+            ("Empty", compound(&[])),
+            ("key with spaces", Tag::Byte(0)),
+        ])
+    }
+
+    #[test]
+    fn compact() {
+        let expected = r#"{Air:300s,Health:20.0f,Pos:[0.5d,64.0d],Tags:["it's \"fake\""],Passengers:[{id:"pig"}],UUID:[I;1,-2],Bytes:[B;1B,-1B],Time:5L,Scale:infd,Empty:{},"key with spaces":0b}"#;
+        assert_eq!(to_compact(&example()), expected);
+    }
+
+    #[test]
+    fn pretty() {
+        let expected = r#"{
+    Air: 300s,
+    Health: 20.0f,
+    Pos: [0.5d, 64.0d],
+    Tags: ["it's \"fake\""],
+    Passengers: [
+        {
+            id: "pig"
+        }
+    ],
+    UUID: [I; 1, -2],
+    Bytes: [B; 1B, -1B],
+    Time: 5L,
+    Scale: infd,
+    Empty: {},
+    "key with spaces": 0b
+}
+"#;
+        assert_eq!(to_pretty(&example()), expected);
+    }
+
+    #[test]
+    fn json() {
+        let expected = r#"{
+    "Air": 300,
+    "Health": 20.0,
+    "Pos": [0.5, 64.0],
+    "Tags": ["it's \"fake\""],
+    "Passengers": [
+        {
+            "id": "pig"
+        }
+    ],
+    "UUID": [1, -2],
+    "Bytes": [1, -1],
+    "Time": 5,
+    "Scale": null,
+    "Empty": {},
+    "key with spaces": 0
+}
+"#;
+        assert_eq!(to_json(&example()), expected);
+
+        let mut control = String::new();
+        write_json_string(&mut control, "a\\b\n\u{1}");
+        assert_eq!(control, r#""a\\b\n\u0001""#);
+    }
+
+    #[test]
+    fn round_trip() {
+        // infinity doesn't survive, as in the game
+        let mut tag = example();
+        if let Tag::Compound(fields) = &mut tag {
+            fields.retain(|(k, _)| k != "Scale");
+        }
+
+        assert_eq!(parse(&to_compact(&tag)), Ok(tag.clone()));
+        assert_eq!(parse(&to_pretty(&tag)), Ok(tag));
+    }
+}
@@ -3,8 +3,8 @@ use std::time::{Duration, Instant};
 
 use log::{debug, trace};
 
-use ipc::generated::{BlockPos, Dimension, EntityDetails, StateRequestArgs};
-use ipc::{IpcChannel, IpcError};
+use ipc::generated::{BlockPos, ColumnPos, Dimension, GameRuleType, StateRequestArgs, Vec3};
+use ipc::{IpcChannel, IpcError, TargetEntity};
 
 const CACHE_TIME: Duration = Duration::from_millis(500);
 
@@ -14,31 +14,144 @@ pub struct GameState {
     pub player_world: Option<Dimension>,
     pub entities: Vec<EntityDetails>,
     pub block: Option<BlockDetails>,
+    pub inventory: Option<InventoryDetails>,
+    /// Active status effects of the target entity, e.g. `minecraft:speed`
+    pub effects: Vec<String>,
+    /// Attributes of the target entity, e.g. `minecraft:generic.max_health`
+    pub attributes: Vec<String>,
+    pub gamerules: Vec<GameRuleDetails>,
+    pub region: Option<RegionDetails>,
+    pub columns: Option<ColumnDetails>,
+    pub chunks: Vec<ChunkDetails>,
+    pub objectives: Vec<ObjectiveDetails>,
+}
+
+#[derive(Debug)]
+pub struct EntityDetails {
+    pub id: i32,
+    pub living: bool,
+    /// e.g. `minecraft:cow`
+    pub ty: String,
+    pub pos: Vec3,
+    /// The entity being ridden
+    pub vehicle: Option<i32>,
+    pub passengers: Vec<i32>,
 }
 
 #[derive(Debug)]
 pub struct BlockDetails {
     pub pos: BlockPos,
+    /// Names of the block state properties
+    pub properties: Vec<String>,
+    pub has_block_entity: bool,
+    /// Number of inventory slots, if a container
+    pub container_size: Option<i32>,
+}
+
+#[derive(Debug)]
+pub struct GameRuleDetails {
+    /// e.g. `doDaylightCycle`
+    pub name: String,
+    pub ty: GameRuleType,
+}
+
+/// Every block in a cuboid
+#[derive(Debug)]
+pub struct RegionDetails {
+    /// Minimum corner, inclusive
+    pub from: BlockPos,
+    /// Maximum corner, inclusive
+    pub to: BlockPos,
+    /// Block states, e.g. `minecraft:oak_stairs[facing=east,half=bottom]`
+    pub palette: Vec<String>,
+    /// Index into `palette` for every block, ordered by y, then z, then x
+    pub blocks: Vec<u16>,
+}
+
+/// The top block of every column in an area
+#[derive(Debug)]
+pub struct ColumnDetails {
+    /// Minimum corner, inclusive
+    pub from: ColumnPos,
+    /// Maximum corner, inclusive
+    pub to: ColumnPos,
+    /// e.g. `minecraft:grass_block`
+    pub palette: Vec<String>,
+    /// Index into `palette` of the top block of every column, ordered by z, then x
+    pub tops: Vec<u16>,
+    /// y of the top block of every column, in the same order
+    pub heights: Vec<i16>,
+}
+
+/// A loaded chunk
+#[derive(Debug)]
+pub struct ChunkDetails {
+    /// Chunk coordinates
+    pub x: i32,
+    pub z: i32,
+    /// Entities in the chunk
+    pub entities: Vec<i32>,
+}
+
+/// A scoreboard objective
+#[derive(Debug)]
+pub struct ObjectiveDetails {
+    pub name: String,
+    /// Everything with a score in the objective, e.g. player names and entity uuids
+    pub holders: Vec<String>,
+}
+
+/// Slot numbers of the target entity's inventory
+#[derive(Debug)]
+pub struct InventoryDetails {
+    pub size: i32,
+    pub selected: Option<i32>,
+    pub mainhand: Option<i32>,
+    pub offhand: Option<i32>,
+    pub head: Option<i32>,
+    pub chest: Option<i32>,
+    pub legs: Option<i32>,
+    pub feet: Option<i32>,
 }
 
 pub struct CachedGameState {
     last_query: Instant,
+    /// Known to be out of date regardless of its age
+    invalidated: bool,
     last_interest: GameStateInterest,
     state: GameState,
 }
 
 /// Maps to generated `StateRequestArgs`
-#[derive(Default, Debug)]
+#[derive(Default, Debug, Clone)]
 pub struct GameStateInterest {
     pub entities_by_id: bool,
     pub target_world: Option<Dimension>,
     pub target_block: Option<BlockPos>,
+    pub target_entity: Option<TargetEntity>,
+    pub inventory: bool,
+    /// Status effects of `target_entity`
+    pub effects: bool,
+    /// Attributes of `target_entity`
+    pub attributes: bool,
+    /// Only entities within the radius of this position, requires `target_world`
+    pub entities_near: Option<(Vec3, f32)>,
+    pub gamerules: bool,
+    /// All blocks between these corners inclusive, requires `target_world`
+    pub region: Option<(BlockPos, BlockPos)>,
+    /// Top blocks of all columns between these corners inclusive, requires `target_world`
+    pub columns: Option<(ColumnPos, ColumnPos)>,
+    /// Loaded chunks, requires `target_world`
+    pub chunks: bool,
+    /// Scoreboard objectives and their score holders
+    pub objectives: bool,
 }
 
 impl Default for CachedGameState {
     fn default() -> Self {
         Self {
             last_query: Instant::now(),
+            invalidated: false,
             state: GameState::default(),
             last_interest: GameStateInterest::default(),
         }
@@ -46,11 +159,27 @@ impl Default for CachedGameState {
 }
 
 impl GameStateInterest {
-    pub fn as_state_request_args(&self) -> StateRequestArgs {
+    pub fn as_state_request_args(&self) -> StateRequestArgs<'_> {
+        let (target_entity, target_player_entity) =
+            TargetEntity::as_request_fields(self.target_entity);
         StateRequestArgs {
             entities_by_id: self.entities_by_id,
             target_world: self.target_world,
             target_block: self.target_block.as_ref(),
+            target_entity,
+            target_player_entity,
+            inventory: self.inventory,
+            effects: self.effects,
+            attributes: self.attributes,
+            entities_near: self.entities_near.as_ref().map(|(pos, _)| pos),
+            entities_radius: self.entities_near.map(|(_, radius)| radius),
+            gamerules: self.gamerules,
+            region_from: self.region.as_ref().map(|(from, _)| from),
+            region_to: self.region.as_ref().map(|(_, to)| to),
+            columns_from: self.columns.as_ref().map(|(from, _)| from),
+            columns_to: self.columns.as_ref().map(|(_, to)| to),
+            chunks: self.chunks,
+            objectives: self.objectives,
         }
     }
 }
@@ -61,6 +190,64 @@ impl GameState {
     }
 }
 
+impl ColumnDetails {
+    /// Width along x and height along z
+    pub fn size(&self) -> (usize, usize) {
+        let width = self.to.x() - self.from.x() + 1;
+        let height = self.to.z() - self.from.z() + 1;
+        (width.max(0) as usize, height.max(0) as usize)
+    }
+
+    /// Top block type and its y of every column ordered by z, then x, or None if any are missing
+    pub fn top_blocks(&self) -> Option<Vec<(&str, i16)>> {
+        let (width, height) = self.size();
+        if self.tops.len() != width * height || self.heights.len() != width * height {
+            return None;
+        }
+
+        self.tops
+            .iter()
+            .zip(self.heights.iter())
+            .map(|(top, y)| self.palette.get(*top as usize).map(|ty| (ty.as_str(), *y)))
+            .collect()
+    }
+}
+
+impl RegionDetails {
+    /// Size along each axis
+    pub fn size(&self) -> [i32; 3] {
+        [
+            self.to.x() - self.from.x() + 1,
+            self.to.y() - self.from.y() + 1,
+            self.to.z() - self.from.z() + 1,
+        ]
+    }
+
+    /// Absolute position and palette index of every block, or None if any are missing
+    pub fn positioned_blocks(&self) -> Option<Vec<([i32; 3], usize)>> {
+        let [sx, sy, sz] = self.size();
+        if self.blocks.len() != (sx * sy * sz) as usize {
+            return None;
+        }
+
+        let mut blocks = self.blocks.iter().map(|i| *i as usize);
+        let mut out = Vec::with_capacity(self.blocks.len());
+        for y in self.from.y()..=self.to.y() {
+            for z in self.from.z()..=self.to.z() {
+                for x in self.from.x()..=self.to.x() {
+                    let idx = blocks.next()?;
+                    if idx >= self.palette.len() {
+                        return None;
+                    }
+                    out.push(([x, y, z], idx));
+                }
+            }
+        }
+
+        Some(out)
+    }
+}
+
 impl CachedGameState {
     pub fn get(
         &mut self,
@@ -68,7 +255,7 @@ impl CachedGameState {
         interest: GameStateInterest,
     ) -> Result<&GameState, IpcError> {
         let now = Instant::now();
-        let stale = now.duration_since(self.last_query) > CACHE_TIME;
+        let stale = self.invalidated || now.duration_since(self.last_query) > CACHE_TIME;
 
         log::debug!("getting state for interest: {:?}", interest);
         let additive = self.last_interest.is_additive(&interest);
@@ -91,13 +278,107 @@ impl CachedGameState {
                 player_world: response.player_world(),
                 entities: response
                     .entities()
-                    .map(|v| v.iter().copied().collect())
+                    .map(|v| {
+                        v.iter()
+                            .map(|e| EntityDetails {
+                                id: e.id(),
+                                living: e.living(),
+                                ty: e.ty().to_owned(),
+                                pos: *e.pos(),
+                                vehicle: e.vehicle(),
+                                passengers: e
+                                    .passengers()
+                                    .map(|v| v.iter().collect())
+                                    .unwrap_or_default(),
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+                block: response.block().map(|b| BlockDetails {
+                    pos: *b.pos(),
+                    properties: b
+                        .properties()
+                        .map(|v| v.iter().map(str::to_owned).collect())
+                        .unwrap_or_default(),
+                    has_block_entity: b.has_block_entity(),
+                    container_size: b.container_size(),
+                }),
+                inventory: response.inventory().map(|inv| InventoryDetails {
+                    size: inv.size(),
+                    selected: inv.selected(),
+                    mainhand: inv.mainhand(),
+                    offhand: inv.offhand(),
+                    head: inv.head(),
+                    chest: inv.chest(),
+                    legs: inv.legs(),
+                    feet: inv.feet(),
+                }),
+                effects: response
+                    .effects()
+                    .map(|v| v.iter().map(str::to_owned).collect())
+                    .unwrap_or_default(),
+                attributes: response
+                    .attributes()
+                    .map(|v| v.iter().map(str::to_owned).collect())
+                    .unwrap_or_default(),
+                gamerules: response
+                    .gamerules()
+                    .map(|v| {
+                        v.iter()
+                            .map(|rule| GameRuleDetails {
+                                name: rule.name().to_owned(),
+                                ty: rule.ty(),
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+                region: response.region().map(|r| RegionDetails {
+                    from: *r.from(),
+                    to: *r.to(),
+                    palette: r.palette().iter().map(str::to_owned).collect(),
+                    blocks: r.blocks().iter().collect(),
+                }),
+                columns: response.columns().map(|c| ColumnDetails {
+                    from: *c.from(),
+                    to: *c.to(),
+                    palette: c.palette().iter().map(str::to_owned).collect(),
+                    tops: c.tops().iter().collect(),
+                    heights: c.heights().iter().collect(),
+                }),
+                chunks: response
+                    .chunks()
+                    .map(|v| {
+                        v.iter()
+                            .map(|c| ChunkDetails {
+                                x: c.x(),
+                                z: c.z(),
+                                entities: c
+                                    .entities()
+                                    .map(|v| v.iter().collect())
+                                    .unwrap_or_default(),
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+                objectives: response
+                    .objectives()
+                    .map(|v| {
+                        v.iter()
+                            .map(|o| ObjectiveDetails {
+                                name: o.name().to_owned(),
+                                holders: o
+                                    .holders()
+                                    .map(|v| v.iter().map(str::to_owned).collect())
+                                    .unwrap_or_default(),
+                            })
+                            .collect()
+                    })
                     .unwrap_or_default(),
-                block: response.block().map(|b| BlockDetails { pos: *b.pos() }),
             };
             trace!("new game state: {:?}", self.state);
             self.last_query = now;
             self.last_interest = interest;
+            self.invalidated = false;
         } else {
             debug!("using cached state for interest");
             trace!("previous interest: {:?}", self.last_interest);
@@ -105,6 +386,11 @@ impl CachedGameState {
 
         Ok(&self.state)
     }
+
+    /// Queries the game on the next access, e.g. after making a change the state should reflect
+    pub fn invalidate(&mut self) {
+        self.invalidated = true;
+    }
 }
 
 impl GameStateInterest {
@@ -113,6 +399,11 @@ impl GameStateInterest {
             return true;
         }
 
+        if newer.entities_by_id && self.entities_near != newer.entities_near {
+            // a different subset of entities
+            return true;
+        }
+
         if newer.target_block.is_some() {
             // only bother checking if we now care about target block
             if self.target_block != newer.target_block {
@@ -120,6 +411,195 @@ impl GameStateInterest {
             }
         }
 
+        if newer.region.is_some()
+            && (self.region != newer.region || self.target_world != newer.target_world)
+        {
+            return true;
+        }
+
+        if newer.columns.is_some()
+            && (self.columns != newer.columns || self.target_world != newer.target_world)
+        {
+            return true;
+        }
+
+        if newer.chunks && (!self.chunks || self.target_world != newer.target_world) {
+            return true;
+        }
+
+        if newer.gamerules && !self.gamerules {
+            return true;
+        }
+
+        if newer.objectives && !self.objectives {
+            return true;
+        }
+
+        if newer.inventory && (!self.inventory || self.target_entity != newer.target_entity) {
+            return true;
+        }
+
+        if newer.effects && (!self.effects || self.target_entity != newer.target_entity) {
+            return true;
+        }
+
+        if newer.attributes && (!self.attributes || self.target_entity != newer.target_entity) {
+            return true;
+        }
+
         false
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inventory_interest() {
+        let player = GameStateInterest {
+            target_entity: Some(TargetEntity::Player),
+            inventory: true,
+            ..GameStateInterest::default()
+        };
+        let entity = GameStateInterest {
+            target_entity: Some(TargetEntity::Entity(50)),
+            ..player.clone()
+        };
+
+        assert!(GameStateInterest::default().is_additive(&player));
+        assert!(!player.is_additive(&player));
+        assert!(player.is_additive(&entity));
+
+        // the cached inventory is still valid if it's not wanted
+        assert!(!player.is_additive(&GameStateInterest::default()));
+    }
+
+    #[test]
+    fn effects_interest() {
+        let player = GameStateInterest {
+            target_entity: Some(TargetEntity::Player),
+            effects: true,
+            ..GameStateInterest::default()
+        };
+        let entity = GameStateInterest {
+            target_entity: Some(TargetEntity::Entity(50)),
+            ..player.clone()
+        };
+        let inventory = GameStateInterest {
+            inventory: true,
+            effects: false,
+            ..player.clone()
+        };
+        let attributes = GameStateInterest {
+            attributes: true,
+            effects: false,
+            ..player.clone()
+        };
+
+        assert!(GameStateInterest::default().is_additive(&player));
+        assert!(!player.is_additive(&player));
+        assert!(player.is_additive(&entity));
+        assert!(player.is_additive(&inventory));
+        assert!(player.is_additive(&attributes));
+        assert!(!attributes.is_additive(&attributes));
+    }
+
+    #[test]
+    fn entities_near_interest() {
+        let all = GameStateInterest {
+            entities_by_id: true,
+            ..GameStateInterest::default()
+        };
+        let near = GameStateInterest {
+            entities_near: Some((Vec3::new(10.0, 64.0, -5.0), 8.0)),
+            target_world: Some(Dimension::Overworld),
+            ..all.clone()
+        };
+
+        assert!(all.is_additive(&near));
+        assert!(!near.is_additive(&near));
+
+        // the cached entities are only a subset
+        assert!(near.is_additive(&all));
+    }
+
+    #[test]
+    fn region_interest() {
+        let region = GameStateInterest {
+            target_world: Some(Dimension::Overworld),
+            region: Some((BlockPos::new(0, 60, 0), BlockPos::new(4, 64, 4))),
+            ..GameStateInterest::default()
+        };
+        let other_world = GameStateInterest {
+            target_world: Some(Dimension::Nether),
+            ..region.clone()
+        };
+
+        assert!(GameStateInterest::default().is_additive(&region));
+        assert!(!region.is_additive(&region));
+        assert!(region.is_additive(&other_world));
+        assert!(!region.is_additive(&GameStateInterest::default()));
+    }
+
+    #[test]
+    fn columns_interest() {
+        let columns = GameStateInterest {
+            target_world: Some(Dimension::Overworld),
+            columns: Some((ColumnPos::new(-8, -8), ColumnPos::new(8, 8))),
+            ..GameStateInterest::default()
+        };
+        let other_area = GameStateInterest {
+            columns: Some((ColumnPos::new(0, 0), ColumnPos::new(8, 8))),
+            ..columns.clone()
+        };
+
+        assert!(GameStateInterest::default().is_additive(&columns));
+        assert!(!columns.is_additive(&columns));
+        assert!(columns.is_additive(&other_area));
+        assert!(!columns.is_additive(&GameStateInterest::default()));
+    }
+
+    #[test]
+    fn chunks_interest() {
+        let chunks = GameStateInterest {
+            target_world: Some(Dimension::Overworld),
+            chunks: true,
+            ..GameStateInterest::default()
+        };
+        let other_world = GameStateInterest {
+            target_world: Some(Dimension::End),
+            ..chunks.clone()
+        };
+        let entities = GameStateInterest {
+            entities_by_id: true,
+            chunks: false,
+            ..chunks.clone()
+        };
+
+        assert!(GameStateInterest::default().is_additive(&chunks));
+        assert!(!chunks.is_additive(&chunks));
+        assert!(chunks.is_additive(&other_world));
+
+        // the chunk list doesn't need the entities, and vice versa
+        assert!(chunks.is_additive(&entities));
+        assert!(entities.is_additive(&chunks));
+    }
+
+    #[test]
+    fn objectives_interest() {
+        let objectives = GameStateInterest {
+            objectives: true,
+            ..GameStateInterest::default()
+        };
+        let gamerules = GameStateInterest {
+            gamerules: true,
+            ..GameStateInterest::default()
+        };
+
+        assert!(GameStateInterest::default().is_additive(&objectives));
+        assert!(!objectives.is_additive(&objectives));
+        assert!(objectives.is_additive(&gamerules));
+        assert!(!objectives.is_additive(&GameStateInterest::default()));
+    }
+}
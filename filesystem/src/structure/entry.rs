@@ -1,11 +1,13 @@
 use std::any::{Any, TypeId};
 use std::borrow::Cow;
 
-use ipc::generated::{BlockPos, Dimension};
+use ipc::generated::{BlockPos, ColumnPos, CommandType, Dimension};
 use ipc::{CommandState, TargetEntity};
 
 use crate::state::{GameState, GameStateInterest};
-use crate::structure::registry::{DynamicDirRegistrationer, DynamicStateType, PhantomChildType};
+use crate::structure::registry::{
+    DynamicDirRegistrationer, DynamicStateType, EntitiesNear, PhantomChildType,
+};
 use crate::structure::{EntryFilterResult, FileBehaviour};
 
 pub type DynamicDirFn = fn(&GameState, &mut DynamicDirRegistrationer);
@@ -26,6 +28,12 @@ pub struct DirEntry {
     dynamic: Option<(DynamicStateType, DynamicDirFn)>,
     associated_data: Option<EntryAssociatedData>,
     filter: Option<DirFilterFn>,
+    /// Sent as a write of the dir's name when it's removed with rmdir
+    remove_cmd: Option<CommandType>,
+    /// Sent as a write of the new child's name when one is made with mkdir
+    create_cmd: Option<CommandType>,
+    /// Children are removable with rmdir, which needs this dir to be writable
+    removable_children: bool,
 }
 
 pub struct FileEntry {
@@ -47,12 +55,32 @@ pub struct FileEntryBuilder(FileEntry);
 
 pub struct LinkEntryBuilder(LinkEntry);
 
-#[derive(PartialEq, Debug, Copy, Clone)]
+#[derive(PartialEq, Debug, Clone)]
 pub enum EntryAssociatedData {
     PlayerId,
     EntityId(i32),
     World(Dimension),
     Block(BlockPos),
+    InventorySlot(i32),
+    EntitiesNear(EntitiesNear),
+    /// Block state property name
+    BlockProperty(Cow<'static, str>),
+    /// Game rule name
+    GameRule(Cow<'static, str>),
+    /// Minimum and maximum corners
+    Region([i32; 6]),
+    /// Minimum and maximum x, z corners
+    Columns([i32; 4]),
+    /// Chunk coordinates
+    Chunk(ColumnPos),
+    /// Status effect name
+    Effect(Cow<'static, str>),
+    /// Attribute name
+    Attribute(Cow<'static, str>),
+    /// Scoreboard objective name
+    Objective(Cow<'static, str>),
+    /// Scoreboard entry, e.g. a player name or entity uuid
+    ScoreHolder(Cow<'static, str>),
 }
 
 impl Entry {
@@ -66,7 +94,6 @@ impl Entry {
 
 impl FileEntryBuilder {
     /// Overrides parent directory
-    #[allow(dead_code)]
     pub fn associated_data(mut self, data: EntryAssociatedData) -> Self {
         self.0.associated_data = Some(data);
         self
@@ -95,8 +122,8 @@ impl FileEntry {
         &self.behaviour
     }
 
-    pub fn associated_data(&self) -> Option<EntryAssociatedData> {
-        self.associated_data
+    pub fn associated_data(&self) -> Option<&EntryAssociatedData> {
+        self.associated_data.as_ref()
     }
 }
 
@@ -141,8 +168,25 @@ impl DirEntry {
         self.dynamic
     }
 
-    pub fn associated_data(&self) -> Option<EntryAssociatedData> {
-        self.associated_data
+    pub fn associated_data(&self) -> Option<&EntryAssociatedData> {
+        self.associated_data.as_ref()
+    }
+
+    pub fn remove_cmd(&self) -> Option<CommandType> {
+        self.remove_cmd
+    }
+
+    pub fn create_cmd(&self) -> Option<CommandType> {
+        self.create_cmd
+    }
+
+    /// Writable if children can be created or removed
+    pub fn perm(&self) -> u16 {
+        if self.create_cmd.is_some() || self.removable_children {
+            0o755
+        } else {
+            0o555
+        }
     }
 }
 
@@ -162,6 +206,21 @@ impl DirEntryBuilder {
         self
     }
 
+    pub fn removable(mut self, cmd: CommandType) -> Self {
+        self.0.remove_cmd = Some(cmd);
+        self
+    }
+
+    pub fn creatable(mut self, cmd: CommandType) -> Self {
+        self.0.create_cmd = Some(cmd);
+        self
+    }
+
+    pub fn removable_children(mut self) -> Self {
+        self.0.removable_children = true;
+        self
+    }
+
     pub fn finish(self) -> DirEntry {
         self.0
     }
@@ -190,6 +249,49 @@ impl EntryAssociatedData {
                     state.target_block = Some(*pos)
                 }
             }
+            EntryAssociatedData::InventorySlot(slot) => {
+                if state.target_slot.is_none() {
+                    state.target_slot = Some(*slot)
+                }
+            }
+            EntryAssociatedData::EntitiesNear(_)
+            | EntryAssociatedData::Region(_)
+            | EntryAssociatedData::Columns(_) => {}
+            EntryAssociatedData::BlockProperty(name) => {
+                if state.target_property.is_none() {
+                    state.target_property = Some(name.as_ref().to_owned())
+                }
+            }
+            EntryAssociatedData::GameRule(name) => {
+                if state.target_gamerule.is_none() {
+                    state.target_gamerule = Some(name.as_ref().to_owned())
+                }
+            }
+            EntryAssociatedData::Chunk(pos) => {
+                if state.target_chunk.is_none() {
+                    state.target_chunk = Some(*pos)
+                }
+            }
+            EntryAssociatedData::Effect(name) => {
+                if state.target_effect.is_none() {
+                    state.target_effect = Some(name.as_ref().to_owned())
+                }
+            }
+            EntryAssociatedData::Attribute(name) => {
+                if state.target_attribute.is_none() {
+                    state.target_attribute = Some(name.as_ref().to_owned())
+                }
+            }
+            EntryAssociatedData::Objective(name) => {
+                if state.target_objective.is_none() {
+                    state.target_objective = Some(name.as_ref().to_owned())
+                }
+            }
+            EntryAssociatedData::ScoreHolder(name) => {
+                if state.target_score_holder.is_none() {
+                    state.target_score_holder = Some(name.as_ref().to_owned())
+                }
+            }
         }
     }
 
@@ -206,8 +308,43 @@ impl EntryAssociatedData {
                 }
             }
 
-            EntryAssociatedData::PlayerId => {}
-            EntryAssociatedData::EntityId(_) => {}
+            EntryAssociatedData::PlayerId => {
+                if interest.target_entity.is_none() {
+                    interest.target_entity = Some(TargetEntity::Player)
+                }
+            }
+            EntryAssociatedData::EntityId(id) => {
+                if interest.target_entity.is_none() {
+                    interest.target_entity = Some(TargetEntity::Entity(*id))
+                }
+            }
+            EntryAssociatedData::InventorySlot(_)
+            | EntryAssociatedData::BlockProperty(_)
+            | EntryAssociatedData::GameRule(_)
+            | EntryAssociatedData::Chunk(_)
+            | EntryAssociatedData::Effect(_)
+            | EntryAssociatedData::Attribute(_)
+            | EntryAssociatedData::Objective(_)
+            | EntryAssociatedData::ScoreHolder(_) => {}
+            EntryAssociatedData::EntitiesNear(near) => {
+                if interest.entities_near.is_none() {
+                    interest.entities_near = Some((near.centre(), near.radius() as f32))
+                }
+            }
+            EntryAssociatedData::Region([x1, y1, z1, x2, y2, z2]) => {
+                if interest.region.is_none() {
+                    let from = BlockPos::new(*x1, *y1, *z1);
+                    let to = BlockPos::new(*x2, *y2, *z2);
+                    interest.region = Some((from, to))
+                }
+            }
+            EntryAssociatedData::Columns([x1, z1, x2, z2]) => {
+                if interest.columns.is_none() {
+                    let from = ColumnPos::new(*x1, *z1);
+                    let to = ColumnPos::new(*x2, *z2);
+                    interest.columns = Some((from, to))
+                }
+            }
         }
     }
 }
@@ -218,6 +355,9 @@ impl From<PhantomChildType> for EntryAssociatedData {
             PhantomChildType::Block([x, y, z]) => {
                 EntryAssociatedData::Block(BlockPos::new(x, y, z))
             }
+            PhantomChildType::EntitiesNear(near) => EntryAssociatedData::EntitiesNear(near),
+            PhantomChildType::Region(region) => EntryAssociatedData::Region(region),
+            PhantomChildType::Map(area, _) => EntryAssociatedData::Columns(area),
         }
     }
 }
@@ -298,6 +438,9 @@ mod entry_impls {
         fn eq(&self, other: &Self) -> bool {
             self.associated_data == other.associated_data
                 && cmp_fn_ptrs!(self.filter, other.filter)
+                && self.remove_cmd == other.remove_cmd
+                && self.create_cmd == other.create_cmd
+                && self.removable_children == other.removable_children
                 && match (self.dynamic, other.dynamic) {
                     (Some((ty_a, fn_a)), Some((ty_b, fn_b))) => {
                         ty_a == ty_b && std::ptr::eq(fn_a as *const (), fn_b as *const ())
@@ -342,6 +485,9 @@ mod entry_impls {
                 )
                 .field("associated_data", &self.associated_data)
                 .field("filter", &debug_fn!(self.filter))
+                .field("remove_cmd", &self.remove_cmd)
+                .field("create_cmd", &self.create_cmd)
+                .field("removable_children", &self.removable_children)
                 .finish()
         }
     }
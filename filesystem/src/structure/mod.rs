@@ -1,5 +1,5 @@
-pub use entry::{DirEntry, Entry, EntryAssociatedData, FileEntry};
-pub use registry::{EntryFilterResult, FileBehaviour, FilesystemStructure};
+pub use entry::Entry;
+pub use registry::{EntryFilterResult, FileBehaviour, FilesystemStructure, StateRenderFn};
 pub use structure::create_structure;
 
 mod entry;
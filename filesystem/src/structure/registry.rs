@@ -8,12 +8,13 @@ use log::*;
 use smallvec::{smallvec, SmallVec};
 use strum::{EnumIter, IntoEnumIterator};
 
-use ipc::generated::{BlockPos, CommandType};
+use ipc::generated::{BlockPos, CommandType, Vec3};
 use ipc::{BodyType, CommandState};
 
+use crate::map::MapKind;
 use crate::state::{GameState, GameStateInterest};
 use crate::structure::entry::{
-    DirEntry, DynamicDirFn, Entry, EntryAssociatedData, PhantomDynamicInterestFn,
+    DirEntry, DynamicDirFn, Entry, EntryAssociatedData, FileEntry, PhantomDynamicInterestFn,
 };
 use crate::structure::inode::InodeBlockAllocator;
 
@@ -38,29 +39,72 @@ struct StructureInner {
     /// owning dir inode -> _
     dynamic_state: HashMap<(u64, DynamicStateType), DynamicState>,
 
-    phantom_registry: HashMap<u64, (PhantomChildFn, PhantomDynamicInterestFn, DynamicDirFn)>,
+    phantom_registry: HashMap<u64, (PhantomChildFn, PhantomChild)>,
+
+    /// phantom file -> lookups the kernel hasn't forgotten yet. Phantom files only live as long
+    /// as the kernel remembers them, as any number of names can be made under a phantom dir
+    phantom_file_lookups: HashMap<u64, u64>,
 }
 
 pub type PhantomChildFn = fn(&str) -> Option<PhantomChildType>;
 
+/// Renders the contents of a file from the game state, or None if it's missing
+pub type StateRenderFn = fn(&GameState) -> Option<Vec<u8>>;
+
+/// What is created under a phantom dir for a parsed child name
+#[derive(Copy, Clone)]
+enum PhantomChild {
+    Dir(PhantomDynamicInterestFn, DynamicDirFn),
+    File(fn(PhantomChildType) -> FileBehaviour),
+}
+
 #[derive(Debug, Hash, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, EnumIter)]
 pub enum DynamicStateType {
     EntityIds,
     PlayerId,
     Block([i32; 3]),
+    Inventory,
+    EntitiesNear(EntitiesNear),
+    GameRules,
+    /// Loaded chunks of the world of the dir
+    Chunks,
+    /// Status effects of the entity of the dir
+    Effects,
+    /// Attributes of the entity of the dir
+    Attributes,
+    /// Scoreboard objectives and their score holders
+    Objectives,
 }
 
 #[derive(Debug, Copy, Clone)]
 pub enum PhantomChildType {
     Block([i32; 3]),
+    EntitiesNear(EntitiesNear),
+    /// Minimum and maximum corners
+    Region([i32; 6]),
+    /// Minimum and maximum x, z corners
+    Map([i32; 4], MapKind),
+}
+
+/// A sphere to find entities in. The centre is kept as the bits of each coordinate so it can be
+/// hashed like the other dynamic state types
+#[derive(Debug, Hash, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Default)]
+pub struct EntitiesNear {
+    centre: [u64; 3],
+    radius: u32,
 }
 
 const STATE_TTL: Duration = Duration::from_secs(1);
 
+/// Chunks load and unload far less often than entities move about
+const CHUNKS_TTL: Duration = Duration::from_secs(5);
+
 struct DynamicState {
     /// (inode, its parent)
     inodes: HashSet<(u64, u64)>,
     time_collected: Instant,
+    /// Known to be out of date regardless of its age
+    invalidated: bool,
 }
 
 pub struct DynamicDirRegistrationer<'a> {
@@ -96,11 +140,54 @@ pub enum FileBehaviour {
         #[derivative(PartialEq(compare_with = "cmp_cmd_proxy_fn"))]
         produce_cmd_fn: CommandProxyFn,
     },
+    /// Tailable stream of events pushed by the game, filtered to the world of the file if any
+    EventStream,
+    /// Validated input is sent as a string, and the game's response to it can be read back
+    /// through the same handle
+    WriteWithResult {
+        cmd: CommandType,
+        result: BodyType,
+        #[derivative(Debug = "ignore")]
+        #[derivative(PartialEq(compare_with = "cmp_cmd_proxy_fn"))]
+        validate_fn: CommandProxyFn,
+    },
+    /// Binary input is decoded to a string to send, e.g. from a file format the game doesn't
+    /// understand
+    WriteDecoded {
+        cmd: CommandType,
+        #[derivative(Debug = "ignore")]
+        #[derivative(PartialEq(compare_with = "cmp_decode_fn"))]
+        decode_fn: DecodeFn,
+    },
+    /// Read as a string and reformatted, e.g. pretty-printed. Validated input is sent as a string,
+    /// and the file is read only without a `validate_fn`
+    Reformatted {
+        cmd: CommandType,
+        #[derivative(Debug = "ignore")]
+        #[derivative(PartialEq(compare_with = "cmp_cmd_proxy_fn"))]
+        format_fn: FormatFn,
+        #[derivative(Debug = "ignore")]
+        #[derivative(PartialEq(compare_with = "cmp_opt_cmd_proxy_fn"))]
+        validate_fn: Option<CommandProxyFn>,
+    },
+    /// Rendered from the game state fetched with the interest of the file and its ancestors
+    FromState(
+        #[derivative(Debug = "ignore")]
+        #[derivative(PartialEq(compare_with = "cmp_state_render_fn"))]
+        StateRenderFn,
+    ),
 }
 
-/// Takes user input from written file, outputs server command to execute
+/// Takes user input from written file, outputs server command to execute or the validated input
+/// to send, or None if invalid
 type CommandProxyFn = fn(&str) -> Option<String>;
 
+/// Takes the game's response, outputs what's read from the file or None if it can't be understood
+type FormatFn = fn(&str) -> Option<String>;
+
+/// Takes the whole binary input written to a file, outputs the string to send or None if invalid
+type DecodeFn = fn(&[u8]) -> Option<String>;
+
 pub struct DynamicInterest {
     /// (inode, interest)
     inodes: SmallVec<[(DynamicInode, DynamicStateType); 2]>,
@@ -113,9 +200,16 @@ pub struct DynamicInterest {
 struct DynamicPhantom {
     parent: u64,
     child_name: String,
-    interest: DynamicStateType,
-    dir_associated_data: EntryAssociatedData,
-    dyn_fn: DynamicDirFn,
+    associated_data: EntryAssociatedData,
+    kind: DynamicPhantomKind,
+}
+
+enum DynamicPhantomKind {
+    Dir {
+        interest: DynamicStateType,
+        dyn_fn: DynamicDirFn,
+    },
+    File(FileBehaviour),
 }
 
 enum DynamicInode {
@@ -141,6 +235,7 @@ impl FilesystemStructure {
                 parent_registry: HashMap::new(),
                 dynamic_state: HashMap::new(),
                 phantom_registry: HashMap::new(),
+                phantom_file_lookups: HashMap::new(),
             },
         }
     }
@@ -174,7 +269,7 @@ impl FilesystemStructure {
     pub fn lookup_children(
         &self,
         inode: u64,
-    ) -> Option<impl Iterator<Item = (&Entry, &str)> + ExactSizeIterator + '_> {
+    ) -> Option<impl ExactSizeIterator<Item = (&Entry, &str)> + '_> {
         self.inner.child_registry.get(&inode).map(|v| {
             v.iter()
                 .map(|(inode, name)| (self.get_inode(*inode), name.as_ref()))
@@ -218,8 +313,7 @@ impl FilesystemStructure {
 
         let mut phantom = None;
         if let Some(child_name) = looked_up_child {
-            if let Some((phantom_fn, interest_fn, dyn_fn)) = self.inner.phantom_registry.get(&inode)
-            {
+            if let Some((phantom_fn, child)) = self.inner.phantom_registry.get(&inode) {
                 let child_name = child_name.to_string_lossy().into_owned();
                 let phantom_ty = (phantom_fn)(&child_name);
                 if let Some(phantom_ty) = phantom_ty {
@@ -228,15 +322,23 @@ impl FilesystemStructure {
                         child_name,
                         inode
                     );
-                    let interest = (interest_fn)(phantom_ty);
-                    dynamics_required.push((DynamicInode::Phantom(inode), interest));
+                    let kind = match *child {
+                        PhantomChild::Dir(interest_fn, dyn_fn) => {
+                            let interest = (interest_fn)(phantom_ty);
+                            dynamics_required.push((DynamicInode::Phantom(inode), interest));
+                            DynamicPhantomKind::Dir { interest, dyn_fn }
+                        }
+                        // nothing to fetch until it's read or written
+                        PhantomChild::File(mk_behaviour) => {
+                            DynamicPhantomKind::File(mk_behaviour(phantom_ty))
+                        }
+                    };
 
                     phantom = Some(DynamicPhantom {
                         parent: inode,
                         child_name,
-                        interest,
-                        dir_associated_data: phantom_ty.into(),
-                        dyn_fn: *dyn_fn,
+                        associated_data: phantom_ty.into(),
+                        kind,
                     });
                 }
             }
@@ -249,7 +351,7 @@ impl FilesystemStructure {
             };
 
             if let Some(state) = self.inner.dynamic_state.get(&(*inode, *interest)) {
-                if state.time_collected.elapsed() <= STATE_TTL {
+                if !state.invalidated && state.time_collected.elapsed() <= interest.ttl() {
                     // cache is valid
                     continue;
                 }
@@ -269,9 +371,46 @@ impl FilesystemStructure {
                 &DynamicStateType::Block([x, y, z]) => {
                     interest.target_block = Some(BlockPos::new(x, y, z));
                 }
+                DynamicStateType::Inventory => { /* always requested, below */ }
+                DynamicStateType::EntitiesNear(_) => {
+                    // filtered by the associated data of the phantom dir
+                    interest.entities_by_id = true;
+                }
+                DynamicStateType::GameRules => {
+                    interest.gamerules = true;
+                }
+                DynamicStateType::Chunks => {
+                    interest.chunks = true;
+                }
+                DynamicStateType::Effects => {
+                    interest.effects = true;
+                }
+                DynamicStateType::Attributes => {
+                    interest.attributes = true;
+                }
+                DynamicStateType::Objectives => {
+                    interest.objectives = true;
+                }
             }
         }
 
+        // slot links are resolved from the state, so it's needed even if the dir is up to date
+        if dynamics_required
+            .iter()
+            .any(|(_, ty)| *ty == DynamicStateType::Inventory)
+        {
+            interest.inventory = true;
+        }
+
+        // entity links are resolved from the state too
+        if matches!(self.try_get_inode(inode), Some(Entry::Link(_)))
+            && dynamics_required
+                .iter()
+                .any(|(_, ty)| *ty == DynamicStateType::EntityIds)
+        {
+            interest.entities_by_id = true;
+        }
+
         DynamicInterest {
             inodes: dynamics_required,
             need_fetching,
@@ -305,6 +444,7 @@ impl FilesystemStructure {
         let new_state = DynamicState {
             inodes: new_inodes.clone(),
             time_collected: Instant::now(),
+            invalidated: false,
         };
 
         let mut old_inodes = HashSet::new();
@@ -391,6 +531,11 @@ impl FilesystemStructure {
             "inode {} is in phantom registry",
             inode
         );
+        assert!(
+            !self.inner.phantom_file_lookups.contains_key(&inode),
+            "inode {} is in phantom file lookups",
+            inode
+        );
     }
 
     #[cfg(debug_assertions)]
@@ -436,6 +581,14 @@ impl FilesystemStructure {
                 )
             }
 
+            for inode in self.inner.phantom_file_lookups.keys() {
+                current_ino = Some(*inode);
+                assert!(
+                    self.inner.registry.contains_key(inode),
+                    "phantom file is not registered"
+                )
+            }
+
             for (inode, _) in self.inner.dynamic_state.keys() {
                 current_ino = Some(*inode);
                 assert!(
@@ -453,22 +606,85 @@ impl FilesystemStructure {
         }
     }
 
+    /// Regenerates the dynamic children of the dir on its next lookup, e.g. after one is
+    /// created or removed
+    pub fn invalidate_dynamic(&mut self, inode: u64) {
+        for (_, state) in self
+            .inner
+            .dynamic_state
+            .iter_mut()
+            .filter(|((dir, _), _)| *dir == inode)
+        {
+            state.invalidated = true;
+        }
+    }
+
+    /// Counts a lookup of the inode by the kernel, to be balanced by [Self::forget]
+    pub fn count_lookup(&mut self, inode: u64) {
+        if let Some(lookups) = self.inner.phantom_file_lookups.get_mut(&inode) {
+            *lookups += 1;
+        }
+    }
+
+    /// Removes a phantom file once the kernel has forgotten every lookup of it. Everything else
+    /// is either static or cleaned up when its dynamic parent is regenerated
+    pub fn forget(&mut self, inode: u64, nlookup: u64) {
+        let lookups = match self.inner.phantom_file_lookups.get_mut(&inode) {
+            Some(n) => n,
+            None => return,
+        };
+
+        *lookups = lookups.saturating_sub(nlookup);
+        if *lookups > 0 {
+            return;
+        }
+
+        let parent = *self
+            .inner
+            .parent_registry
+            .get(&inode)
+            .expect("phantom file has no parent");
+        trace!("forgetting phantom file {} under {}", inode, parent);
+        self.inner.unregister(inode, parent);
+
+        #[cfg(debug_assertions)]
+        self.ensure_unused(inode);
+    }
+
     pub fn ensure_generated(&mut self, state: &GameState, dynamics: DynamicInterest) {
         if let Some(phantom) = dynamics.phantom {
-            // make new phantom dir
-            let phantom_dir = self.inner.inode_alloc.allocate();
-            self.inner.register(
-                phantom_dir,
-                DirEntry::build()
-                    .associated_data(phantom.dir_associated_data)
-                    .filter(|_| EntryFilterResult::Exclude) // hide phantom dirs
-                    .finish()
-                    .into(),
-                Some((phantom.parent, phantom.child_name)),
-            );
+            match phantom.kind {
+                DynamicPhantomKind::Dir { interest, dyn_fn } => {
+                    // make new phantom dir
+                    let phantom_dir = self.inner.inode_alloc.allocate();
+                    self.inner.register(
+                        phantom_dir,
+                        DirEntry::build()
+                            .associated_data(phantom.associated_data)
+                            .dynamic(interest, dyn_fn) // regenerated once stale
+                            .filter(|_| EntryFilterResult::Exclude) // hide phantom dirs
+                            .finish()
+                            .into(),
+                        Some((phantom.parent, phantom.child_name)),
+                    );
 
-            // register entries under new phantom dir
-            self.register_dynamic_entries(phantom.dyn_fn, phantom_dir, phantom.interest, state);
+                    // register entries under new phantom dir
+                    self.register_dynamic_entries(dyn_fn, phantom_dir, interest, state);
+                }
+                DynamicPhantomKind::File(behaviour) => {
+                    let phantom_file = self.inner.inode_alloc.allocate();
+                    self.inner.register(
+                        phantom_file,
+                        FileEntry::build(behaviour)
+                            .associated_data(phantom.associated_data)
+                            .filter(|_| false) // hide phantom files
+                            .finish()
+                            .into(),
+                        Some((phantom.parent, phantom.child_name)),
+                    );
+                    self.inner.phantom_file_lookups.insert(phantom_file, 0);
+                }
+            }
         }
 
         for (inode, interest) in dynamics
@@ -529,6 +745,50 @@ impl FilesystemStructure {
     }
 }
 
+impl DynamicStateType {
+    /// How long a dynamic dir's entries are reused for before being fetched again
+    fn ttl(&self) -> Duration {
+        match self {
+            DynamicStateType::Chunks => CHUNKS_TTL,
+            _ => STATE_TTL,
+        }
+    }
+}
+
+impl EntitiesNear {
+    /// None if the centre isn't finite or the radius is 0
+    pub fn new(centre: [f64; 3], radius: u32) -> Option<Self> {
+        if radius == 0 || !centre.iter().all(|f| f.is_finite()) {
+            return None;
+        }
+
+        Some(Self {
+            centre: centre.map(f64::to_bits),
+            radius,
+        })
+    }
+
+    pub fn centre(&self) -> Vec3 {
+        let [x, y, z] = self.centre.map(f64::from_bits);
+        Vec3::new(x, y, z)
+    }
+
+    pub fn radius(&self) -> u32 {
+        self.radius
+    }
+
+    pub fn contains(&self, pos: &Vec3) -> bool {
+        let centre = self.centre();
+        let (dx, dy, dz) = (
+            pos.x() - centre.x(),
+            pos.y() - centre.y(),
+            pos.z() - centre.z(),
+        );
+        let radius = self.radius as f64;
+        dx * dx + dy * dy + dz * dz <= radius * radius
+    }
+}
+
 impl DynamicInterest {
     pub fn as_interest(&self) -> GameStateInterest {
         GameStateInterest { ..self.interest }
@@ -562,10 +822,24 @@ impl FilesystemStructureBuilder {
         parse_func: PhantomChildFn,
         interest_func: PhantomDynamicInterestFn,
         dyn_func: DynamicDirFn,
+    ) {
+        self.inner.phantom_registry.insert(
+            inode,
+            (parse_func, PhantomChild::Dir(interest_func, dyn_func)),
+        );
+    }
+
+    /// Like [Self::add_phantom] but parsed children are files, with the parsed type as their
+    /// associated data
+    pub fn add_phantom_file(
+        &mut self,
+        inode: u64,
+        parse_func: PhantomChildFn,
+        behaviour_func: fn(PhantomChildType) -> FileBehaviour,
     ) {
         self.inner
             .phantom_registry
-            .insert(inode, (parse_func, interest_func, dyn_func));
+            .insert(inode, (parse_func, PhantomChild::File(behaviour_func)));
     }
 
     pub fn finish(self) -> FilesystemStructure {
@@ -604,6 +878,7 @@ impl StructureInner {
             trace!("removing inode {}", next);
             let _ = self.registry.remove(&next);
             let _ = self.phantom_registry.remove(&next);
+            let _ = self.phantom_file_lookups.remove(&next);
 
             // try all interest types
             for ty in DynamicStateType::iter() {
@@ -657,6 +932,7 @@ impl<'a> DynamicDirRegistrationer<'a> {
 
     /// (vec of (new inode, its name under the parent, the entry, its parent),
     /// set of (reused inode, its parent))
+    #[allow(clippy::type_complexity)]
     pub fn take_entries(
         self,
     ) -> (
@@ -705,14 +981,65 @@ impl<'a> DynamicDirRegistrationer<'a> {
     pub fn parent(&self) -> u64 {
         self.parent
     }
+
+    /// Associated data of the dir being generated, e.g. what a phantom dir was parsed from
+    pub fn parent_data(&self) -> Option<&EntryAssociatedData> {
+        self.structure
+            .lookup_inode(self.parent)
+            .and_then(|e| e.as_dir())
+            .and_then(|dir| dir.associated_data())
+    }
 }
 
 impl FileBehaviour {
     pub fn is_readable(&self) -> bool {
-        !matches!(self, Self::ForShow | Self::WriteOnly(_, _))
+        !matches!(
+            self,
+            Self::ForShow | Self::WriteOnly(_, _) | Self::WriteDecoded { .. }
+        )
+    }
+
+    pub fn is_writable(&self) -> bool {
+        matches!(
+            self,
+            Self::WriteOnly(_, _)
+                | Self::ReadWrite(_, _)
+                | Self::CommandProxy { .. }
+                | Self::WriteWithResult { .. }
+                | Self::WriteDecoded { .. }
+                | Self::Reformatted {
+                    validate_fn: Some(_),
+                    ..
+                }
+        )
+    }
+
+    /// Permission bits matching readability and writability
+    pub fn perm(&self) -> u16 {
+        match (self.is_readable(), self.is_writable()) {
+            (true, true) => 0o644,
+            (true, false) => 0o444,
+            (false, true) => 0o222,
+            (false, false) => 0o000,
+        }
     }
 }
 
 fn cmp_cmd_proxy_fn(a: &CommandProxyFn, b: &CommandProxyFn) -> bool {
     std::ptr::eq(*a as *const (), *b as *const ())
 }
+
+fn cmp_opt_cmd_proxy_fn(a: &Option<CommandProxyFn>, b: &Option<CommandProxyFn>) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => cmp_cmd_proxy_fn(a, b),
+        (a, b) => a.is_none() && b.is_none(),
+    }
+}
+
+fn cmp_decode_fn(a: &DecodeFn, b: &DecodeFn) -> bool {
+    std::ptr::eq(*a as *const (), *b as *const ())
+}
+
+fn cmp_state_render_fn(a: &StateRenderFn, b: &StateRenderFn) -> bool {
+    std::ptr::eq(*a as *const (), *b as *const ())
+}
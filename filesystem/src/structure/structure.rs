@@ -1,14 +1,21 @@
 use std::borrow::Cow;
 
-use crate::state::GameState;
-use ipc::generated::{CommandType, Dimension, EntityDetails};
+use std::collections::BTreeMap;
+
+use crate::map::{render_map, MapKind};
+use crate::nbt::Tag;
+use crate::schematic::Schematic;
+use crate::snbt;
+use crate::state::{EntityDetails, GameState, InventoryDetails};
+use ipc::generated::{ColumnPos, CommandType, Dimension, GameRuleType};
 use ipc::BodyType;
 use ipc::BodyType::*;
 
 use crate::structure::entry::{DirEntry, EntryAssociatedData, FileEntry, LinkEntry};
 use crate::structure::registry::EntryFilterResult::{Exclude, IncludeAllChildren};
 use crate::structure::registry::{
-    DynamicDirRegistrationer, DynamicStateType, FilesystemStructureBuilder, PhantomChildType,
+    DynamicDirRegistrationer, DynamicStateType, EntitiesNear, FilesystemStructureBuilder,
+    PhantomChildType,
 };
 use crate::structure::FileBehaviour::*;
 use crate::structure::{EntryFilterResult, FileBehaviour, FilesystemStructure};
@@ -23,6 +30,7 @@ pub fn create_structure() -> FilesystemStructure {
 
     player_dir(&mut builder);
     worlds_dir(&mut builder);
+    server_dir(&mut builder);
 
     builder.add_entry(
         builder.root(),
@@ -41,6 +49,14 @@ pub fn create_structure() -> FilesystemStructure {
             .finish(),
     );
 
+    builder.add_entry(
+        builder.root(),
+        "events",
+        FileEntry::build(FileBehaviour::EventStream)
+            .filter(filter_in_game)
+            .finish(),
+    );
+
     builder.finish()
 }
 
@@ -106,6 +122,23 @@ fn player_dir(builder: &mut FilesystemStructureBuilder) -> u64 {
         .finish(),
     );
 
+    builder.add_entry(
+        dir,
+        "give",
+        FileEntry::build(FileBehaviour::CommandProxy {
+            readme: r#"Give the player an item with optional NBT tags.
+Format: "[item type]\n[count]\n<nbt>"
+Examples:
+   diamond\n64\n
+   minecraft:stone\n1\n
+   diamond_sword\n1\n{Damage:100,display:{Name:'{"text":"Old Sword"}'}}"#
+                .into(),
+            produce_cmd_fn: give_command,
+        })
+        .filter(filter_in_game)
+        .finish(),
+    );
+
     builder.add_entry(
         dir,
         "entity",
@@ -178,6 +211,176 @@ fn player_dir(builder: &mut FilesystemStructureBuilder) -> u64 {
     dir
 }
 
+fn server_dir(builder: &mut FilesystemStructureBuilder) -> u64 {
+    let dir = builder.add_entry(
+        builder.root(),
+        "server",
+        DirEntry::build()
+            .filter(|state| {
+                if state.is_in_game() {
+                    IncludeAllChildren
+                } else {
+                    Exclude
+                }
+            })
+            .finish(),
+    );
+
+    builder.add_entry(
+        dir,
+        "gamerules",
+        DirEntry::build()
+            .dynamic(DynamicStateType::GameRules, mk_gamerules_dir)
+            .finish(),
+    );
+
+    let scoreboard = builder.add_entry(dir, "scoreboard", DirEntry::default());
+    builder.add_entry(
+        scoreboard,
+        "objectives",
+        DirEntry::build()
+            .dynamic(DynamicStateType::Objectives, mk_objectives_dir)
+            .creatable(CommandType::ScoreboardObjectiveAdd)
+            .removable_children()
+            .finish(),
+    );
+
+    builder.add_entry(
+        dir,
+        "difficulty",
+        FileEntry::build(ReadWrite(
+            CommandType::ServerDifficulty,
+            Keyword(&["peaceful", "easy", "normal", "hard"]),
+        ))
+        .finish(),
+    );
+
+    builder.add_entry(
+        dir,
+        "pvp",
+        FileEntry::build(ReadWrite(CommandType::ServerPvp, Bool)).finish(),
+    );
+
+    dir
+}
+
+/// Listed by the game, so new rules show up without any changes here
+fn mk_gamerules_dir(state: &GameState, reg: &mut DynamicDirRegistrationer) {
+    for rule in &state.gamerules {
+        let body = match rule.ty {
+            GameRuleType::Bool => Bool,
+            GameRuleType::Int => Integer,
+            _ => continue,
+        };
+
+        reg.add_root_entry(
+            rule.name.clone(),
+            FileEntry::build(ReadWrite(CommandType::ServerGameRule, body))
+                .associated_data(EntryAssociatedData::GameRule(rule.name.clone().into()))
+                .finish(),
+        );
+    }
+}
+
+/// A dir per objective, holding a file per score
+fn mk_objectives_dir(state: &GameState, reg: &mut DynamicDirRegistrationer) {
+    for objective in &state.objectives {
+        let objective_dir = reg.add_root_entry(
+            objective.name.clone(),
+            DirEntry::build()
+                .associated_data(EntryAssociatedData::Objective(
+                    objective.name.clone().into(),
+                ))
+                .removable(CommandType::ScoreboardObjectiveRemove)
+                .finish(),
+        );
+
+        reg.add_entry(
+            objective_dir,
+            "criteria",
+            FileEntry::build(ReadOnly(CommandType::ScoreboardObjectiveCriteria, String)).finish(),
+        );
+        reg.add_entry(
+            objective_dir,
+            "display_name",
+            FileEntry::build(ReadWrite(
+                CommandType::ScoreboardObjectiveDisplayName,
+                String,
+            ))
+            .finish(),
+        );
+        reg.add_entry(
+            objective_dir,
+            "display_slot",
+            FileEntry::build(ReadWrite(
+                CommandType::ScoreboardObjectiveDisplaySlot,
+                String,
+            ))
+            .finish(),
+        );
+
+        let scores = reg.add_entry(objective_dir, "scores", DirEntry::default());
+        // fake players can be named anything, but not everything makes a valid file name
+        for holder in objective
+            .holders
+            .iter()
+            .filter(|h| !matches!(h.as_str(), "" | "." | "..") && !h.contains('/'))
+        {
+            reg.add_entry(
+                scores,
+                holder.clone(),
+                FileEntry::build(ReadWrite(CommandType::ScoreboardScore, Integer))
+                    .associated_data(EntryAssociatedData::ScoreHolder(holder.clone().into()))
+                    .finish(),
+            );
+        }
+    }
+}
+
+/// A dir per loaded chunk, named by its chunk coordinates
+fn mk_chunks_dir(state: &GameState, reg: &mut DynamicDirRegistrationer) {
+    for chunk in &state.chunks {
+        let chunk_dir = reg.add_root_entry(
+            format!("{},{}", chunk.x, chunk.z),
+            DirEntry::build()
+                .associated_data(EntryAssociatedData::Chunk(ColumnPos::new(chunk.x, chunk.z)))
+                .finish(),
+        );
+
+        reg.add_entry(
+            chunk_dir,
+            "loaded",
+            FileEntry::build(ReadOnly(CommandType::ChunkLoaded, Bool)).finish(),
+        );
+        reg.add_entry(
+            chunk_dir,
+            "biome",
+            FileEntry::build(ReadOnly(CommandType::ChunkBiomes, BodyType::String)).finish(),
+        );
+        reg.add_entry(
+            chunk_dir,
+            "inhabited_time",
+            FileEntry::build(ReadOnly(CommandType::ChunkInhabitedTime, Integer)).finish(),
+        );
+        reg.add_entry(
+            chunk_dir,
+            "forceload",
+            FileEntry::build(WriteOnly(CommandType::ChunkForceLoad, Bool)).finish(),
+        );
+
+        let entities_dir = reg.add_entry(chunk_dir, "entities", DirEntry::default());
+        for id in &chunk.entities {
+            let id = *id;
+            reg.add_entry(
+                entities_dir,
+                id.to_string(),
+                LinkEntry::build(move |_| Some(format!("../../../entities/by-id/{}", id).into()))
+                    .finish(),
+            );
+        }
+    }
+}
+
 fn worlds_dir(builder: &mut FilesystemStructureBuilder) -> u64 {
     let dir = builder.add_entry(
         builder.root(),
@@ -216,6 +419,41 @@ fn worlds_dir(builder: &mut FilesystemStructureBuilder) -> u64 {
             FileEntry::build(FileBehaviour::ReadWrite(CommandType::WorldTime, Integer)).finish(),
         );
 
+        builder.add_entry(
+            world,
+            "fill",
+            FileEntry::build(FileBehaviour::WriteWithResult {
+                cmd: CommandType::WorldFill,
+                result: Integer,
+                validate_fn: fill_input,
+            })
+            .finish(),
+        );
+
+        builder.add_entry(
+            world,
+            "weather",
+            FileEntry::build(FileBehaviour::ReadWrite(
+                CommandType::WorldWeather,
+                Keyword(&["clear", "rain", "thunder"]),
+            ))
+            .finish(),
+        );
+
+        builder.add_entry(
+            world,
+            "events",
+            FileEntry::build(FileBehaviour::EventStream).finish(),
+        );
+
+        builder.add_entry(
+            world,
+            "chunks",
+            DirEntry::build()
+                .dynamic(DynamicStateType::Chunks, mk_chunks_dir)
+                .finish(),
+        );
+
         let blocks_dir = builder.add_entry(world, "blocks", DirEntry::default());
         builder.add_entry(
             blocks_dir,
@@ -234,9 +472,9 @@ fn worlds_dir(builder: &mut FilesystemStructureBuilder) -> u64 {
         builder.add_phantom(
             blocks_dir,
             parse_pos,
-            |ty| {
-                let PhantomChildType::Block(pos) = ty;
-                DynamicStateType::Block(pos)
+            |ty| match ty {
+                PhantomChildType::Block(pos) => DynamicStateType::Block(pos),
+                _ => unreachable!(),
             },
             |state, reg| {
                 let block = state.block.as_ref().expect("missing block details");
@@ -259,6 +497,51 @@ fn worlds_dir(builder: &mut FilesystemStructureBuilder) -> u64 {
                     .finish(),
                 );
 
+                if block.has_block_entity {
+                    reg.add_root_entry(
+                        "nbt",
+                        FileEntry::build(FileBehaviour::Reformatted {
+                            cmd: CommandType::BlockNbt,
+                            format_fn: pretty_snbt,
+                            validate_fn: Some(nbt_merge_input),
+                        })
+                        .filter(|state| state.block.as_ref().is_some_and(|b| b.has_block_entity))
+                        .finish(),
+                    );
+                }
+
+                if let Some(size) = block.container_size {
+                    let contents_dir = reg.add_root_entry(
+                        "contents",
+                        DirEntry::build()
+                            .filter(|state| {
+                                match state.block.as_ref().and_then(|b| b.container_size) {
+                                    Some(_) => IncludeAllChildren,
+                                    None => Exclude,
+                                }
+                            })
+                            .finish(),
+                    );
+
+                    for slot in 0..size {
+                        mk_slot_dir(reg, contents_dir, slot);
+                    }
+                }
+
+                let state_dir = reg.add_root_entry("state", DirEntry::default());
+                for name in &block.properties {
+                    reg.add_entry(
+                        state_dir,
+                        name.clone(),
+                        FileEntry::build(FileBehaviour::ReadWrite(
+                            CommandType::BlockProperty,
+                            BodyType::String,
+                        ))
+                        .associated_data(EntryAssociatedData::BlockProperty(name.clone().into()))
+                        .finish(),
+                    );
+                }
+
                 let neighbours_dir = reg.add_root_entry("adjacent", DirEntry::default());
                 type PosMut<'a> = &'a mut (i32, i32, i32);
                 macro_rules! adjacent {
@@ -285,6 +568,96 @@ fn worlds_dir(builder: &mut FilesystemStructureBuilder) -> u64 {
                 adjacent!("south", |pos: PosMut| pos.2 += 1);
             },
         );
+
+        let regions_dir = builder.add_entry(world, "regions", DirEntry::default());
+        builder.add_entry(
+            regions_dir,
+            "README",
+            FileEntry::build(FileBehaviour::Static(
+                "Path format is ./x1,y1,z1..x2,y2,z2\ne.g. 0,60,0..15,70,15\n\
+                 Each line is \"x y z type\", ordered by y, then z, then x\n"
+                    .into(),
+            ))
+            .finish(),
+        );
+
+        builder.add_phantom_file(
+            regions_dir,
+            |name| parse_region(name).map(PhantomChildType::Region),
+            |_| FromState(render_region),
+        );
+
+        let maps_dir = builder.add_entry(world, "maps", DirEntry::default());
+        builder.add_entry(
+            maps_dir,
+            "README",
+            FileEntry::build(FileBehaviour::Static(
+                "Path format is ./x1,z1..x2,z2.pgm or ./x1,z1..x2,z2.ppm\ne.g. -64,-64..63,63.ppm\n\
+                 .pgm is a heightmap and .ppm is coloured by block type, with north at the top\n"
+                    .into(),
+            ))
+            .finish(),
+        );
+
+        builder.add_phantom_file(
+            maps_dir,
+            |name| {
+                let (area, ext) = name.rsplit_once('.')?;
+                let kind = MapKind::from_extension(ext)?;
+                Some(PhantomChildType::Map(parse_map_area(area)?, kind))
+            },
+            |ty| match ty {
+                PhantomChildType::Map(_, MapKind::Height) => {
+                    FromState(|state| render_map(state.columns.as_ref()?, MapKind::Height))
+                }
+                PhantomChildType::Map(_, MapKind::Colour) => {
+                    FromState(|state| render_map(state.columns.as_ref()?, MapKind::Colour))
+                }
+                _ => unreachable!(),
+            },
+        );
+
+        let schematics_dir = builder.add_entry(world, "schematics", DirEntry::default());
+        builder.add_entry(
+            schematics_dir,
+            "README",
+            FileEntry::build(FileBehaviour::Static(
+                "Path format is ./x1,y1,z1..x2,y2,z2.nbt\ne.g. 0,60,0..15,70,15.nbt\n\
+                 Each file is a structure block file of the region, to be copied into ../paste\n"
+                    .into(),
+            ))
+            .finish(),
+        );
+
+        builder.add_phantom_file(
+            schematics_dir,
+            |name| {
+                let region = parse_region(name.strip_suffix(".nbt")?)?;
+                Some(PhantomChildType::Region(region))
+            },
+            |_| FromState(render_schematic),
+        );
+
+        let paste_dir = builder.add_entry(world, "paste", DirEntry::default());
+        builder.add_entry(
+            paste_dir,
+            "README",
+            FileEntry::build(FileBehaviour::Static(
+                "Path format is ./x,y,z or ./x\\ y\\ z\ne.g. 0,64,100\n\
+                 Writing a structure block file places it with its minimum corner there\n"
+                    .into(),
+            ))
+            .finish(),
+        );
+
+        builder.add_phantom_file(
+            paste_dir,
+            |name| parse_block_position(name).map(PhantomChildType::Block),
+            |_| WriteDecoded {
+                cmd: CommandType::WorldPaste,
+                decode_fn: paste_input,
+            },
+        );
     }
 
     dir
@@ -311,7 +684,7 @@ fn entities_dir(builder: &mut FilesystemStructureBuilder, root: u64) -> u64 {
         DirEntry::build()
             .dynamic(DynamicStateType::EntityIds, |state, reg| {
                 for details in &state.entities {
-                    let id = details.id();
+                    let id = details.id;
                     let entity_dir = reg.add_root_entry(
                         id.to_string(),
                         DirEntry::build()
@@ -325,6 +698,57 @@ fn entities_dir(builder: &mut FilesystemStructureBuilder, root: u64) -> u64 {
             .finish(),
     );
 
+    builder.add_entry(
+        dir,
+        "by-type",
+        DirEntry::build()
+            .dynamic(DynamicStateType::EntityIds, |state, reg| {
+                let mut by_type = BTreeMap::<&str, Vec<i32>>::new();
+                for details in &state.entities {
+                    by_type.entry(&details.ty).or_default().push(details.id);
+                }
+
+                for (ty, ids) in by_type {
+                    let type_dir = reg.add_root_entry(ty.to_owned(), DirEntry::default());
+                    for id in ids {
+                        reg.add_entry(type_dir, id.to_string(), entity_link(id));
+                    }
+                }
+            })
+            .finish(),
+    );
+
+    let near_dir = builder.add_entry(dir, "near", DirEntry::default());
+    builder.add_entry(
+        near_dir,
+        "README",
+        FileEntry::build(FileBehaviour::Static(
+            "Path format is ./x,y,z,radius or ./x\\ y\\ z\\ radius\ne.g. 0,64,100,16 or \"0.5 22.3 41.5555 8\"\n"
+                .into(),
+        ))
+        .finish(),
+    );
+
+    builder.add_phantom(
+        near_dir,
+        |name| parse_entities_near(name).map(PhantomChildType::EntitiesNear),
+        |ty| match ty {
+            PhantomChildType::EntitiesNear(near) => DynamicStateType::EntitiesNear(near),
+            _ => unreachable!(),
+        },
+        |state, reg| {
+            let near = match reg.parent_data() {
+                Some(EntryAssociatedData::EntitiesNear(near)) => *near,
+                _ => return,
+            };
+
+            // the game only returns entities in range, but don't rely on it
+            for details in state.entities.iter().filter(|e| near.contains(&e.pos)) {
+                reg.add_root_entry(details.id.to_string(), entity_link(details.id));
+            }
+        },
+    );
+
     builder.add_entry(
         dir,
         "spawn",
@@ -355,8 +779,12 @@ Examples:
     dir
 }
 
-fn mk_entity_dir(reg: &mut DynamicDirRegistrationer, entity_dir: u64, ty: EntityType) {
+/// Link from a sibling dir of `by-id`, e.g. `by-type/minecraft:cow/<id>`
+fn entity_link(id: i32) -> LinkEntry {
+    LinkEntry::build(move |_| Some(format!("../../by-id/{}", id).into())).finish()
+}
 
+fn mk_entity_dir(reg: &mut DynamicDirRegistrationer, entity_dir: u64, ty: EntityType) {
     reg.add_entry(
         entity_dir,
         "position",
@@ -373,44 +801,417 @@ fn mk_entity_dir(reg: &mut DynamicDirRegistrationer, entity_dir: u64, ty: Entity
             .finish(),
     );
 
-    let add_health = match ty {
-        EntityType::SpecificallyPlayer => true,
-        EntityType::Other(details) => details.living()
-    };
-
-    if add_health {
-        reg.add_entry(
-            entity_dir,
-            "health",
-            FileEntry::build(ReadWrite(CommandType::EntityHealth, Float))
-                .filter(filter_in_game)
-                .finish(),
-        );
-    }
+    reg.add_entry(
+        entity_dir,
+        "ride",
+        FileEntry::build(WriteOnly(CommandType::EntityVehicle, String))
+            .filter(filter_in_game)
+            .finish(),
+    );
 
-    if let EntityType::Other(details) = ty {
-        reg.add_entry(
-            entity_dir,
-            "type",
-            FileEntry::build(ReadOnly(CommandType::EntityType, String))
-                .filter(filter_in_game)
-                .finish(),
-        );
+    reg.add_entry(
+        entity_dir,
+        "rotation",
+        FileEntry::build(ReadWrite(CommandType::EntityRotation, Rotation))
+            .filter(filter_in_game)
+            .finish(),
+    );
 
-        if details.living() {
-            reg.add_entry(
-                entity_dir,
-                "living",
-                FileEntry::build(FileBehaviour::ForShow)
-                    .filter(filter_in_game)
-                    .finish(),
-            );
-        }
-    }
-}
+    reg.add_entry(
+        entity_dir,
+        "velocity",
+        FileEntry::build(ReadWrite(CommandType::EntityVelocity, Position))
+            .filter(filter_in_game)
+            .finish(),
+    );
 
-// ------
-fn parse_block_position(s: &str) -> Option<[i32; 3]> {
+    reg.add_entry(
+        entity_dir,
+        "on_ground",
+        FileEntry::build(ReadOnly(CommandType::EntityOnGround, Bool))
+            .filter(filter_in_game)
+            .finish(),
+    );
+
+    reg.add_entry(
+        entity_dir,
+        "eye_position",
+        FileEntry::build(ReadOnly(CommandType::EntityEyePosition, Position))
+            .filter(filter_in_game)
+            .finish(),
+    );
+
+    reg.add_entry(
+        entity_dir,
+        "tags",
+        FileEntry::build(ReadWrite(CommandType::EntityTags, Lines))
+            .filter(filter_in_game)
+            .finish(),
+    );
+
+    reg.add_entry(
+        entity_dir,
+        "glowing",
+        FileEntry::build(ReadWrite(CommandType::EntityGlowing, Bool))
+            .filter(filter_in_game)
+            .finish(),
+    );
+
+    reg.add_entry(
+        entity_dir,
+        "silent",
+        FileEntry::build(ReadWrite(CommandType::EntitySilent, Bool))
+            .filter(filter_in_game)
+            .finish(),
+    );
+
+    reg.add_entry(
+        entity_dir,
+        "team",
+        FileEntry::build(ReadWrite(CommandType::EntityTeam, String))
+            .filter(filter_in_game)
+            .finish(),
+    );
+
+    reg.add_entry(
+        entity_dir,
+        "nbt",
+        FileEntry::build(FileBehaviour::Reformatted {
+            cmd: CommandType::EntityNbt,
+            format_fn: pretty_snbt,
+            validate_fn: Some(nbt_merge_input),
+        })
+        .filter(filter_in_game)
+        .finish(),
+    );
+
+    reg.add_entry(
+        entity_dir,
+        "nbt.json",
+        FileEntry::build(FileBehaviour::Reformatted {
+            cmd: CommandType::EntityNbt,
+            format_fn: snbt_as_json,
+            validate_fn: None,
+        })
+        .filter(filter_in_game)
+        .finish(),
+    );
+
+    let add_health = match ty {
+        EntityType::SpecificallyPlayer => true,
+        EntityType::Other(details) => details.living,
+    };
+
+    if add_health {
+        reg.add_entry(
+            entity_dir,
+            "health",
+            FileEntry::build(ReadWrite(CommandType::EntityHealth, Float))
+                .filter(filter_in_game)
+                .finish(),
+        );
+
+        reg.add_entry(
+            entity_dir,
+            "inventory",
+            DirEntry::build()
+                .dynamic(DynamicStateType::Inventory, mk_inventory_dir)
+                .finish(),
+        );
+
+        reg.add_entry(
+            entity_dir,
+            "effects",
+            DirEntry::build()
+                .dynamic(DynamicStateType::Effects, mk_effects_dir)
+                .removable_children()
+                .finish(),
+        );
+
+        reg.add_entry(
+            entity_dir,
+            "attributes",
+            DirEntry::build()
+                .dynamic(DynamicStateType::Attributes, mk_attributes_dir)
+                .finish(),
+        );
+    }
+
+    if let EntityType::Other(details) = ty {
+        reg.add_entry(
+            entity_dir,
+            "type",
+            FileEntry::build(ReadOnly(CommandType::EntityType, String))
+                .filter(filter_in_game)
+                .finish(),
+        );
+
+        // the player dir already has a name, which isn't a custom name
+        reg.add_entry(
+            entity_dir,
+            "name",
+            FileEntry::build(ReadWrite(CommandType::EntityCustomName, String))
+                .filter(filter_in_game)
+                .finish(),
+        );
+
+        if details.living {
+            reg.add_entry(
+                entity_dir,
+                "living",
+                FileEntry::build(FileBehaviour::ForShow)
+                    .filter(filter_in_game)
+                    .finish(),
+            );
+
+            reg.add_entry(
+                entity_dir,
+                "no_ai",
+                FileEntry::build(ReadWrite(CommandType::EntityNoAi, Bool))
+                    .filter(filter_in_game)
+                    .finish(),
+            );
+        }
+
+        if details.vehicle.is_some() {
+            // resolved from the latest state, as the entity could start riding something else
+            let id = details.id;
+            reg.add_entry(
+                entity_dir,
+                "vehicle",
+                LinkEntry::build(move |state| {
+                    let vehicle = state.entities.iter().find(|e| e.id == id)?.vehicle?;
+                    Some(format!("../{}", vehicle).into())
+                })
+                .filter(filter_in_game)
+                .finish(),
+            );
+        }
+
+        if !details.passengers.is_empty() {
+            // also resolved from the latest state, so a passenger that has since got off is
+            // left dangling rather than still pointing at it
+            let id = details.id;
+            let passengers = reg.add_entry(entity_dir, "passengers", DirEntry::default());
+            for &passenger in &details.passengers {
+                reg.add_entry(
+                    passengers,
+                    passenger.to_string(),
+                    LinkEntry::build(move |state| {
+                        let entity = state.entities.iter().find(|e| e.id == id)?;
+                        entity
+                            .passengers
+                            .contains(&passenger)
+                            .then(|| format!("../../{}", passenger).into())
+                    })
+                    .filter(filter_in_game)
+                    .finish(),
+                );
+            }
+        }
+    }
+}
+
+fn mk_inventory_dir(state: &GameState, reg: &mut DynamicDirRegistrationer) {
+    let inventory = match state.inventory.as_ref() {
+        Some(inv) => inv,
+        None => return,
+    };
+
+    for slot in 0..inventory.size {
+        mk_slot_dir(reg, reg.parent(), slot);
+    }
+
+    type SlotFn = fn(&InventoryDetails) -> Option<i32>;
+    let links: [(&str, SlotFn); 7] = [
+        ("selected", |inv| inv.selected),
+        ("mainhand", |inv| inv.mainhand),
+        ("offhand", |inv| inv.offhand),
+        ("head", |inv| inv.head),
+        ("chest", |inv| inv.chest),
+        ("legs", |inv| inv.legs),
+        ("feet", |inv| inv.feet),
+    ];
+
+    for (name, slot_fn) in links {
+        if slot_fn(inventory).is_none() {
+            continue;
+        }
+
+        // resolved from the latest state, as the selected slot can change
+        reg.add_root_entry(
+            name,
+            LinkEntry::build(move |state| {
+                let slot = slot_fn(state.inventory.as_ref()?)?;
+                Some(slot.to_string().into())
+            })
+            .finish(),
+        );
+    }
+}
+
+/// One dir per active status effect, which is removed from the entity with rmdir
+fn mk_effects_dir(state: &GameState, reg: &mut DynamicDirRegistrationer) {
+    reg.add_root_entry(
+        "add",
+        FileEntry::build(FileBehaviour::CommandProxy {
+            readme: r#"Give the entity a status effect.
+Format: "[effect] [duration in seconds] [amplifier]"
+Examples:
+   speed 30 1
+   minecraft:night_vision 600 0"#
+                .into(),
+            produce_cmd_fn: effect_give_command,
+        })
+        .finish(),
+    );
+
+    reg.add_root_entry(
+        "clear",
+        FileEntry::build(FileBehaviour::CommandProxy {
+            readme: "Write anything to remove every status effect from the entity".into(),
+            produce_cmd_fn: |_| Some("effect clear @s".to_owned()),
+        })
+        .finish(),
+    );
+
+    for effect in &state.effects {
+        let effect_dir = reg.add_root_entry(
+            effect.clone(),
+            DirEntry::build()
+                .associated_data(EntryAssociatedData::Effect(effect.clone().into()))
+                .removable(CommandType::EntityEffectRemove)
+                .finish(),
+        );
+
+        reg.add_entry(
+            effect_dir,
+            "amplifier",
+            FileEntry::build(ReadOnly(CommandType::EntityEffectAmplifier, Integer)).finish(),
+        );
+        reg.add_entry(
+            effect_dir,
+            "duration",
+            FileEntry::build(ReadOnly(CommandType::EntityEffectDuration, Integer)).finish(),
+        );
+        reg.add_entry(
+            effect_dir,
+            "ambient",
+            FileEntry::build(ReadOnly(CommandType::EntityEffectAmbient, Bool)).finish(),
+        );
+        reg.add_entry(
+            effect_dir,
+            "show_particles",
+            FileEntry::build(ReadOnly(CommandType::EntityEffectParticles, Bool)).finish(),
+        );
+    }
+}
+
+/// Listed by the game, so attributes added by mods show up too
+fn mk_attributes_dir(state: &GameState, reg: &mut DynamicDirRegistrationer) {
+    for attribute in &state.attributes {
+        let attribute_dir = reg.add_root_entry(
+            attribute.clone(),
+            DirEntry::build()
+                .associated_data(EntryAssociatedData::Attribute(attribute.clone().into()))
+                .finish(),
+        );
+
+        reg.add_entry(
+            attribute_dir,
+            "base",
+            FileEntry::build(ReadWrite(CommandType::EntityAttributeBase, Float)).finish(),
+        );
+        reg.add_entry(
+            attribute_dir,
+            "value",
+            FileEntry::build(ReadOnly(CommandType::EntityAttributeValue, Float)).finish(),
+        );
+    }
+}
+
+/// Inventory slot of an entity, or of a container block
+fn mk_slot_dir(reg: &mut DynamicDirRegistrationer, parent: u64, slot: i32) {
+    let slot_dir = reg.add_entry(
+        parent,
+        slot.to_string(),
+        DirEntry::build()
+            .associated_data(EntryAssociatedData::InventorySlot(slot))
+            .finish(),
+    );
+
+    reg.add_entry(
+        slot_dir,
+        "item",
+        FileEntry::build(ReadWrite(CommandType::InventorySlotItem, String)).finish(),
+    );
+    reg.add_entry(
+        slot_dir,
+        "count",
+        FileEntry::build(ReadWrite(CommandType::InventorySlotCount, Integer)).finish(),
+    );
+    reg.add_entry(
+        slot_dir,
+        "nbt",
+        FileEntry::build(ReadWrite(CommandType::InventorySlotNbt, String)).finish(),
+    );
+}
+
+/// Validates input so a typo doesn't reach the game as a broken command
+fn give_command(input: &str) -> Option<std::string::String> {
+    let mut lines = input.lines();
+    let item = lines.next()?.trim(); // required
+    let count = lines.next()?.trim(); // required
+    let nbt = lines.next().unwrap_or_default().trim(); // optional
+
+    let valid_item = !item.is_empty()
+        && item
+            .chars()
+            .all(|c| matches!(c, 'a'..='z' | '0'..='9' | '_' | '-' | '.' | ':' | '/'));
+    if !valid_item || item.matches(':').count() > 1 {
+        return None;
+    }
+
+    let count = count
+        .parse::<u32>()
+        .ok()
+        .filter(|n| (1..=6400).contains(n))?;
+
+    let valid_nbt = nbt.is_empty() || (nbt.starts_with('{') && nbt.ends_with('}'));
+    if !valid_nbt {
+        return None;
+    }
+
+    Some(format!("give @s {item}{nbt} {count}"))
+}
+
+/// `effect duration amplifier`, with the duration in seconds
+fn effect_give_command(input: &str) -> Option<std::string::String> {
+    let mut parts = input.split_whitespace();
+    let (effect, duration, amplifier) = (parts.next()?, parts.next()?, parts.next()?);
+    if parts.next().is_some() {
+        return None;
+    }
+
+    let valid_effect = effect
+        .chars()
+        .all(|c| matches!(c, 'a'..='z' | '0'..='9' | '_' | '-' | '.' | ':' | '/'));
+    if !valid_effect || effect.matches(':').count() > 1 {
+        return None;
+    }
+
+    // limits of the effect command
+    let duration = duration
+        .parse::<u32>()
+        .ok()
+        .filter(|n| (1..=1_000_000).contains(n))?;
+    let amplifier = amplifier.parse::<u8>().ok()?;
+
+    Some(format!("effect give @s {effect} {duration} {amplifier}"))
+}
+
+// ------
+fn parse_block_position(s: &str) -> Option<[i32; 3]> {
     let mut parts = s
         .splitn(3, &[',', ' '])
         .filter_map(|s| s.parse::<f32>().ok().map(|f| f as i32));
@@ -421,9 +1222,251 @@ fn parse_block_position(s: &str) -> Option<[i32; 3]> {
     }
 }
 
+/// Any bigger than a 64 block cube is a lot of text for one read
+const MAX_REGION_VOLUME: i64 = 64 * 64 * 64;
+
+/// Each column has to be loaded by the game
+const MAX_MAP_AREA: i64 = 256 * 256;
+
+/// Minimum and maximum corners of `x1,z1..x2,z2`, in either order. Fractional corners are in the
+/// column they fall in, so -0.5 is in column -1
+fn parse_map_area(s: &str) -> Option<[i32; 4]> {
+    let parse_corner = |s: &str| {
+        let mut parts = s
+            .splitn(2, &[',', ' '])
+            .filter_map(|s| s.parse::<f64>().ok())
+            .filter(|f| f.is_finite())
+            .map(|f| f.floor() as i32);
+        match (parts.next(), parts.next()) {
+            (Some(x), Some(z)) => Some([x, z]),
+            _ => None,
+        }
+    };
+
+    let (a, b) = s.split_once("..")?;
+    let [x1, z1] = parse_corner(a)?;
+    let [x2, z2] = parse_corner(b)?;
+
+    let area = ((x1 as i64 - x2 as i64).abs() + 1) * ((z1 as i64 - z2 as i64).abs() + 1);
+    if area > MAX_MAP_AREA {
+        return None;
+    }
+
+    Some([x1.min(x2), z1.min(z2), x1.max(x2), z1.max(z2)])
+}
+
+/// Minimum and maximum corners of `x1,y1,z1..x2,y2,z2`, in either order
+fn parse_region(s: &str) -> Option<[i32; 6]> {
+    let (a, b) = s.split_once("..")?;
+    let a = parse_block_position(a)?;
+    let b = parse_block_position(b)?;
+
+    let volume = a
+        .iter()
+        .zip(b.iter())
+        .map(|(a, b)| (*a as i64 - *b as i64).abs() + 1)
+        .product::<i64>();
+    if volume > MAX_REGION_VOLUME {
+        return None;
+    }
+
+    let [x1, y1, z1] = a;
+    let [x2, y2, z2] = b;
+    Some([
+        x1.min(x2),
+        y1.min(y2),
+        z1.min(z2),
+        x1.max(x2),
+        y1.max(y2),
+        z1.max(z2),
+    ])
+}
+
+/// One `x y z type` line per block
+fn render_region(state: &GameState) -> Option<Vec<u8>> {
+    use std::io::Write;
+
+    let region = state.region.as_ref()?;
+    let mut out = Vec::with_capacity(region.blocks.len() * 32);
+    for ([x, y, z], idx) in region.positioned_blocks()? {
+        // only the type, without block state properties
+        let state = &region.palette[idx];
+        let ty = state.split_once('[').map_or(state.as_str(), |(ty, _)| ty);
+        writeln!(out, "{} {} {} {}", x, y, z, ty).ok()?;
+    }
+
+    Some(out)
+}
+
+/// Vanilla structure block file of the whole region
+fn render_schematic(state: &GameState) -> Option<Vec<u8>> {
+    let region = state.region.as_ref()?;
+    let from = region.from;
+    let blocks = region
+        .positioned_blocks()?
+        .into_iter()
+        .map(|([x, y, z], idx)| ([x - from.x(), y - from.y(), z - from.z()], idx))
+        .collect();
+
+    let schematic = Schematic {
+        size: region.size(),
+        palette: region.palette.clone(),
+        blocks,
+    };
+    Some(schematic.encode())
+}
+
+/// Decodes a structure block file to one `x y z block_state` line per block, relative to the
+/// paste position
+fn paste_input(data: &[u8]) -> Option<std::string::String> {
+    use std::fmt::Write;
+
+    let schematic = match Schematic::decode(data) {
+        Ok(s) => s,
+        Err(err) => {
+            log::warn!("invalid schematic: {}", err);
+            return None;
+        }
+    };
+
+    let volume = schematic.size.iter().map(|s| *s as i64).product::<i64>();
+    if volume > MAX_REGION_VOLUME {
+        return None;
+    }
+
+    let mut out = std::string::String::new();
+    for ([x, y, z], idx) in &schematic.blocks {
+        writeln!(out, "{} {} {} {}", x, y, z, schematic.palette[*idx]).ok()?;
+    }
+    Some(out)
+}
+
+fn pretty_snbt(resp: &str) -> Option<std::string::String> {
+    snbt::parse(resp).ok().map(|tag| snbt::to_pretty(&tag))
+}
+
+fn snbt_as_json(resp: &str) -> Option<std::string::String> {
+    snbt::parse(resp).ok().map(|tag| snbt::to_json(&tag))
+}
+
+/// Only a compound can be merged into existing data. Normalised to compact SNBT so the game
+/// parses exactly what was validated
+fn nbt_merge_input(input: &str) -> Option<std::string::String> {
+    match snbt::parse(input) {
+        Ok(tag @ Tag::Compound(_)) => Some(snbt::to_compact(&tag)),
+        Ok(_) => None,
+        Err(err) => {
+            log::debug!("invalid snbt: {}", err);
+            None
+        }
+    }
+}
+
+/// Same as the fill command's own limit
+const MAX_FILL_VOLUME: i64 = 32768;
+
+/// Validates `x1,y1,z1 x2,y2,z2 block [mode]` so a typo or an oversized region doesn't reach the
+/// game, and normalises it to the fill command's arguments
+fn fill_input(input: &str) -> Option<std::string::String> {
+    let mut parts = input.split_whitespace();
+    let from = parse_block_position(parts.next()?)?; // required
+    let to = parse_block_position(parts.next()?)?; // required
+    let block = parts.next()?; // required
+    let mode = parts.next().unwrap_or("replace"); // optional
+    if parts.next().is_some() {
+        return None;
+    }
+
+    let in_world = |[x, y, z]: [i32; 3]| {
+        (-30_000_000..30_000_000).contains(&x)
+            && (-64..320).contains(&y)
+            && (-30_000_000..30_000_000).contains(&z)
+    };
+    if !in_world(from) || !in_world(to) {
+        return None;
+    }
+
+    let volume = from
+        .iter()
+        .zip(to.iter())
+        .map(|(a, b)| (*a as i64 - *b as i64).abs() + 1)
+        .product::<i64>();
+    if volume > MAX_FILL_VOLUME {
+        return None;
+    }
+
+    // block state properties are left to the game, e.g. oak_stairs[facing=east]
+    let (id, properties) = match block.split_once('[') {
+        Some((id, properties)) => (id, Some(properties)),
+        None => (block, None),
+    };
+    let valid_id = !id.is_empty()
+        && id.matches(':').count() <= 1
+        && id
+            .chars()
+            .all(|c| matches!(c, 'a'..='z' | '0'..='9' | '_' | '-' | '.' | ':' | '/'));
+    let valid_properties =
+        properties.is_none_or(|p| p.strip_suffix(']').is_some_and(|p| !p.contains(['[', ']'])));
+    if !valid_id || !valid_properties {
+        return None;
+    }
+
+    if !matches!(mode, "replace" | "hollow" | "outline" | "keep") {
+        return None;
+    }
+
+    let [x1, y1, z1] = from;
+    let [x2, y2, z2] = to;
+    Some(format!(
+        "{} {} {} {} {} {} {} {}",
+        x1, y1, z1, x2, y2, z2, block, mode
+    ))
+}
+
+/// x, y, z and radius, which is rounded up to a whole block
+fn parse_entities_near(s: &str) -> Option<EntitiesNear> {
+    let mut parts = s
+        .splitn(4, &[',', ' '])
+        .filter_map(|s| s.parse::<f64>().ok());
+
+    match (parts.next(), parts.next(), parts.next(), parts.next()) {
+        (Some(x), Some(y), Some(z), Some(radius)) if radius > 0.0 => {
+            EntitiesNear::new([x, y, z], radius.ceil() as u32)
+        }
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use std::ffi::OsStr;
+
+    use ipc::generated::{BlockPos, Vec3};
+
     use super::*;
+    use crate::structure::Entry;
+
+    /// Follows `path` from `parent`, panicking if any of it is missing
+    fn lookup_path(structure: &FilesystemStructure, parent: u64, path: &[&str]) -> u64 {
+        path.iter().fold(parent, |parent, name| {
+            structure
+                .lookup_child(parent, OsStr::new(name))
+                .unwrap_or_else(|| panic!("missing {}", name))
+                .0
+        })
+    }
+
+    /// A living entity at the origin that isn't riding anything
+    fn entity_details(id: i32, ty: &str) -> EntityDetails {
+        EntityDetails {
+            id,
+            living: true,
+            ty: ty.to_owned(),
+            pos: Vec3::new(0.0, 64.0, 0.0),
+            vehicle: None,
+            passengers: vec![],
+        }
+    }
 
     #[test]
     fn block_pos_parsing() {
@@ -445,4 +1488,770 @@ mod tests {
         assert!(parse_block_position("500,200").is_none());
         assert!(parse_block_position("123,nice,200").is_none());
     }
+
+    #[test]
+    fn entities_near_parsing() {
+        let near = |s| {
+            parse_entities_near(s).map(|near| {
+                let centre = near.centre();
+                ([centre.x(), centre.y(), centre.z()], near.radius())
+            })
+        };
+
+        assert_eq!(near("1,64,-20,16"), Some(([1.0, 64.0, -20.0], 16)));
+        assert_eq!(
+            near("0.5 22.3 41.5555 8.5"),
+            Some(([0.5, 22.3, 41.5555], 9))
+        );
+        assert_eq!(near("-0.7,64,-20,0.1"), Some(([-0.7, 64.0, -20.0], 1)));
+
+        // measured from the exact centre, not one truncated towards 0
+        let near = parse_entities_near("-0.7,64,-20,0.1").unwrap();
+        assert!(near.contains(&Vec3::new(-1.6, 64.0, -20.0)));
+        assert!(!near.contains(&Vec3::new(0.5, 64.0, -20.0)));
+
+        assert!(parse_entities_near("1,64,-20,nan").is_none());
+        assert!(parse_entities_near("inf,64,-20,5").is_none());
+        assert!(parse_entities_near("1,64,-20").is_none());
+        assert!(parse_entities_near("1,64,-20,0").is_none());
+        assert!(parse_entities_near("1,64,-20,-5").is_none());
+        assert!(parse_entities_near("1,64,-20,5,5").is_none());
+        assert!(parse_entities_near("1,64,-20,big").is_none());
+    }
+
+    #[test]
+    fn region_parsing() {
+        assert!(matches!(
+            parse_region("0,60,0..15,70,15"),
+            Some([0, 60, 0, 15, 70, 15])
+        ));
+        assert!(matches!(
+            parse_region("5,70,-2..-3,60.5,4"),
+            Some([-3, 60, -2, 5, 70, 4])
+        ));
+        assert!(matches!(
+            parse_region("1,2,3..1,2,3"),
+            Some([1, 2, 3, 1, 2, 3])
+        ));
+        assert!(parse_region("0,0,0..63,63,63").is_some());
+        assert!(parse_region("0,0,0..64,63,63").is_none());
+        assert!(parse_region("0,0,0").is_none());
+        assert!(parse_region("0,0,0..1,1").is_none());
+        assert!(parse_region("0,0,0..oof").is_none());
+    }
+
+    #[test]
+    fn phantom_region_file() {
+        use crate::state::RegionDetails;
+
+        let mut structure = create_structure();
+        let regions = lookup_path(&structure, 1, &["worlds", "nether", "regions"]);
+
+        let name = OsStr::new("1,64,0..0,65,0");
+        let interest = structure.interest_for_inode(regions, Some(name));
+        structure.ensure_generated(&GameState::default(), interest);
+        let (region, entry) = structure
+            .lookup_child(regions, name)
+            .expect("no region file");
+        let render = match entry {
+            Entry::File(f) => match f.behaviour() {
+                FromState(render) => *render,
+                other => panic!("unexpected behaviour {:?}", other),
+            },
+            _ => panic!("not a file"),
+        };
+
+        let interest = structure.interest_for_inode(region, None).as_interest();
+        let (from, to) = (BlockPos::new(0, 64, 0), BlockPos::new(1, 65, 0));
+        assert_eq!(interest.region, Some((from, to)));
+        assert_eq!(interest.target_world, Some(Dimension::Nether));
+
+        let state = GameState {
+            region: Some(RegionDetails {
+                from,
+                to,
+                palette: vec!["minecraft:air".to_owned(), "minecraft:stone".to_owned()],
+                blocks: vec![1, 1, 0, 1],
+            }),
+            ..GameState::default()
+        };
+        let rendered = render(&state).expect("render failed");
+        assert_eq!(
+            std::str::from_utf8(&rendered).unwrap(),
+            "0 64 0 minecraft:stone\n1 64 0 minecraft:stone\n\
+             0 65 0 minecraft:air\n1 65 0 minecraft:stone\n"
+        );
+
+        // missing blocks
+        let state = GameState {
+            region: state.region.map(|r| RegionDetails {
+                blocks: vec![1, 1],
+                ..r
+            }),
+            ..GameState::default()
+        };
+        assert!(render(&state).is_none());
+    }
+
+    #[test]
+    fn phantom_files_are_forgotten() {
+        let mut structure = create_structure();
+        let regions = lookup_path(&structure, 1, &["worlds", "overworld", "regions"]);
+
+        let name = OsStr::new("0,0,0..1,1,1");
+        let interest = structure.interest_for_inode(regions, Some(name));
+        structure.ensure_generated(&GameState::default(), interest);
+        let (region, _) = structure.lookup_child(regions, name).unwrap();
+        structure.count_lookup(region);
+        structure.count_lookup(region);
+
+        structure.forget(region, 1);
+        assert!(structure.lookup_child(regions, name).is_some());
+        structure.forget(region, 1);
+        assert!(structure.lookup_child(regions, name).is_none());
+        assert!(structure.lookup_inode(region).is_none());
+
+        // static entries are never forgotten
+        structure.count_lookup(regions);
+        structure.forget(regions, 1);
+        assert!(structure.lookup_inode(regions).is_some());
+    }
+
+    #[test]
+    fn map_area_parsing() {
+        assert!(matches!(
+            parse_map_area("-64,-64..63,63"),
+            Some([-64, -64, 63, 63])
+        ));
+        assert!(matches!(
+            parse_map_area("10.5 -3..2 8"),
+            Some([2, -3, 10, 8])
+        ));
+        assert!(matches!(
+            parse_map_area("-0.5,3..4,-2.5"),
+            Some([-1, -3, 4, 3])
+        ));
+        assert!(parse_map_area("nan,0..1,1").is_none());
+        assert!(parse_map_area("0,0..255,255").is_some());
+        assert!(parse_map_area("0,0..256,255").is_none());
+        assert!(parse_map_area("0,0,0..1,1").is_none());
+        assert!(parse_map_area("0,0").is_none());
+    }
+
+    #[test]
+    fn phantom_map_files() {
+        use crate::state::ColumnDetails;
+
+        let mut structure = create_structure();
+        let maps = lookup_path(&structure, 1, &["worlds", "overworld", "maps"]);
+
+        for (name, kind) in [("0,0..3,1.pgm", "P5"), ("0,0..3,1.ppm", "P6")] {
+            let name = OsStr::new(name);
+            let interest = structure.interest_for_inode(maps, Some(name));
+            structure.ensure_generated(&GameState::default(), interest);
+            let (map, entry) = structure.lookup_child(maps, name).expect("no map file");
+
+            let interest = structure.interest_for_inode(map, None).as_interest();
+            assert_eq!(
+                interest.columns,
+                Some((ColumnPos::new(0, 0), ColumnPos::new(3, 1)))
+            );
+
+            let render = match entry {
+                Entry::File(f) => match f.behaviour() {
+                    FromState(render) => *render,
+                    other => panic!("unexpected behaviour {:?}", other),
+                },
+                _ => panic!("not a file"),
+            };
+            let state = GameState {
+                columns: Some(ColumnDetails {
+                    from: ColumnPos::new(0, 0),
+                    to: ColumnPos::new(3, 1),
+                    palette: vec!["minecraft:stone".to_owned()],
+                    tops: vec![0; 8],
+                    heights: vec![64; 8],
+                }),
+                ..GameState::default()
+            };
+            let image = render(&state).expect("render failed");
+            assert!(image.starts_with(format!("{}\n4 2\n255\n", kind).as_bytes()));
+        }
+
+        assert!(structure
+            .interest_for_inode(maps, Some(OsStr::new("0,0..3,1.png")))
+            .as_interest()
+            .columns
+            .is_none());
+    }
+
+    #[test]
+    fn schematic_export_and_paste() {
+        use crate::state::RegionDetails;
+
+        let state = GameState {
+            region: Some(RegionDetails {
+                from: BlockPos::new(10, 64, -5),
+                to: BlockPos::new(11, 64, -4),
+                palette: vec![
+                    "minecraft:air".to_owned(),
+                    "minecraft:oak_stairs[facing=east,half=top]".to_owned(),
+                ],
+                blocks: vec![1, 0, 0, 1],
+            }),
+            ..GameState::default()
+        };
+
+        // block states are only in schematics
+        let region = render_region(&state).expect("render failed");
+        assert!(std::str::from_utf8(&region)
+            .unwrap()
+            .starts_with("10 64 -5 minecraft:oak_stairs\n"));
+
+        // relative to the paste position
+        let nbt = render_schematic(&state).expect("render failed");
+        assert_eq!(
+            paste_input(&nbt).as_deref(),
+            Some(
+                "0 0 0 minecraft:oak_stairs[facing=east,half=top]\n\
+                 1 0 0 minecraft:air\n\
+                 0 0 1 minecraft:air\n\
+                 1 0 1 minecraft:oak_stairs[facing=east,half=top]\n"
+            )
+        );
+
+        assert!(paste_input(&nbt[..10]).is_none());
+        assert!(paste_input(b"oof").is_none());
+    }
+
+    #[test]
+    fn dynamic_block_dir() {
+        use crate::state::BlockDetails;
+
+        let mut structure = create_structure();
+        let blocks = lookup_path(&structure, 1, &["worlds", "overworld", "blocks"]);
+        let interest = structure.interest_for_inode(blocks, Some(OsStr::new("1,2,3")));
+        let pos = BlockPos::new(1, 2, 3);
+        assert_eq!(interest.as_interest().target_block, Some(pos));
+
+        let state = GameState {
+            block: Some(BlockDetails {
+                pos,
+                properties: vec!["facing".to_owned(), "waterlogged".to_owned()],
+                has_block_entity: true,
+                container_size: Some(2),
+            }),
+            ..GameState::default()
+        };
+        structure.ensure_generated(&state, interest);
+        let block = lookup_path(&structure, blocks, &["1,2,3"]);
+
+        let state_dir = lookup_path(&structure, block, &["state"]);
+        let names = structure
+            .lookup_children(state_dir)
+            .unwrap()
+            .map(|(_, name)| name.to_owned())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["facing", "waterlogged"]);
+
+        let facing = lookup_path(&structure, state_dir, &["facing"]);
+        let cmd_state = structure.command_state_for_file(facing);
+        assert_eq!(cmd_state.target_property.as_deref(), Some("facing"));
+        assert_eq!(cmd_state.target_block, Some(pos));
+        assert_eq!(cmd_state.target_world, Some(Dimension::Overworld));
+
+        // partial writes are merged by the game, so only malformed input is rejected here
+        let nbt = lookup_path(&structure, block, &["nbt"]);
+        match structure.lookup_inode(nbt) {
+            Some(Entry::File(f)) => match f.behaviour() {
+                FileBehaviour::Reformatted {
+                    cmd: CommandType::BlockNbt,
+                    validate_fn: Some(validate),
+                    ..
+                } => {
+                    assert!(validate("{CustomName:'\"x\"'}").is_some());
+                    assert!(validate("{Items:[").is_none());
+                }
+                other => panic!("unexpected behaviour {:?}", other),
+            },
+            _ => panic!("not a file"),
+        }
+
+        let count = lookup_path(&structure, block, &["contents", "1", "count"]);
+        let cmd_state = structure.command_state_for_file(count);
+        assert_eq!(cmd_state.target_slot, Some(1));
+        assert_eq!(cmd_state.target_block, Some(pos));
+        assert!(cmd_state.target_entity.is_none());
+    }
+
+    #[test]
+    fn dynamic_gamerules_dir() {
+        use crate::state::GameRuleDetails;
+
+        let mut structure = create_structure();
+        let gamerules = lookup_path(&structure, 1, &["server", "gamerules"]);
+
+        let interest = structure.interest_for_inode(gamerules, None);
+        assert!(interest.as_interest().gamerules);
+
+        let state = GameState {
+            gamerules: vec![
+                GameRuleDetails {
+                    name: "keepInventory".to_owned(),
+                    ty: GameRuleType::Bool,
+                },
+                GameRuleDetails {
+                    name: "randomTickSpeed".to_owned(),
+                    ty: GameRuleType::Int,
+                },
+            ],
+            ..GameState::default()
+        };
+        structure.ensure_generated(&state, interest);
+
+        for (name, ty) in [("keepInventory", Bool), ("randomTickSpeed", Integer)] {
+            let (inode, entry) = structure
+                .lookup_child(gamerules, OsStr::new(name))
+                .unwrap_or_else(|| panic!("missing {}", name));
+            assert!(matches!(
+                entry,
+                Entry::File(file) if *file.behaviour() == ReadWrite(CommandType::ServerGameRule, ty)
+            ));
+
+            let cmd_state = structure.command_state_for_file(inode);
+            assert_eq!(cmd_state.target_gamerule.as_deref(), Some(name));
+        }
+    }
+
+    #[test]
+    fn dynamic_objectives_dir() {
+        use crate::state::ObjectiveDetails;
+
+        let mut structure = create_structure();
+        let objectives = lookup_path(&structure, 1, &["server", "scoreboard", "objectives"]);
+        assert!(matches!(
+            structure.lookup_inode(objectives),
+            Some(Entry::Dir(dir)) if dir.create_cmd() == Some(CommandType::ScoreboardObjectiveAdd)
+        ));
+        let perm = |structure: &FilesystemStructure, inode| match structure.lookup_inode(inode) {
+            Some(Entry::Dir(dir)) => dir.perm(),
+            _ => panic!("not a dir"),
+        };
+        assert_eq!(perm(&structure, objectives), 0o755);
+
+        let interest = structure.interest_for_inode(objectives, None);
+        assert!(interest.as_interest().objectives);
+
+        let state = GameState {
+            objectives: vec![ObjectiveDetails {
+                name: "kills".to_owned(),
+                holders: vec!["Steve".to_owned(), "a/b".to_owned(), "..".to_owned()],
+            }],
+            ..GameState::default()
+        };
+        structure.ensure_generated(&state, interest);
+
+        let (kills, entry) = structure
+            .lookup_child(objectives, OsStr::new("kills"))
+            .expect("missing objective");
+        assert!(matches!(
+            entry,
+            Entry::Dir(dir) if dir.remove_cmd() == Some(CommandType::ScoreboardObjectiveRemove)
+        ));
+        assert_eq!(perm(&structure, kills), 0o555);
+        for name in ["criteria", "display_name", "display_slot", "scores"] {
+            assert!(
+                structure.lookup_child(kills, OsStr::new(name)).is_some(),
+                "missing {}",
+                name
+            );
+        }
+
+        let (scores, _) = structure.lookup_child(kills, OsStr::new("scores")).unwrap();
+        let names = structure
+            .lookup_children(scores)
+            .unwrap()
+            .map(|(_, name)| name.to_owned())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["Steve"]);
+
+        let (steve, _) = structure.lookup_child(scores, OsStr::new("Steve")).unwrap();
+        let cmd_state = structure.command_state_for_file(steve);
+        assert_eq!(cmd_state.target_objective.as_deref(), Some("kills"));
+        assert_eq!(cmd_state.target_score_holder.as_deref(), Some("Steve"));
+
+        // regenerated straight away after mkdir or rmdir, rather than once stale
+        let needs_objectives = |structure: &FilesystemStructure| {
+            structure
+                .interest_for_inode(objectives, None)
+                .as_interest()
+                .objectives
+        };
+        assert!(!needs_objectives(&structure));
+        structure.invalidate_dynamic(objectives);
+        assert!(needs_objectives(&structure));
+    }
+
+    #[test]
+    fn dynamic_chunks_dir() {
+        use crate::state::ChunkDetails;
+
+        let mut structure = create_structure();
+        let chunks = lookup_path(&structure, 1, &["worlds", "end", "chunks"]);
+
+        let interest = structure.interest_for_inode(chunks, None).as_interest();
+        assert!(interest.chunks);
+        assert!(!interest.entities_by_id);
+        assert_eq!(interest.target_world, Some(Dimension::End));
+
+        let state = GameState {
+            chunks: vec![
+                ChunkDetails {
+                    x: 0,
+                    z: -1,
+                    entities: vec![10, 11],
+                },
+                ChunkDetails {
+                    x: 1,
+                    z: -1,
+                    entities: vec![],
+                },
+            ],
+            ..GameState::default()
+        };
+        structure.ensure_generated(&state, structure.interest_for_inode(chunks, None));
+
+        let (chunk, _) = structure
+            .lookup_child(chunks, OsStr::new("0,-1"))
+            .expect("missing chunk");
+        assert!(structure.lookup_child(chunks, OsStr::new("1,-1")).is_some());
+
+        let (forceload, _) = structure
+            .lookup_child(chunk, OsStr::new("forceload"))
+            .expect("missing forceload");
+        let cmd_state = structure.command_state_for_file(forceload);
+        assert_eq!(cmd_state.target_chunk, Some(ColumnPos::new(0, -1)));
+        assert_eq!(cmd_state.target_world, Some(Dimension::End));
+
+        let (entities, _) = structure
+            .lookup_child(chunk, OsStr::new("entities"))
+            .expect("missing entities");
+        let (_, link) = structure
+            .lookup_child(entities, OsStr::new("11"))
+            .expect("missing entity link");
+        let target = match link {
+            Entry::Link(link) => (link.target())(&state),
+            _ => panic!("entity is not a link"),
+        };
+        assert_eq!(target.as_deref(), Some("../../../entities/by-id/11"));
+    }
+
+    #[test]
+    fn dynamic_effects_dir() {
+        use ipc::TargetEntity;
+
+        let mut structure = create_structure();
+        let (player, _) = structure
+            .lookup_child(1, OsStr::new("player"))
+            .expect("no player dir");
+        let state = GameState {
+            player_entity_id: Some(0),
+            effects: vec!["minecraft:speed".to_owned()],
+            ..GameState::default()
+        };
+        let interest = structure.interest_for_inode(player, Some(OsStr::new("effects")));
+        structure.ensure_generated(&state, interest);
+        let (effects, entry) = structure
+            .lookup_child(player, OsStr::new("effects"))
+            .expect("no effects dir");
+
+        // writable so effects can be removed with rmdir
+        assert!(matches!(entry, Entry::Dir(dir) if dir.perm() == 0o755));
+
+        let interest = structure.interest_for_inode(effects, None);
+        assert!(interest.as_interest().effects);
+        assert_eq!(
+            interest.as_interest().target_entity,
+            Some(TargetEntity::Player)
+        );
+        structure.ensure_generated(&state, interest);
+
+        let (speed, entry) = structure
+            .lookup_child(effects, OsStr::new("minecraft:speed"))
+            .expect("missing effect");
+        assert!(matches!(
+            entry,
+            Entry::Dir(dir) if dir.remove_cmd() == Some(CommandType::EntityEffectRemove)
+        ));
+
+        let (amplifier, _) = structure
+            .lookup_child(speed, OsStr::new("amplifier"))
+            .expect("missing amplifier");
+        let cmd_state = structure.command_state_for_file(amplifier);
+        assert_eq!(cmd_state.target_effect.as_deref(), Some("minecraft:speed"));
+        assert_eq!(cmd_state.target_entity, Some(TargetEntity::Player));
+
+        for name in ["add", "clear"] {
+            let (_, entry) = structure
+                .lookup_child(effects, OsStr::new(name))
+                .unwrap_or_else(|| panic!("missing {}", name));
+            assert!(matches!(entry, Entry::File(_)));
+        }
+    }
+
+    #[test]
+    fn entity_vehicle_links() {
+        let mut structure = create_structure();
+        let by_id = lookup_path(&structure, 1, &["worlds", "overworld", "entities", "by-id"]);
+
+        let entity = |id, vehicle, passengers| EntityDetails {
+            vehicle,
+            passengers,
+            ..entity_details(id, "minecraft:pig")
+        };
+        let state = GameState {
+            player_entity_id: Some(0),
+            entities: vec![entity(12, None, vec![13]), entity(13, Some(12), vec![])],
+            ..GameState::default()
+        };
+        for name in ["12", "13"] {
+            let interest = structure.interest_for_inode(by_id, Some(OsStr::new(name)));
+            structure.ensure_generated(&state, interest);
+        }
+
+        let (pig, _) = structure.lookup_child(by_id, OsStr::new("12")).unwrap();
+        let (rider, _) = structure.lookup_child(by_id, OsStr::new("13")).unwrap();
+        let (passengers, _) = structure
+            .lookup_child(pig, OsStr::new("passengers"))
+            .expect("missing passengers");
+        let link = |dir, name| match structure.lookup_child(dir, OsStr::new(name)) {
+            Some((_, Entry::Link(link))) => link,
+            _ => panic!("missing link {}", name),
+        };
+
+        let vehicle = link(rider, "vehicle");
+        let passenger = link(passengers, "13");
+        assert_eq!((vehicle.target())(&state).as_deref(), Some("../12"));
+        assert_eq!((passenger.target())(&state).as_deref(), Some("../../13"));
+
+        // both follow the latest state once the rider gets off
+        let state = GameState {
+            player_entity_id: Some(0),
+            entities: vec![entity(12, None, vec![]), entity(13, None, vec![])],
+            ..GameState::default()
+        };
+        assert!((vehicle.target())(&state).is_none());
+        assert!((passenger.target())(&state).is_none());
+    }
+
+    #[test]
+    fn dynamic_attributes_dir() {
+        use ipc::TargetEntity;
+
+        let mut structure = create_structure();
+        let by_id = lookup_path(&structure, 1, &["worlds", "overworld", "entities", "by-id"]);
+
+        let state = GameState {
+            player_entity_id: Some(0),
+            entities: vec![entity_details(12, "minecraft:zombie")],
+            attributes: vec![
+                "minecraft:generic.max_health".to_owned(),
+                "minecraft:generic.armor".to_owned(),
+            ],
+            ..GameState::default()
+        };
+        let interest = structure.interest_for_inode(by_id, Some(OsStr::new("12")));
+        structure.ensure_generated(&state, interest);
+        let (zombie, _) = structure
+            .lookup_child(by_id, OsStr::new("12"))
+            .expect("missing entity");
+        let (attributes, _) = structure
+            .lookup_child(zombie, OsStr::new("attributes"))
+            .expect("missing attributes dir");
+
+        let interest = structure.interest_for_inode(attributes, None);
+        assert!(interest.as_interest().attributes);
+        assert_eq!(
+            interest.as_interest().target_entity,
+            Some(TargetEntity::Entity(12))
+        );
+        structure.ensure_generated(&state, interest);
+
+        let names = structure
+            .lookup_children(attributes)
+            .unwrap()
+            .map(|(_, name)| name.to_owned())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            ["minecraft:generic.max_health", "minecraft:generic.armor"]
+        );
+
+        let (armor, _) = structure
+            .lookup_child(attributes, OsStr::new("minecraft:generic.armor"))
+            .unwrap();
+        for (name, behaviour) in [
+            ("base", ReadWrite(CommandType::EntityAttributeBase, Float)),
+            ("value", ReadOnly(CommandType::EntityAttributeValue, Float)),
+        ] {
+            let (inode, entry) = structure
+                .lookup_child(armor, OsStr::new(name))
+                .unwrap_or_else(|| panic!("missing {}", name));
+            assert!(matches!(entry, Entry::File(file) if *file.behaviour() == behaviour));
+
+            let cmd_state = structure.command_state_for_file(inode);
+            assert_eq!(
+                cmd_state.target_attribute.as_deref(),
+                Some("minecraft:generic.armor")
+            );
+            assert_eq!(cmd_state.target_entity, Some(TargetEntity::Entity(12)));
+        }
+    }
+
+    #[test]
+    fn effect_give_command_parsing() {
+        assert_eq!(
+            effect_give_command("speed 30 1").as_deref(),
+            Some("effect give @s speed 30 1")
+        );
+        assert_eq!(
+            effect_give_command(" minecraft:night_vision  600 0\n").as_deref(),
+            Some("effect give @s minecraft:night_vision 600 0")
+        );
+
+        assert!(effect_give_command("").is_none());
+        assert!(effect_give_command("speed").is_none());
+        assert!(effect_give_command("speed 30").is_none());
+        assert!(effect_give_command("speed 0 1").is_none());
+        assert!(effect_give_command("speed 30 256").is_none());
+        assert!(effect_give_command("speed 30 -1").is_none());
+        assert!(effect_give_command("speed 30 1 true").is_none());
+        assert!(effect_give_command("Speed 30 1").is_none());
+    }
+
+    #[test]
+    fn give_command_parsing() {
+        assert_eq!(
+            give_command("diamond\n64\n").as_deref(),
+            Some("give @s diamond 64")
+        );
+        assert_eq!(
+            give_command("minecraft:diamond_sword\n1\n{Damage:100}").as_deref(),
+            Some("give @s minecraft:diamond_sword{Damage:100} 1")
+        );
+        assert_eq!(
+            give_command("stone \n 2 \n\n").as_deref(),
+            Some("give @s stone 2")
+        );
+
+        assert!(give_command("").is_none());
+        assert!(give_command("diamond").is_none());
+        assert!(give_command("diamond\n").is_none());
+        assert!(give_command("diamond\nlots").is_none());
+        assert!(give_command("diamond\n0").is_none());
+        assert!(give_command("diamond\n-5").is_none());
+        assert!(give_command("diamond\n100000").is_none());
+        assert!(give_command("Diamond Sword\n1").is_none());
+        assert!(give_command("a:b:c\n1").is_none());
+        assert!(give_command("diamond\n1\nDamage:100").is_none());
+    }
+
+    #[test]
+    fn fill_input_parsing() {
+        assert_eq!(
+            fill_input("0,64,0 19,83,19 stone").as_deref(),
+            Some("0 64 0 19 83 19 stone replace")
+        );
+        assert_eq!(
+            fill_input("  -5,-64,10   5,-60,0 minecraft:glass hollow ").as_deref(),
+            Some("-5 -64 10 5 -60 0 minecraft:glass hollow")
+        );
+        assert_eq!(
+            fill_input("1,2,3 1,2,3 oak_stairs[facing=east,half=top] keep").as_deref(),
+            Some("1 2 3 1 2 3 oak_stairs[facing=east,half=top] keep")
+        );
+
+        // exactly the limit, 32x32x32
+        assert!(fill_input("0,0,0 31,31,31 air").is_some());
+        assert!(fill_input("0,0,0 32,31,31 air").is_none());
+        assert!(fill_input("0,-100,0 1,-99,1 air").is_none());
+        assert!(fill_input("0,319,0 0,320,0 air").is_none());
+        assert!(fill_input("30000000,0,0 30000000,0,0 air").is_none());
+
+        assert!(fill_input("").is_none());
+        assert!(fill_input("0,64,0 1,64,1").is_none());
+        assert!(fill_input("0,64 1,64,1 stone").is_none());
+        assert!(fill_input("0,64,0 1,64,1 Stone").is_none());
+        assert!(fill_input("0,64,0 1,64,1 a:b:c").is_none());
+        assert!(fill_input("0,64,0 1,64,1 oak_stairs[facing=east").is_none());
+        assert!(fill_input("0,64,0 1,64,1 stone destroy").is_none());
+        assert!(fill_input("0,64,0 1,64,1 stone keep now").is_none());
+    }
+
+    #[test]
+    fn nbt_merge_input_parsing() {
+        assert_eq!(
+            nbt_merge_input("{ Glowing: true, Tags: ['a', \"b\"] }").as_deref(),
+            Some("{Glowing:1b,Tags:[\"a\",\"b\"]}")
+        );
+        assert_eq!(nbt_merge_input("{}").as_deref(), Some("{}"));
+
+        assert!(nbt_merge_input("").is_none());
+        assert!(nbt_merge_input("[1, 2]").is_none());
+        assert!(nbt_merge_input("Glowing:1b").is_none());
+        assert!(nbt_merge_input("{Glowing:1b").is_none());
+        assert!(nbt_merge_input("{Tags:[1,\"a\"]}").is_none());
+    }
+
+    #[test]
+    fn entity_metadata_files() {
+        let mut structure = create_structure();
+        let by_id = lookup_path(&structure, 1, &["worlds", "overworld", "entities", "by-id"]);
+        let (player, _) = structure.lookup_child(1, OsStr::new("player")).unwrap();
+
+        let entity = |id, living| EntityDetails {
+            living,
+            ..entity_details(id, "minecraft:zombie")
+        };
+        let state = GameState {
+            player_entity_id: Some(0),
+            entities: vec![entity(12, true), entity(13, false)],
+            ..GameState::default()
+        };
+        for (parent, name) in [(by_id, Some("12")), (by_id, Some("13")), (player, None)] {
+            let interest = structure.interest_for_inode(parent, name.map(OsStr::new));
+            structure.ensure_generated(&state, interest);
+        }
+
+        let behaviour = |dir, name| match structure.lookup_child(dir, OsStr::new(name)) {
+            Some((_, Entry::File(file))) => Some(file.behaviour()),
+            _ => None,
+        };
+
+        let (zombie, _) = structure.lookup_child(by_id, OsStr::new("12")).unwrap();
+        let (item, _) = structure.lookup_child(by_id, OsStr::new("13")).unwrap();
+        for (name, expected) in [
+            ("name", ReadWrite(CommandType::EntityCustomName, String)),
+            ("tags", ReadWrite(CommandType::EntityTags, Lines)),
+            ("glowing", ReadWrite(CommandType::EntityGlowing, Bool)),
+            ("silent", ReadWrite(CommandType::EntitySilent, Bool)),
+            ("no_ai", ReadWrite(CommandType::EntityNoAi, Bool)),
+            ("team", ReadWrite(CommandType::EntityTeam, String)),
+            ("on_ground", ReadOnly(CommandType::EntityOnGround, Bool)),
+        ] {
+            assert_eq!(behaviour(zombie, name), Some(&expected), "{}", name);
+        }
+
+        // only mobs have ai to disable
+        assert!(behaviour(item, "no_ai").is_none());
+        assert!(behaviour(item, "glowing").is_some());
+
+        // the player's own name is kept
+        assert_eq!(
+            behaviour(player, "name"),
+            Some(&ReadOnly(CommandType::PlayerName, String))
+        );
+        assert!(behaviour(player, "tags").is_some());
+        assert!(behaviour(player, "no_ai").is_none());
+    }
 }
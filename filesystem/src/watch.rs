@@ -0,0 +1,463 @@
+use std::collections::{HashMap, HashSet};
+use std::ffi::OsStr;
+use std::sync::{Arc, Weak};
+use std::thread;
+use std::time::Duration;
+
+use fuser::Notifier;
+use log::*;
+use parking_lot::Mutex;
+
+use ipc::generated::CommandType;
+use ipc::{BodyType, CommandState, IpcChannel};
+
+use crate::events::{EventSubscribers, SharedEvents, Wakeup};
+use crate::state::GameStateInterest;
+
+/// How often pushed events are collected
+const EVENT_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// How often the game is asked for the values of watched files, in multiples of
+/// [EVENT_POLL_INTERVAL]
+const POLL_INTERVAL_TICKS: u32 = 5;
+
+/// Files and dirs that are open, and so should be checked for changes
+#[derive(Default)]
+pub struct Watched {
+    /// Keyed by inode
+    files: HashMap<u64, WatchedFile>,
+    /// Keyed by inode
+    dirs: HashMap<u64, WatchedDir>,
+}
+
+struct WatchedFile {
+    cmd: CommandType,
+    body_type: BodyType,
+    state: CommandState,
+    last_value: Option<String>,
+    /// Keyed by file handle
+    handles: HashMap<u64, WatchingHandle>,
+}
+
+struct WatchingHandle {
+    /// Value has changed since it was last read through this handle
+    changed: bool,
+    /// Kernel poll handle to notify on the next change
+    poll_handle: Option<u64>,
+}
+
+struct WatchedDir {
+    interest: GameStateInterest,
+    last_entities: Option<HashSet<i32>>,
+    open_count: usize,
+}
+
+/// Everything a change can cause. There's no inotify event among them, as FUSE can't raise one,
+/// so only `poll()` waiters and the kernel's caches hear about changes
+#[derive(Debug, PartialEq)]
+enum Notification {
+    InvalidateInode(u64),
+    InvalidateEntry { parent: u64, name: String },
+    Poll(u64),
+}
+
+pub type SharedWatched = Arc<Mutex<Watched>>;
+
+impl Watched {
+    pub fn watch_file(
+        &mut self,
+        ino: u64,
+        fh: u64,
+        cmd: CommandType,
+        body_type: BodyType,
+        state: CommandState,
+    ) {
+        let file = self.files.entry(ino).or_insert_with(|| WatchedFile {
+            cmd,
+            body_type,
+            state,
+            last_value: None,
+            handles: HashMap::new(),
+        });
+
+        file.handles.insert(
+            fh,
+            WatchingHandle {
+                // not read yet
+                changed: true,
+                poll_handle: None,
+            },
+        );
+    }
+
+    pub fn unwatch_file(&mut self, ino: u64, fh: u64) {
+        if let Some(file) = self.files.get_mut(&ino) {
+            file.handles.remove(&fh);
+            if file.handles.is_empty() {
+                self.files.remove(&ino);
+            }
+        }
+    }
+
+    pub fn watch_dir(&mut self, ino: u64, interest: GameStateInterest) {
+        self.dirs
+            .entry(ino)
+            .or_insert_with(|| WatchedDir {
+                interest,
+                last_entities: None,
+                open_count: 0,
+            })
+            .open_count += 1;
+    }
+
+    pub fn unwatch_dir(&mut self, ino: u64) {
+        if let Some(dir) = self.dirs.get_mut(&ino) {
+            dir.open_count -= 1;
+            if dir.open_count == 0 {
+                self.dirs.remove(&ino);
+            }
+        }
+    }
+
+    pub fn mark_read(&mut self, ino: u64, fh: u64) {
+        if let Some(handle) = self.handle_mut(ino, fh) {
+            handle.changed = false;
+        }
+    }
+
+    /// Returns true if the value has changed since it was last read through the handle. If not,
+    /// the given poll handle is notified on the next change
+    pub fn poll(&mut self, ino: u64, fh: u64, poll_handle: Option<u64>) -> bool {
+        match self.handle_mut(ino, fh) {
+            Some(handle) if handle.changed => true,
+            Some(handle) => {
+                if poll_handle.is_some() {
+                    handle.poll_handle = poll_handle;
+                }
+                false
+            }
+            // not watched, so is always readable
+            None => true,
+        }
+    }
+
+    fn handle_mut(&mut self, ino: u64, fh: u64) -> Option<&mut WatchingHandle> {
+        self.files
+            .get_mut(&ino)
+            .and_then(|f| f.handles.get_mut(&fh))
+    }
+
+    fn file_updated(&mut self, ino: u64, value: String) -> Vec<Notification> {
+        let mut notifications = vec![];
+        let file = match self.files.get_mut(&ino) {
+            Some(f) => f,
+            None => return notifications,
+        };
+
+        // the first value is just the baseline
+        let changed = matches!(&file.last_value, Some(last) if *last != value);
+        file.last_value = Some(value);
+
+        if changed {
+            notifications.push(Notification::InvalidateInode(ino));
+            for handle in file.handles.values_mut() {
+                handle.changed = true;
+                if let Some(kh) = handle.poll_handle.take() {
+                    notifications.push(Notification::Poll(kh));
+                }
+            }
+        }
+
+        notifications
+    }
+
+    fn dir_updated(&mut self, ino: u64, entities: HashSet<i32>) -> Vec<Notification> {
+        let mut notifications = vec![];
+        let dir = match self.dirs.get_mut(&ino) {
+            Some(d) => d,
+            None => return notifications,
+        };
+
+        if let Some(last) = dir.last_entities.as_ref() {
+            if *last != entities {
+                notifications.push(Notification::InvalidateInode(ino));
+                notifications.extend(last.difference(&entities).map(|id| {
+                    Notification::InvalidateEntry {
+                        parent: ino,
+                        name: id.to_string(),
+                    }
+                }));
+            }
+        }
+
+        dir.last_entities = Some(entities);
+        notifications
+    }
+}
+
+/// Spawns a thread that polls the game for the values of watched files and collects pushed events
+/// for event stream subscribers, until the filesystem is dropped
+pub fn spawn_poller(
+    ipc: Arc<Mutex<IpcChannel>>,
+    watched: &SharedWatched,
+    events: &SharedEvents,
+    notifier: Notifier,
+) {
+    let watched = Arc::downgrade(watched);
+    let events = Arc::downgrade(events);
+    thread::Builder::new()
+        .name("mcfs-poller".to_owned())
+        .spawn(move || poll_loop(ipc, watched, events, notifier))
+        .expect("failed to spawn poller thread");
+}
+
+fn poll_loop(
+    ipc: Arc<Mutex<IpcChannel>>,
+    watched: Weak<Mutex<Watched>>,
+    events: Weak<Mutex<EventSubscribers>>,
+    notifier: Notifier,
+) {
+    for tick in 1u32.. {
+        thread::sleep(EVENT_POLL_INTERVAL);
+        let (watched, events) = match (watched.upgrade(), events.upgrade()) {
+            (Some(w), Some(e)) => (w, e),
+            _ => break,
+        };
+
+        poll_events(&ipc, &events, &notifier);
+
+        if tick % POLL_INTERVAL_TICKS != 0 {
+            continue;
+        }
+
+        // copy out what to query so the lock isn't held while waiting on the game
+        let (files, dirs) = {
+            let guard = watched.lock();
+            let files = guard
+                .files
+                .iter()
+                .map(|(ino, f)| (*ino, f.cmd, f.body_type, f.state.clone()))
+                .collect::<Vec<_>>();
+            let dirs = guard
+                .dirs
+                .iter()
+                .map(|(ino, d)| (*ino, d.interest.clone()))
+                .collect::<Vec<_>>();
+            (files, dirs)
+        };
+
+        let mut notifications = vec![];
+        for (ino, cmd, body_type, state) in files {
+            let value = match ipc.lock().send_read_command(cmd, body_type, state) {
+                Ok(body) => body.to_string(),
+                Err(err) => {
+                    debug!("failed to poll inode {}: {}", ino, err);
+                    continue;
+                }
+            };
+
+            notifications.extend(watched.lock().file_updated(ino, value));
+        }
+
+        for (ino, interest) in dirs {
+            let entities = match ipc
+                .lock()
+                .send_state_request(&interest.as_state_request_args())
+            {
+                Ok(resp) => resp
+                    .entities()
+                    .map(|v| v.iter().map(|e| e.id()).collect())
+                    .unwrap_or_default(),
+                Err(err) => {
+                    debug!("failed to poll dir {}: {}", ino, err);
+                    continue;
+                }
+            };
+
+            notifications.extend(watched.lock().dir_updated(ino, entities));
+        }
+
+        for notification in notifications {
+            trace!("sending notification {:?}", notification);
+            let result = match &notification {
+                Notification::InvalidateInode(ino) => notifier.inval_inode(*ino, 0, 0),
+                Notification::InvalidateEntry { parent, name } => {
+                    notifier.inval_entry(*parent, OsStr::new(name))
+                }
+                Notification::Poll(kh) => notifier.poll(*kh),
+            };
+
+            if let Err(err) = result {
+                debug!("failed to send notification {:?}: {}", notification, err);
+            }
+        }
+    }
+
+    debug!("poller thread exiting");
+}
+
+fn poll_events(ipc: &Mutex<IpcChannel>, events: &Mutex<EventSubscribers>, notifier: &Notifier) {
+    let wanted = events.lock().has_subscribers();
+    let pushed = {
+        let mut ipc = ipc.lock();
+        if wanted != ipc.is_subscribed() {
+            debug!(
+                "{} events",
+                if wanted {
+                    "subscribing to"
+                } else {
+                    "unsubscribing from"
+                }
+            );
+            if let Err(err) = ipc.set_event_subscription(wanted) {
+                debug!("failed to change event subscription: {}", err);
+            }
+        }
+
+        if let Err(err) = ipc.recv_pushed_events() {
+            debug!("failed to receive events: {}", err);
+        }
+
+        ipc.take_events().collect::<Vec<_>>()
+    };
+
+    let wakeups = {
+        let mut events = events.lock();
+        let mut wakeups = if pushed.is_empty() {
+            vec![]
+        } else {
+            trace!("publishing {} events", pushed.len());
+            events.publish(&pushed)
+        };
+
+        // reads that are still blocked
+        wakeups.extend(events.interrupt_parked());
+        wakeups
+    };
+
+    for wakeup in wakeups {
+        match wakeup {
+            Wakeup::Reply(reply, data) => reply.data(&data),
+            Wakeup::Interrupted(reply) => reply.error(libc::EINTR),
+            Wakeup::Poll(kh) => {
+                if let Err(err) = notifier.poll(kh) {
+                    debug!("failed to send poll notification: {}", err);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn watched_file() -> Watched {
+        let mut watched = Watched::default();
+        watched.watch_file(
+            10,
+            1,
+            CommandType::EntityHealth,
+            BodyType::Float,
+            CommandState::default(),
+        );
+        watched
+    }
+
+    #[test]
+    fn file_changes() {
+        let mut watched = watched_file();
+
+        // initially readable
+        assert!(watched.poll(10, 1, Some(100)));
+        watched.mark_read(10, 1);
+        assert!(!watched.poll(10, 1, Some(100)));
+
+        // baseline
+        assert!(watched.file_updated(10, "20.0".to_owned()).is_empty());
+        assert!(watched.file_updated(10, "20.0".to_owned()).is_empty());
+
+        assert_eq!(
+            watched.file_updated(10, "19.0".to_owned()),
+            vec![Notification::InvalidateInode(10), Notification::Poll(100)]
+        );
+        assert!(watched.poll(10, 1, None));
+
+        // poll handle is only notified once
+        watched.mark_read(10, 1);
+        assert_eq!(
+            watched.file_updated(10, "18.0".to_owned()),
+            vec![Notification::InvalidateInode(10)]
+        );
+    }
+
+    #[test]
+    fn only_pollers_are_woken() {
+        let mut watched = watched_file();
+        watched.watch_file(
+            10,
+            2,
+            CommandType::EntityHealth,
+            BodyType::Float,
+            CommandState::default(),
+        );
+        watched.mark_read(10, 1);
+        watched.mark_read(10, 2);
+        assert!(!watched.poll(10, 1, Some(100)));
+
+        assert!(watched.file_updated(10, "20.0".to_owned()).is_empty());
+
+        // handle 2 isn't polling, so it's only marked as changed for its next poll()
+        assert_eq!(
+            watched.file_updated(10, "19.0".to_owned()),
+            vec![Notification::InvalidateInode(10), Notification::Poll(100)]
+        );
+        assert!(watched.poll(10, 2, None));
+    }
+
+    #[test]
+    fn file_unwatched() {
+        let mut watched = watched_file();
+        watched.watch_file(
+            10,
+            2,
+            CommandType::EntityHealth,
+            BodyType::Float,
+            CommandState::default(),
+        );
+
+        watched.unwatch_file(10, 1);
+        assert!(watched.files.contains_key(&10));
+        watched.unwatch_file(10, 2);
+        assert!(!watched.files.contains_key(&10));
+
+        assert!(watched.file_updated(10, "oof".to_owned()).is_empty());
+    }
+
+    #[test]
+    fn dir_changes() {
+        let mut watched = Watched::default();
+        watched.watch_dir(20, GameStateInterest::default());
+        watched.watch_dir(20, GameStateInterest::default());
+
+        assert!(watched.dir_updated(20, [1, 2, 3].into()).is_empty());
+        assert!(watched.dir_updated(20, [3, 2, 1].into()).is_empty());
+
+        let notifications = watched.dir_updated(20, [1, 3, 4].into());
+        assert_eq!(
+            notifications,
+            vec![
+                Notification::InvalidateInode(20),
+                Notification::InvalidateEntry {
+                    parent: 20,
+                    name: "2".to_owned()
+                }
+            ]
+        );
+
+        watched.unwatch_dir(20);
+        assert!(watched.dirs.contains_key(&20));
+        watched.unwatch_dir(20);
+        assert!(!watched.dirs.contains_key(&20));
+    }
+}
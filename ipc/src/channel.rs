@@ -1,3 +1,4 @@
+use std::collections::VecDeque;
 use std::io::{ErrorKind, Read, Write};
 use std::os::unix::net::UnixStream;
 use std::path::{Path, PathBuf};
@@ -8,10 +9,11 @@ use log::warn;
 use thiserror::Error;
 
 use crate::command::{Body, BodyType, CommandState, TargetEntity};
+use crate::event::GameEvent;
 use crate::generated::{
-    BlockPos, CommandArgs, CommandType, Error, GameRequest, GameRequestArgs, GameRequestBody,
-    GameResponse, GameResponseBody, StateRequest, StateRequestArgs, StateResponse, Vec3, WriteBody,
-    WriteBodyArgs,
+    BlockPos, CommandArgs, CommandType, Error, EventSubscription, EventSubscriptionArgs,
+    GameRequest, GameRequestArgs, GameRequestBody, GameResponse, GameResponseBody, Rotation,
+    StateRequest, StateRequestArgs, StateResponse, Vec3, WriteBody, WriteBodyArgs,
 };
 
 const RETRIES: u8 = 2;
@@ -23,6 +25,10 @@ pub struct IpcChannel {
     retries: u8,
     recv_buffer: Vec<u8>,
     ser_buffer: FlatBufferBuilder<'static>,
+    /// Events pushed by the game, received while waiting for a response or in
+    /// [Self::recv_pushed_events]
+    pushed_events: VecDeque<GameEvent>,
+    subscribed: bool,
 }
 
 #[derive(Debug, Error)]
@@ -86,15 +92,71 @@ impl IpcChannel {
             retries: RETRIES,
             recv_buffer: Vec::with_capacity(8192),
             ser_buffer: FlatBufferBuilder::with_capacity(4096),
+            pushed_events: VecDeque::new(),
+            subscribed: false,
         })
     }
 
+    /// Whether the game is pushing events. Reset if the socket has to be reopened
+    pub fn is_subscribed(&self) -> bool {
+        self.subscribed
+    }
+
+    pub fn set_event_subscription(&mut self, subscribe: bool) -> Result<(), IpcError> {
+        self.ser_buffer.reset();
+
+        let req =
+            EventSubscription::create(&mut self.ser_buffer, &EventSubscriptionArgs { subscribe });
+        let req = GameRequest::create(
+            &mut self.ser_buffer,
+            &GameRequestArgs {
+                body_type: GameRequestBody::EventSubscription,
+                body: Some(req.as_union_value()),
+            },
+        );
+        self.ser_buffer.finish(req, None);
+        self.send_raw_request()?;
+
+        let response = self
+            .recv_raw_response()
+            .and_then(|resp| root::<GameResponse>(resp).map_err(IpcError::Deserialization))?;
+
+        match response.body_as_response() {
+            Some(resp) if resp.error().is_none() => {
+                self.subscribed = subscribe;
+                Ok(())
+            }
+            Some(resp) => Err(IpcError::ClientError(
+                resp.error().and_then(|e| e.variant_name()).unwrap_or("?"),
+            )),
+            None => Err(IpcError::UnexpectedGameResponse(response.body_type())),
+        }
+    }
+
+    /// Receives any events that have already been pushed by the game without blocking, to be
+    /// collected with [Self::take_events]
+    pub fn recv_pushed_events(&mut self) -> Result<(), IpcError> {
+        while let Some(first_byte) = self.try_recv_first_byte()? {
+            self.recv_frame(Some(first_byte))?;
+            match root::<GameResponse>(&self.recv_buffer)?.body_as_event() {
+                Some(event) => self.pushed_events.push_back(event.into()),
+                None => warn!("ignoring unexpected non-event message pushed by game"),
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn take_events(&mut self) -> impl Iterator<Item = GameEvent> + '_ {
+        self.pushed_events.drain(..)
+    }
+
     pub fn send_read_command(
         &mut self,
         cmd: CommandType,
         resp: BodyType,
         state: CommandState,
-    ) -> Result<Body, IpcError> {
+    ) -> Result<Body<'_>, IpcError> {
         self.send_raw_command(cmd, Some(resp), None, state)
             .map(|opt| opt.expect("response expected"))
     }
@@ -115,10 +177,28 @@ impl IpcChannel {
         Ok(())
     }
 
+    /// For writes that the game responds to, e.g. with how many blocks were changed
+    pub fn send_write_command_with_result(
+        &mut self,
+        cmd: CommandType,
+        body_type: BodyType,
+        data: &[u8],
+        resp: BodyType,
+        state: CommandState,
+    ) -> Result<Body<'_>, IpcError> {
+        log::trace!("write data {:?}", data);
+        let write = body_type
+            .create_from_data(data)
+            .ok_or(IpcError::BadData(body_type))?;
+
+        self.send_raw_command(cmd, Some(resp), Some(write), state)
+            .map(|opt| opt.expect("response expected"))
+    }
+
     pub fn send_state_request(
         &mut self,
         req: &StateRequestArgs,
-    ) -> Result<StateResponse, IpcError> {
+    ) -> Result<StateResponse<'_>, IpcError> {
         self.ser_buffer.reset();
 
         let req = StateRequest::create(&mut self.ser_buffer, req);
@@ -147,7 +227,7 @@ impl IpcChannel {
         expected_response_type: Option<BodyType>,
         write: Option<Body>,
         state: CommandState,
-    ) -> Result<Option<Body>, IpcError> {
+    ) -> Result<Option<Body<'_>>, IpcError> {
         use crate::generated::Command;
 
         self.ser_buffer.reset();
@@ -158,12 +238,16 @@ impl IpcChannel {
                 let mut string = None;
                 let mut pos = None;
                 let mut block = None;
+                let mut rotation = None;
+                let mut bool = None;
                 match body {
                     Body::Integer(val) => int = Some(val),
                     Body::Float(val) => float = Some(val),
                     Body::String(val) => string = Some(self.ser_buffer.create_string(&val)),
                     Body::Vec { x, y, z } => pos = Some(Vec3::new(x, y, z)),
                     Body::Block { x, y, z } => block = Some(BlockPos::new(x, y, z)),
+                    Body::Rotation { yaw, pitch } => rotation = Some(Rotation::new(yaw, pitch)),
+                    Body::Bool(val) => bool = Some(val),
                 }
                 WriteBody::create(
                     &mut self.ser_buffer,
@@ -173,15 +257,38 @@ impl IpcChannel {
                         string,
                         vec: pos.as_ref(),
                         block: block.as_ref(),
+                        rotation: rotation.as_ref(),
+                        bool,
                     },
                 )
             });
 
-            let (target_entity, target_player_entity) = match state.target_entity {
-                Some(TargetEntity::Entity(id)) => (Some(id), false),
-                Some(TargetEntity::Player) => (None, true),
-                None => (None, false),
-            };
+            let (target_entity, target_player_entity) =
+                TargetEntity::as_request_fields(state.target_entity);
+            let target_property = state
+                .target_property
+                .as_deref()
+                .map(|prop| self.ser_buffer.create_string(prop));
+            let target_gamerule = state
+                .target_gamerule
+                .as_deref()
+                .map(|rule| self.ser_buffer.create_string(rule));
+            let target_effect = state
+                .target_effect
+                .as_deref()
+                .map(|effect| self.ser_buffer.create_string(effect));
+            let target_attribute = state
+                .target_attribute
+                .as_deref()
+                .map(|attr| self.ser_buffer.create_string(attr));
+            let target_objective = state
+                .target_objective
+                .as_deref()
+                .map(|objective| self.ser_buffer.create_string(objective));
+            let target_score_holder = state
+                .target_score_holder
+                .as_deref()
+                .map(|holder| self.ser_buffer.create_string(holder));
 
             Command::create(
                 &mut self.ser_buffer,
@@ -191,6 +298,14 @@ impl IpcChannel {
                     target_player_entity,
                     target_world: state.target_world,
                     target_block: state.target_block.as_ref(),
+                    target_slot: state.target_slot,
+                    target_property,
+                    target_gamerule,
+                    target_chunk: state.target_chunk.as_ref(),
+                    target_effect,
+                    target_attribute,
+                    target_objective,
+                    target_score_holder,
                     write: write_body,
                 },
             )
@@ -223,32 +338,53 @@ impl IpcChannel {
             })
         } else {
             use BodyType::*;
-            return Ok(Some(
+            Ok(Some(
                 match (
                     expected_response_type,
                     response.float(),
                     response.int(),
                     response.string(),
                     response.vec(),
+                    response.rotation(),
+                    response.bool(),
                 ) {
-                    (None, None, None, None, None) => return Ok(None),
-                    (Some(Float), val, None, None, None) => Body::Float(val.unwrap_or(0.0)),
-                    (Some(Integer), None, val, None, None) => Body::Integer(val.unwrap_or(0)),
-                    (Some(String), None, None, Some(val), None) => Body::String(val.into()),
-                    (Some(Position), None, None, None, Some(val)) => Body::Vec {
+                    (None, None, None, None, None, None, None) => return Ok(None),
+                    (Some(Float), val, None, None, None, None, None) => {
+                        Body::Float(val.unwrap_or(0.0))
+                    }
+                    (Some(Integer), None, val, None, None, None, None) => {
+                        Body::Integer(val.unwrap_or(0))
+                    }
+                    (
+                        Some(String | Lines | Keyword(_)),
+                        None,
+                        None,
+                        Some(val),
+                        None,
+                        None,
+                        None,
+                    ) => Body::String(val.into()),
+                    (Some(Position), None, None, None, Some(val), None, None) => Body::Vec {
                         x: val.x(),
                         y: val.y(),
                         z: val.z(),
                     },
-                    (expected, f, i, s, v) => {
+                    (Some(Rotation), None, None, None, None, Some(val), None) => Body::Rotation {
+                        yaw: val.yaw(),
+                        pitch: val.pitch(),
+                    },
+                    (Some(Bool), None, None, None, None, None, val) => {
+                        Body::Bool(val.unwrap_or(false))
+                    }
+                    (expected, f, i, s, v, r, b) => {
                         warn!(
-                            "expected {:?} but instead got this: float={:?},int={:?},str={:?},vec={:?}",
-                            expected, f, i, s, v
+                            "expected {:?} but instead got this: float={:?},int={:?},str={:?},vec={:?},rotation={:?},bool={:?}",
+                            expected, f, i, s, v, r, b
                         );
                         return Err(IpcError::UnexpectedResponse(expected_response_type));
                     }
                 },
-            ));
+            ))
         }
     }
 
@@ -268,10 +404,49 @@ impl IpcChannel {
         self.attempt_write(data)
     }
 
+    /// Skips over any pushed events, which are queued up
     fn recv_raw_response(&mut self) -> Result<&[u8], IpcError> {
+        loop {
+            self.recv_frame(None)?;
+            match root::<GameResponse>(&self.recv_buffer)?.body_as_event() {
+                Some(event) => self.pushed_events.push_back(event.into()),
+                None => return Ok(&self.recv_buffer),
+            }
+        }
+    }
+
+    /// Reads the first byte of a pushed frame without blocking, if there is one. The game writes
+    /// whole frames at once, so the rest can then be read normally
+    fn try_recv_first_byte(&mut self) -> Result<Option<u8>, IpcError> {
+        let mut byte = [0u8];
+        self.sock
+            .set_nonblocking(true)
+            .map_err(IpcError::SettingTimeout)?;
+        let result = self.sock.read(&mut byte);
+        self.sock
+            .set_nonblocking(false)
+            .map_err(IpcError::SettingTimeout)?;
+
+        match result {
+            Ok(0) => Err(IpcError::Receiving(ErrorKind::UnexpectedEof.into())),
+            Ok(_) => Ok(Some(byte[0])),
+            Err(err) if err.kind() == ErrorKind::WouldBlock => Ok(None),
+            Err(err) => Err(IpcError::Receiving(err)),
+        }
+    }
+
+    /// `first_byte` has already been read from the length prefix
+    fn recv_frame(&mut self, first_byte: Option<u8>) -> Result<(), IpcError> {
         let mut len_bytes = [0u8; 4];
+        let len_bytes_remaining = match first_byte {
+            Some(b) => {
+                len_bytes[0] = b;
+                &mut len_bytes[1..]
+            }
+            None => &mut len_bytes[..],
+        };
         self.sock
-            .read_exact(&mut len_bytes)
+            .read_exact(len_bytes_remaining)
             .map_err(IpcError::Receiving)?;
 
         let len = u32::from_le_bytes(len_bytes);
@@ -285,7 +460,7 @@ impl IpcChannel {
         #[cfg(feature = "log_socket")]
         log::trace!("data: {:02X?}", self.recv_buffer);
 
-        Ok(&self.recv_buffer)
+        Ok(())
     }
 
     fn open_socket(path: &Path) -> Result<UnixStream, IpcError> {
@@ -322,6 +497,7 @@ impl IpcChannel {
                     // reboot and try again
                     log::debug!("reopening socket, {} retries remaining", self.retries);
                     self.sock = Self::open_socket(&self.sock_path)?;
+                    self.subscribed = false;
                 }
             }
         }
@@ -330,8 +506,9 @@ impl IpcChannel {
 
 #[cfg(feature = "client")]
 pub mod recv {
-    use std::io::{Read, Write};
+    use std::io::{ErrorKind, Read, Write};
     use std::os::unix::net::{UnixListener, UnixStream};
+    use std::time::Duration;
 
     use flatbuffers::root;
     use log::*;
@@ -347,6 +524,8 @@ pub mod recv {
     pub struct ConnectedIpcClient {
         sock: UnixStream,
         recv_buffer: Vec<u8>,
+        /// Start of the next request, already read by [Self::wait_for_request]
+        first_byte: Option<u8>,
     }
 
     impl IpcClient {
@@ -380,16 +559,57 @@ pub mod recv {
             self.server.accept().map(|(s, _)| ConnectedIpcClient {
                 sock: s,
                 recv_buffer: vec![],
+                first_byte: None,
             })
         }
     }
 
     impl ConnectedIpcClient {
-        pub fn recv(&mut self) -> Result<GameRequest, IpcError> {
+        pub fn recv(&mut self) -> Result<GameRequest<'_>, IpcError> {
             let mut len_bytes = [0u8; 4];
+            let len_bytes_remaining = match self.first_byte.take() {
+                Some(b) => {
+                    len_bytes[0] = b;
+                    &mut len_bytes[1..]
+                }
+                None => &mut len_bytes[..],
+            };
             self.sock
-                .read_exact(&mut len_bytes)
+                .read_exact(len_bytes_remaining)
                 .map_err(IpcError::Receiving)?;
+            self.recv_body(len_bytes)
+        }
+
+        /// Returns false if no request starts arriving within the timeout, so events can be pushed
+        /// in the meantime. Otherwise the request is then read with [Self::recv]
+        pub fn wait_for_request(&mut self, timeout: Duration) -> Result<bool, IpcError> {
+            if self.first_byte.is_some() {
+                return Ok(true);
+            }
+
+            let mut byte = [0u8];
+            self.sock
+                .set_read_timeout(Some(timeout))
+                .map_err(IpcError::SettingTimeout)?;
+            let result = self.sock.read(&mut byte);
+            self.sock
+                .set_read_timeout(None)
+                .map_err(IpcError::SettingTimeout)?;
+
+            match result {
+                Ok(0) => Err(IpcError::Receiving(ErrorKind::UnexpectedEof.into())),
+                Ok(_) => {
+                    self.first_byte = Some(byte[0]);
+                    Ok(true)
+                }
+                Err(err) if matches!(err.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => {
+                    Ok(false)
+                }
+                Err(err) => Err(IpcError::Receiving(err)),
+            }
+        }
+
+        fn recv_body(&mut self, len_bytes: [u8; 4]) -> Result<GameRequest<'_>, IpcError> {
             let len = u32::from_le_bytes(len_bytes) as usize;
             debug!("recv'ing message of {} bytes", len);
 
@@ -1,4 +1,4 @@
-use crate::generated::{BlockPos, CommandType, Dimension};
+use crate::generated::{BlockPos, ColumnPos, CommandType, Dimension};
 use std::borrow::Cow;
 use std::fmt::{Debug, Display, Formatter};
 
@@ -8,6 +8,15 @@ pub enum BodyType {
     String,
     Float,
     Position,
+    /// Yaw and pitch in degrees
+    Rotation,
+    /// `true` or `false`, or `1` or `0` when writing
+    Bool,
+    /// One string per line, e.g. a set of tags. Lines are trimmed and blank ones dropped
+    Lines,
+    /// String starting with one of the given words, optionally followed by a single argument
+    /// for the game to parse, e.g. `rain 300`
+    Keyword(&'static [&'static str]),
 }
 
 pub enum Body<'a> {
@@ -16,18 +25,37 @@ pub enum Body<'a> {
     String(Cow<'a, str>),
     Vec { x: f64, y: f64, z: f64 },
     Block { x: i32, y: i32, z: i32 },
+    Rotation { yaw: f32, pitch: f32 },
+    Bool(bool),
 }
 
+#[derive(Debug, Copy, Clone, PartialEq)]
 pub enum TargetEntity {
     Player,
     Entity(i32),
 }
 
-#[derive(Default)]
+#[derive(Default, Clone)]
 pub struct CommandState {
     pub target_entity: Option<TargetEntity>,
     pub target_world: Option<Dimension>,
     pub target_block: Option<BlockPos>,
+    /// Inventory slot of the target entity, or of the target block if there is no entity
+    pub target_slot: Option<i32>,
+    /// Block state property of the target block
+    pub target_property: Option<String>,
+    /// Game rule name
+    pub target_gamerule: Option<String>,
+    /// Chunk coordinates
+    pub target_chunk: Option<ColumnPos>,
+    /// Status effect of the target entity
+    pub target_effect: Option<String>,
+    /// Attribute of the target entity
+    pub target_attribute: Option<String>,
+    /// Scoreboard objective name
+    pub target_objective: Option<String>,
+    /// Scoreboard entry in the target objective
+    pub target_score_holder: Option<String>,
 }
 
 pub struct Command {
@@ -45,6 +73,19 @@ impl Display for Body<'_> {
             Body::String(val) => Display::fmt(val, f),
             Body::Vec { x, y, z } => write!(f, "{:?} {:?} {:?}", x, y, z),
             Body::Block { x, y, z } => write!(f, "{:?} {:?} {:?}", x, y, z),
+            Body::Rotation { yaw, pitch } => write!(f, "{:?} {:?}", yaw, pitch),
+            Body::Bool(val) => Display::fmt(val, f),
+        }
+    }
+}
+
+impl TargetEntity {
+    /// As `(target_entity, target_player_entity)` fields of a request
+    pub fn as_request_fields(target: Option<Self>) -> (Option<i32>, bool) {
+        match target {
+            Some(TargetEntity::Entity(id)) => (Some(id), false),
+            Some(TargetEntity::Player) => (None, true),
+            None => (None, false),
         }
     }
 }
@@ -70,6 +111,14 @@ impl BodyType {
             BodyType::Float => data.parse().ok().map(Body::Float),
             BodyType::Integer => data.parse().ok().map(Body::Integer),
             BodyType::String => Some(Body::String(data.into())),
+            BodyType::Lines => {
+                let lines = data
+                    .lines()
+                    .map(str::trim)
+                    .filter(|line| !line.is_empty())
+                    .collect::<Vec<_>>();
+                Some(Body::String(lines.join("\n").into()))
+            }
             BodyType::Position => {
                 let xyz = data.splitn(3, &[',', ' ']);
                 let mut iter = xyz.into_iter().map(|s| s.parse());
@@ -82,6 +131,26 @@ impl BodyType {
                     None
                 }
             }
+            BodyType::Rotation => {
+                let mut iter = data.splitn(2, &[',', ' ']).map(|s| s.trim().parse());
+
+                if let (Some(Ok(yaw)), Some(Ok(pitch))) = (iter.next(), iter.next()) {
+                    Some(Body::Rotation { yaw, pitch })
+                } else {
+                    None
+                }
+            }
+            BodyType::Bool => match data {
+                "true" | "1" => Some(Body::Bool(true)),
+                "false" | "0" => Some(Body::Bool(false)),
+                _ => None,
+            },
+            BodyType::Keyword(words) => {
+                let mut parts = data.split_whitespace();
+                let valid = parts.next().is_some_and(|word| words.contains(&word))
+                    && parts.nth(1).is_none();
+                valid.then(|| Body::String(data.trim().into()))
+            }
         }
     }
 }
@@ -104,4 +173,79 @@ mod tests {
             _ => unreachable!(),
         }
     }
+
+    #[test]
+    fn parse_rotation() {
+        for data in [
+            &b"-90.5,45\n"[..],
+            b"-90.5 45",
+            b"-90.5 45.0\n",
+            b"-90.5, 45",
+        ] {
+            let rot = BodyType::Rotation
+                .create_from_data(data)
+                .expect("parse failed");
+            match rot {
+                Body::Rotation { yaw, pitch } => {
+                    assert_eq!(yaw, -90.5);
+                    assert_eq!(pitch, 45.0);
+                }
+                _ => unreachable!(),
+            }
+        }
+
+        assert!(BodyType::Rotation.create_from_data(b"10").is_none());
+        assert!(BodyType::Rotation.create_from_data(b"10,20,30").is_none());
+        assert!(BodyType::Rotation.create_from_data(b"up,down").is_none());
+    }
+
+    #[test]
+    fn parse_bool() {
+        for (data, expected) in [(&b"true\n"[..], true), (b"1", true), (b"false", false)] {
+            match BodyType::Bool.create_from_data(data) {
+                Some(Body::Bool(val)) => assert_eq!(val, expected),
+                _ => panic!("parse failed"),
+            }
+        }
+
+        assert!(BodyType::Bool.create_from_data(b"yes").is_none());
+        assert!(BodyType::Bool.create_from_data(b"True").is_none());
+        assert!(BodyType::Bool.create_from_data(b"").is_none());
+    }
+
+    #[test]
+    fn parse_lines() {
+        for (data, expected) in [
+            (&b"a\nb\n"[..], "a\nb"),
+            (b"  a \r\n\n\tb", "a\nb"),
+            (b"single", "single"),
+            (b"\n", ""),
+            (b"", ""),
+        ] {
+            match BodyType::Lines.create_from_data(data) {
+                Some(Body::String(val)) => assert_eq!(val, expected),
+                _ => panic!("parse failed"),
+            }
+        }
+    }
+
+    #[test]
+    fn parse_keyword() {
+        let ty = BodyType::Keyword(&["clear", "rain", "thunder"]);
+        for (data, expected) in [
+            (&b"rain\n"[..], "rain"),
+            (b"thunder 300", "thunder 300"),
+            (b"  clear   60\n", "clear   60"),
+        ] {
+            match ty.create_from_data(data) {
+                Some(Body::String(val)) => assert_eq!(val, expected),
+                _ => panic!("parse failed"),
+            }
+        }
+
+        assert!(ty.create_from_data(b"snow").is_none());
+        assert!(ty.create_from_data(b"Rain").is_none());
+        assert!(ty.create_from_data(b"rain 300 now").is_none());
+        assert!(ty.create_from_data(b"").is_none());
+    }
 }
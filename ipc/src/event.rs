@@ -0,0 +1,133 @@
+use crate::generated::{BlockPos, Dimension, Event, EventType};
+use std::fmt::{Display, Formatter};
+
+/// Owned copy of an [Event] pushed by the game
+#[derive(Debug, Clone, PartialEq)]
+pub struct GameEvent {
+    pub ty: EventType,
+    pub world: Option<Dimension>,
+    pub entity: Option<i32>,
+    pub block: Option<BlockPos>,
+    pub message: Option<String>,
+    pub amount: Option<f32>,
+}
+
+impl From<Event<'_>> for GameEvent {
+    fn from(event: Event) -> Self {
+        Self {
+            ty: event.ty(),
+            world: event.world(),
+            entity: event.entity(),
+            block: event.block().copied(),
+            message: event.message().map(str::to_owned),
+            amount: event.amount(),
+        }
+    }
+}
+
+/// A single line, e.g. `chat world=overworld entity=135 message=hello there`. The message is
+/// always last, so may contain spaces
+impl Display for GameEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let ty = match self.ty {
+            EventType::Chat => "chat",
+            EventType::Death => "death",
+            EventType::Damage => "damage",
+            EventType::DimensionChange => "dimension_change",
+            EventType::EntitySpawn => "entity_spawn",
+            EventType::EntityDespawn => "entity_despawn",
+            EventType::BlockBreak => "block_break",
+            EventType::BlockPlace => "block_place",
+            _ => "unknown",
+        };
+        f.write_str(ty)?;
+
+        if let Some(world) = self.world {
+            let world = match world {
+                Dimension::Overworld => "overworld",
+                Dimension::Nether => "nether",
+                Dimension::End => "end",
+                _ => "unknown",
+            };
+            write!(f, " world={}", world)?;
+        }
+
+        if let Some(entity) = self.entity {
+            write!(f, " entity={}", entity)?;
+        }
+
+        if let Some(block) = self.block {
+            write!(f, " block={},{},{}", block.x(), block.y(), block.z())?;
+        }
+
+        if let Some(amount) = self.amount {
+            write!(f, " amount={:?}", amount)?;
+        }
+
+        if let Some(message) = self.message.as_deref() {
+            // keep it to a single line
+            f.write_str(" message=")?;
+            for c in message.chars() {
+                let c = if c == '\n' || c == '\r' { ' ' } else { c };
+                write!(f, "{}", c)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(ty: EventType) -> GameEvent {
+        GameEvent {
+            ty,
+            world: None,
+            entity: None,
+            block: None,
+            message: None,
+            amount: None,
+        }
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(event(EventType::Death).to_string(), "death");
+
+        let chat = GameEvent {
+            world: Some(Dimension::Overworld),
+            entity: Some(135),
+            message: Some("hello\nthere".to_owned()),
+            ..event(EventType::Chat)
+        };
+        assert_eq!(
+            chat.to_string(),
+            "chat world=overworld entity=135 message=hello there"
+        );
+
+        let damage = GameEvent {
+            world: Some(Dimension::Nether),
+            entity: Some(4),
+            amount: Some(2.0),
+            message: Some("lava".to_owned()),
+            ..event(EventType::Damage)
+        };
+        assert_eq!(
+            damage.to_string(),
+            "damage world=nether entity=4 amount=2.0 message=lava"
+        );
+
+        let place = GameEvent {
+            world: Some(Dimension::End),
+            block: Some(BlockPos::new(1, -60, 300)),
+            message: Some("minecraft:dirt".to_owned()),
+            ..event(EventType::BlockPlace)
+        };
+        assert_eq!(
+            place.to_string(),
+            "block_place world=end block=1,-60,300 message=minecraft:dirt"
+        );
+    }
+}
@@ -118,13 +118,13 @@ pub mod mcfs {
         since = "2.0.0",
         note = "Use associated constants instead. This will no longer be generated in 2021."
     )]
-    pub const ENUM_MAX_COMMAND_TYPE: i32 = 14;
+    pub const ENUM_MAX_COMMAND_TYPE: i32 = 54;
     #[deprecated(
         since = "2.0.0",
         note = "Use associated constants instead. This will no longer be generated in 2021."
     )]
     #[allow(non_camel_case_types)]
-    pub const ENUM_VALUES_COMMAND_TYPE: [CommandType; 15] = [
+    pub const ENUM_VALUES_COMMAND_TYPE: [CommandType; 55] = [
         CommandType::PlayerName,
         CommandType::PlayerGamemode,
         CommandType::PlayerHunger,
@@ -134,12 +134,52 @@ pub mod mcfs {
         CommandType::EntityPosition,
         CommandType::EntityHealth,
         CommandType::EntityTarget,
+        CommandType::EntityRotation,
+        CommandType::EntityVelocity,
+        CommandType::EntityOnGround,
+        CommandType::EntityEyePosition,
+        CommandType::EntityVehicle,
+        CommandType::EntityEffectAmplifier,
+        CommandType::EntityEffectDuration,
+        CommandType::EntityEffectAmbient,
+        CommandType::EntityEffectParticles,
+        CommandType::EntityEffectRemove,
+        CommandType::EntityAttributeBase,
+        CommandType::EntityAttributeValue,
+        CommandType::EntityNbt,
+        CommandType::EntityCustomName,
+        CommandType::EntityTags,
+        CommandType::EntityGlowing,
+        CommandType::EntitySilent,
+        CommandType::EntityNoAi,
+        CommandType::EntityTeam,
+        CommandType::InventorySlotItem,
+        CommandType::InventorySlotCount,
+        CommandType::InventorySlotNbt,
         CommandType::WorldTime,
+        CommandType::WorldWeather,
+        CommandType::WorldFill,
+        CommandType::WorldPaste,
         CommandType::BlockType,
+        CommandType::BlockProperty,
+        CommandType::BlockNbt,
+        CommandType::ChunkLoaded,
+        CommandType::ChunkBiomes,
+        CommandType::ChunkInhabitedTime,
+        CommandType::ChunkForceLoad,
         CommandType::ControlSay,
         CommandType::ControlJump,
         CommandType::ControlMove,
         CommandType::ServerCommand,
+        CommandType::ServerGameRule,
+        CommandType::ServerDifficulty,
+        CommandType::ServerPvp,
+        CommandType::ScoreboardObjectiveAdd,
+        CommandType::ScoreboardObjectiveCriteria,
+        CommandType::ScoreboardObjectiveDisplayName,
+        CommandType::ScoreboardObjectiveDisplaySlot,
+        CommandType::ScoreboardObjectiveRemove,
+        CommandType::ScoreboardScore,
     ];
 
     #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -156,15 +196,55 @@ pub mod mcfs {
         pub const EntityPosition: Self = Self(6);
         pub const EntityHealth: Self = Self(7);
         pub const EntityTarget: Self = Self(8);
-        pub const WorldTime: Self = Self(9);
-        pub const BlockType: Self = Self(10);
-        pub const ControlSay: Self = Self(11);
-        pub const ControlJump: Self = Self(12);
-        pub const ControlMove: Self = Self(13);
-        pub const ServerCommand: Self = Self(14);
+        pub const EntityRotation: Self = Self(9);
+        pub const EntityVelocity: Self = Self(10);
+        pub const EntityOnGround: Self = Self(11);
+        pub const EntityEyePosition: Self = Self(12);
+        pub const EntityVehicle: Self = Self(13);
+        pub const EntityEffectAmplifier: Self = Self(14);
+        pub const EntityEffectDuration: Self = Self(15);
+        pub const EntityEffectAmbient: Self = Self(16);
+        pub const EntityEffectParticles: Self = Self(17);
+        pub const EntityEffectRemove: Self = Self(18);
+        pub const EntityAttributeBase: Self = Self(19);
+        pub const EntityAttributeValue: Self = Self(20);
+        pub const EntityNbt: Self = Self(21);
+        pub const EntityCustomName: Self = Self(22);
+        pub const EntityTags: Self = Self(23);
+        pub const EntityGlowing: Self = Self(24);
+        pub const EntitySilent: Self = Self(25);
+        pub const EntityNoAi: Self = Self(26);
+        pub const EntityTeam: Self = Self(27);
+        pub const InventorySlotItem: Self = Self(28);
+        pub const InventorySlotCount: Self = Self(29);
+        pub const InventorySlotNbt: Self = Self(30);
+        pub const WorldTime: Self = Self(31);
+        pub const WorldWeather: Self = Self(32);
+        pub const WorldFill: Self = Self(33);
+        pub const WorldPaste: Self = Self(34);
+        pub const BlockType: Self = Self(35);
+        pub const BlockProperty: Self = Self(36);
+        pub const BlockNbt: Self = Self(37);
+        pub const ChunkLoaded: Self = Self(38);
+        pub const ChunkBiomes: Self = Self(39);
+        pub const ChunkInhabitedTime: Self = Self(40);
+        pub const ChunkForceLoad: Self = Self(41);
+        pub const ControlSay: Self = Self(42);
+        pub const ControlJump: Self = Self(43);
+        pub const ControlMove: Self = Self(44);
+        pub const ServerCommand: Self = Self(45);
+        pub const ServerGameRule: Self = Self(46);
+        pub const ServerDifficulty: Self = Self(47);
+        pub const ServerPvp: Self = Self(48);
+        pub const ScoreboardObjectiveAdd: Self = Self(49);
+        pub const ScoreboardObjectiveCriteria: Self = Self(50);
+        pub const ScoreboardObjectiveDisplayName: Self = Self(51);
+        pub const ScoreboardObjectiveDisplaySlot: Self = Self(52);
+        pub const ScoreboardObjectiveRemove: Self = Self(53);
+        pub const ScoreboardScore: Self = Self(54);
 
         pub const ENUM_MIN: i32 = 0;
-        pub const ENUM_MAX: i32 = 14;
+        pub const ENUM_MAX: i32 = 54;
         pub const ENUM_VALUES: &'static [Self] = &[
             Self::PlayerName,
             Self::PlayerGamemode,
@@ -175,12 +255,52 @@ pub mod mcfs {
             Self::EntityPosition,
             Self::EntityHealth,
             Self::EntityTarget,
+            Self::EntityRotation,
+            Self::EntityVelocity,
+            Self::EntityOnGround,
+            Self::EntityEyePosition,
+            Self::EntityVehicle,
+            Self::EntityEffectAmplifier,
+            Self::EntityEffectDuration,
+            Self::EntityEffectAmbient,
+            Self::EntityEffectParticles,
+            Self::EntityEffectRemove,
+            Self::EntityAttributeBase,
+            Self::EntityAttributeValue,
+            Self::EntityNbt,
+            Self::EntityCustomName,
+            Self::EntityTags,
+            Self::EntityGlowing,
+            Self::EntitySilent,
+            Self::EntityNoAi,
+            Self::EntityTeam,
+            Self::InventorySlotItem,
+            Self::InventorySlotCount,
+            Self::InventorySlotNbt,
             Self::WorldTime,
+            Self::WorldWeather,
+            Self::WorldFill,
+            Self::WorldPaste,
             Self::BlockType,
+            Self::BlockProperty,
+            Self::BlockNbt,
+            Self::ChunkLoaded,
+            Self::ChunkBiomes,
+            Self::ChunkInhabitedTime,
+            Self::ChunkForceLoad,
             Self::ControlSay,
             Self::ControlJump,
             Self::ControlMove,
             Self::ServerCommand,
+            Self::ServerGameRule,
+            Self::ServerDifficulty,
+            Self::ServerPvp,
+            Self::ScoreboardObjectiveAdd,
+            Self::ScoreboardObjectiveCriteria,
+            Self::ScoreboardObjectiveDisplayName,
+            Self::ScoreboardObjectiveDisplaySlot,
+            Self::ScoreboardObjectiveRemove,
+            Self::ScoreboardScore,
         ];
         /// Returns the variant's name or "" if unknown.
         pub fn variant_name(self) -> Option<&'static str> {
@@ -194,12 +314,52 @@ pub mod mcfs {
                 Self::EntityPosition => Some("EntityPosition"),
                 Self::EntityHealth => Some("EntityHealth"),
                 Self::EntityTarget => Some("EntityTarget"),
+                Self::EntityRotation => Some("EntityRotation"),
+                Self::EntityVelocity => Some("EntityVelocity"),
+                Self::EntityOnGround => Some("EntityOnGround"),
+                Self::EntityEyePosition => Some("EntityEyePosition"),
+                Self::EntityVehicle => Some("EntityVehicle"),
+                Self::EntityEffectAmplifier => Some("EntityEffectAmplifier"),
+                Self::EntityEffectDuration => Some("EntityEffectDuration"),
+                Self::EntityEffectAmbient => Some("EntityEffectAmbient"),
+                Self::EntityEffectParticles => Some("EntityEffectParticles"),
+                Self::EntityEffectRemove => Some("EntityEffectRemove"),
+                Self::EntityAttributeBase => Some("EntityAttributeBase"),
+                Self::EntityAttributeValue => Some("EntityAttributeValue"),
+                Self::EntityNbt => Some("EntityNbt"),
+                Self::EntityCustomName => Some("EntityCustomName"),
+                Self::EntityTags => Some("EntityTags"),
+                Self::EntityGlowing => Some("EntityGlowing"),
+                Self::EntitySilent => Some("EntitySilent"),
+                Self::EntityNoAi => Some("EntityNoAi"),
+                Self::EntityTeam => Some("EntityTeam"),
+                Self::InventorySlotItem => Some("InventorySlotItem"),
+                Self::InventorySlotCount => Some("InventorySlotCount"),
+                Self::InventorySlotNbt => Some("InventorySlotNbt"),
                 Self::WorldTime => Some("WorldTime"),
+                Self::WorldWeather => Some("WorldWeather"),
+                Self::WorldFill => Some("WorldFill"),
+                Self::WorldPaste => Some("WorldPaste"),
                 Self::BlockType => Some("BlockType"),
+                Self::BlockProperty => Some("BlockProperty"),
+                Self::BlockNbt => Some("BlockNbt"),
+                Self::ChunkLoaded => Some("ChunkLoaded"),
+                Self::ChunkBiomes => Some("ChunkBiomes"),
+                Self::ChunkInhabitedTime => Some("ChunkInhabitedTime"),
+                Self::ChunkForceLoad => Some("ChunkForceLoad"),
                 Self::ControlSay => Some("ControlSay"),
                 Self::ControlJump => Some("ControlJump"),
                 Self::ControlMove => Some("ControlMove"),
                 Self::ServerCommand => Some("ServerCommand"),
+                Self::ServerGameRule => Some("ServerGameRule"),
+                Self::ServerDifficulty => Some("ServerDifficulty"),
+                Self::ServerPvp => Some("ServerPvp"),
+                Self::ScoreboardObjectiveAdd => Some("ScoreboardObjectiveAdd"),
+                Self::ScoreboardObjectiveCriteria => Some("ScoreboardObjectiveCriteria"),
+                Self::ScoreboardObjectiveDisplayName => Some("ScoreboardObjectiveDisplayName"),
+                Self::ScoreboardObjectiveDisplaySlot => Some("ScoreboardObjectiveDisplaySlot"),
+                Self::ScoreboardObjectiveRemove => Some("ScoreboardObjectiveRemove"),
+                Self::ScoreboardScore => Some("ScoreboardScore"),
                 _ => None,
             }
         }
@@ -267,16 +427,17 @@ pub mod mcfs {
         since = "2.0.0",
         note = "Use associated constants instead. This will no longer be generated in 2021."
     )]
-    pub const ENUM_MAX_GAME_REQUEST_BODY: u8 = 2;
+    pub const ENUM_MAX_GAME_REQUEST_BODY: u8 = 3;
     #[deprecated(
         since = "2.0.0",
         note = "Use associated constants instead. This will no longer be generated in 2021."
     )]
     #[allow(non_camel_case_types)]
-    pub const ENUM_VALUES_GAME_REQUEST_BODY: [GameRequestBody; 3] = [
+    pub const ENUM_VALUES_GAME_REQUEST_BODY: [GameRequestBody; 4] = [
         GameRequestBody::NONE,
         GameRequestBody::Command,
         GameRequestBody::StateRequest,
+        GameRequestBody::EventSubscription,
     ];
 
     #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -287,16 +448,23 @@ pub mod mcfs {
         pub const NONE: Self = Self(0);
         pub const Command: Self = Self(1);
         pub const StateRequest: Self = Self(2);
+        pub const EventSubscription: Self = Self(3);
 
         pub const ENUM_MIN: u8 = 0;
-        pub const ENUM_MAX: u8 = 2;
-        pub const ENUM_VALUES: &'static [Self] = &[Self::NONE, Self::Command, Self::StateRequest];
+        pub const ENUM_MAX: u8 = 3;
+        pub const ENUM_VALUES: &'static [Self] = &[
+            Self::NONE,
+            Self::Command,
+            Self::StateRequest,
+            Self::EventSubscription,
+        ];
         /// Returns the variant's name or "" if unknown.
         pub fn variant_name(self) -> Option<&'static str> {
             match self {
                 Self::NONE => Some("NONE"),
                 Self::Command => Some("Command"),
                 Self::StateRequest => Some("StateRequest"),
+                Self::EventSubscription => Some("EventSubscription"),
                 _ => None,
             }
         }
@@ -366,13 +534,13 @@ pub mod mcfs {
         since = "2.0.0",
         note = "Use associated constants instead. This will no longer be generated in 2021."
     )]
-    pub const ENUM_MAX_ERROR: i32 = 6;
+    pub const ENUM_MAX_ERROR: i32 = 7;
     #[deprecated(
         since = "2.0.0",
         note = "Use associated constants instead. This will no longer be generated in 2021."
     )]
     #[allow(non_camel_case_types)]
-    pub const ENUM_VALUES_ERROR: [Error; 7] = [
+    pub const ENUM_VALUES_ERROR: [Error; 8] = [
         Error::Unknown,
         Error::UnknownCommand,
         Error::NoGame,
@@ -380,6 +548,7 @@ pub mod mcfs {
         Error::NoSuchEntity,
         Error::NoSuchBlock,
         Error::BadInput,
+        Error::NoSuchChunk,
     ];
 
     #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -394,9 +563,10 @@ pub mod mcfs {
         pub const NoSuchEntity: Self = Self(4);
         pub const NoSuchBlock: Self = Self(5);
         pub const BadInput: Self = Self(6);
+        pub const NoSuchChunk: Self = Self(7);
 
         pub const ENUM_MIN: i32 = 0;
-        pub const ENUM_MAX: i32 = 6;
+        pub const ENUM_MAX: i32 = 7;
         pub const ENUM_VALUES: &'static [Self] = &[
             Self::Unknown,
             Self::UnknownCommand,
@@ -405,6 +575,7 @@ pub mod mcfs {
             Self::NoSuchEntity,
             Self::NoSuchBlock,
             Self::BadInput,
+            Self::NoSuchChunk,
         ];
         /// Returns the variant's name or "" if unknown.
         pub fn variant_name(self) -> Option<&'static str> {
@@ -416,6 +587,7 @@ pub mod mcfs {
                 Self::NoSuchEntity => Some("NoSuchEntity"),
                 Self::NoSuchBlock => Some("NoSuchBlock"),
                 Self::BadInput => Some("BadInput"),
+                Self::NoSuchChunk => Some("NoSuchChunk"),
                 _ => None,
             }
         }
@@ -478,21 +650,235 @@ pub mod mcfs {
         since = "2.0.0",
         note = "Use associated constants instead. This will no longer be generated in 2021."
     )]
+    pub const ENUM_MIN_EVENT_TYPE: i32 = 0;
+    #[deprecated(
+        since = "2.0.0",
+        note = "Use associated constants instead. This will no longer be generated in 2021."
+    )]
+    pub const ENUM_MAX_EVENT_TYPE: i32 = 7;
+    #[deprecated(
+        since = "2.0.0",
+        note = "Use associated constants instead. This will no longer be generated in 2021."
+    )]
+    #[allow(non_camel_case_types)]
+    pub const ENUM_VALUES_EVENT_TYPE: [EventType; 8] = [
+        EventType::Chat,
+        EventType::Death,
+        EventType::Damage,
+        EventType::DimensionChange,
+        EventType::EntitySpawn,
+        EventType::EntityDespawn,
+        EventType::BlockBreak,
+        EventType::BlockPlace,
+    ];
+
+    #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+    #[repr(transparent)]
+    pub struct EventType(pub i32);
+    #[allow(non_upper_case_globals)]
+    impl EventType {
+        pub const Chat: Self = Self(0);
+        pub const Death: Self = Self(1);
+        pub const Damage: Self = Self(2);
+        pub const DimensionChange: Self = Self(3);
+        pub const EntitySpawn: Self = Self(4);
+        pub const EntityDespawn: Self = Self(5);
+        pub const BlockBreak: Self = Self(6);
+        pub const BlockPlace: Self = Self(7);
+
+        pub const ENUM_MIN: i32 = 0;
+        pub const ENUM_MAX: i32 = 7;
+        pub const ENUM_VALUES: &'static [Self] = &[
+            Self::Chat,
+            Self::Death,
+            Self::Damage,
+            Self::DimensionChange,
+            Self::EntitySpawn,
+            Self::EntityDespawn,
+            Self::BlockBreak,
+            Self::BlockPlace,
+        ];
+        /// Returns the variant's name or "" if unknown.
+        pub fn variant_name(self) -> Option<&'static str> {
+            match self {
+                Self::Chat => Some("Chat"),
+                Self::Death => Some("Death"),
+                Self::Damage => Some("Damage"),
+                Self::DimensionChange => Some("DimensionChange"),
+                Self::EntitySpawn => Some("EntitySpawn"),
+                Self::EntityDespawn => Some("EntityDespawn"),
+                Self::BlockBreak => Some("BlockBreak"),
+                Self::BlockPlace => Some("BlockPlace"),
+                _ => None,
+            }
+        }
+    }
+    impl std::fmt::Debug for EventType {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            if let Some(name) = self.variant_name() {
+                f.write_str(name)
+            } else {
+                f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+            }
+        }
+    }
+    impl<'a> flatbuffers::Follow<'a> for EventType {
+        type Inner = Self;
+        #[inline]
+        fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            let b = unsafe { flatbuffers::read_scalar_at::<i32>(buf, loc) };
+            Self(b)
+        }
+    }
+
+    impl flatbuffers::Push for EventType {
+        type Output = EventType;
+        #[inline]
+        fn push(&self, dst: &mut [u8], _rest: &[u8]) {
+            unsafe {
+                flatbuffers::emplace_scalar::<i32>(dst, self.0);
+            }
+        }
+    }
+
+    impl flatbuffers::EndianScalar for EventType {
+        #[inline]
+        fn to_little_endian(self) -> Self {
+            let b = i32::to_le(self.0);
+            Self(b)
+        }
+        #[inline]
+        #[allow(clippy::wrong_self_convention)]
+        fn from_little_endian(self) -> Self {
+            let b = i32::from_le(self.0);
+            Self(b)
+        }
+    }
+
+    impl<'a> flatbuffers::Verifiable for EventType {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            i32::run_verifier(v, pos)
+        }
+    }
+
+    impl flatbuffers::SimpleToVerifyInSlice for EventType {}
+    #[deprecated(
+        since = "2.0.0",
+        note = "Use associated constants instead. This will no longer be generated in 2021."
+    )]
+    pub const ENUM_MIN_GAME_RULE_TYPE: u8 = 0;
+    #[deprecated(
+        since = "2.0.0",
+        note = "Use associated constants instead. This will no longer be generated in 2021."
+    )]
+    pub const ENUM_MAX_GAME_RULE_TYPE: u8 = 1;
+    #[deprecated(
+        since = "2.0.0",
+        note = "Use associated constants instead. This will no longer be generated in 2021."
+    )]
+    #[allow(non_camel_case_types)]
+    pub const ENUM_VALUES_GAME_RULE_TYPE: [GameRuleType; 2] =
+        [GameRuleType::Bool, GameRuleType::Int];
+
+    #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+    #[repr(transparent)]
+    pub struct GameRuleType(pub u8);
+    #[allow(non_upper_case_globals)]
+    impl GameRuleType {
+        pub const Bool: Self = Self(0);
+        pub const Int: Self = Self(1);
+
+        pub const ENUM_MIN: u8 = 0;
+        pub const ENUM_MAX: u8 = 1;
+        pub const ENUM_VALUES: &'static [Self] = &[Self::Bool, Self::Int];
+        /// Returns the variant's name or "" if unknown.
+        pub fn variant_name(self) -> Option<&'static str> {
+            match self {
+                Self::Bool => Some("Bool"),
+                Self::Int => Some("Int"),
+                _ => None,
+            }
+        }
+    }
+    impl std::fmt::Debug for GameRuleType {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            if let Some(name) = self.variant_name() {
+                f.write_str(name)
+            } else {
+                f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+            }
+        }
+    }
+    impl<'a> flatbuffers::Follow<'a> for GameRuleType {
+        type Inner = Self;
+        #[inline]
+        fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            let b = unsafe { flatbuffers::read_scalar_at::<u8>(buf, loc) };
+            Self(b)
+        }
+    }
+
+    impl flatbuffers::Push for GameRuleType {
+        type Output = GameRuleType;
+        #[inline]
+        fn push(&self, dst: &mut [u8], _rest: &[u8]) {
+            unsafe {
+                flatbuffers::emplace_scalar::<u8>(dst, self.0);
+            }
+        }
+    }
+
+    impl flatbuffers::EndianScalar for GameRuleType {
+        #[inline]
+        fn to_little_endian(self) -> Self {
+            let b = u8::to_le(self.0);
+            Self(b)
+        }
+        #[inline]
+        #[allow(clippy::wrong_self_convention)]
+        fn from_little_endian(self) -> Self {
+            let b = u8::from_le(self.0);
+            Self(b)
+        }
+    }
+
+    impl<'a> flatbuffers::Verifiable for GameRuleType {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            u8::run_verifier(v, pos)
+        }
+    }
+
+    impl flatbuffers::SimpleToVerifyInSlice for GameRuleType {}
+    #[deprecated(
+        since = "2.0.0",
+        note = "Use associated constants instead. This will no longer be generated in 2021."
+    )]
     pub const ENUM_MIN_GAME_RESPONSE_BODY: u8 = 0;
     #[deprecated(
         since = "2.0.0",
         note = "Use associated constants instead. This will no longer be generated in 2021."
     )]
-    pub const ENUM_MAX_GAME_RESPONSE_BODY: u8 = 2;
+    pub const ENUM_MAX_GAME_RESPONSE_BODY: u8 = 3;
     #[deprecated(
         since = "2.0.0",
         note = "Use associated constants instead. This will no longer be generated in 2021."
     )]
     #[allow(non_camel_case_types)]
-    pub const ENUM_VALUES_GAME_RESPONSE_BODY: [GameResponseBody; 3] = [
+    pub const ENUM_VALUES_GAME_RESPONSE_BODY: [GameResponseBody; 4] = [
         GameResponseBody::NONE,
         GameResponseBody::Response,
         GameResponseBody::StateResponse,
+        GameResponseBody::Event,
     ];
 
     #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -503,16 +889,19 @@ pub mod mcfs {
         pub const NONE: Self = Self(0);
         pub const Response: Self = Self(1);
         pub const StateResponse: Self = Self(2);
+        pub const Event: Self = Self(3);
 
         pub const ENUM_MIN: u8 = 0;
-        pub const ENUM_MAX: u8 = 2;
-        pub const ENUM_VALUES: &'static [Self] = &[Self::NONE, Self::Response, Self::StateResponse];
+        pub const ENUM_MAX: u8 = 3;
+        pub const ENUM_VALUES: &'static [Self] =
+            &[Self::NONE, Self::Response, Self::StateResponse, Self::Event];
         /// Returns the variant's name or "" if unknown.
         pub fn variant_name(self) -> Option<&'static str> {
             match self {
                 Self::NONE => Some("NONE"),
                 Self::Response => Some("Response"),
                 Self::StateResponse => Some("StateResponse"),
+                Self::Event => Some("Event"),
                 _ => None,
             }
         }
@@ -724,6 +1113,131 @@ pub mod mcfs {
         }
     }
 
+    // struct Rotation, aligned to 4
+    #[repr(transparent)]
+    #[derive(Clone, Copy, PartialEq)]
+    pub struct Rotation(pub [u8; 8]);
+    impl Default for Rotation {
+        fn default() -> Self {
+            Self([0; 8])
+        }
+    }
+    impl std::fmt::Debug for Rotation {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.debug_struct("Rotation")
+                .field("yaw", &self.yaw())
+                .field("pitch", &self.pitch())
+                .finish()
+        }
+    }
+
+    impl flatbuffers::SimpleToVerifyInSlice for Rotation {}
+    impl flatbuffers::SafeSliceAccess for Rotation {}
+    impl<'a> flatbuffers::Follow<'a> for Rotation {
+        type Inner = &'a Rotation;
+        #[inline]
+        fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            <&'a Rotation>::follow(buf, loc)
+        }
+    }
+    impl<'a> flatbuffers::Follow<'a> for &'a Rotation {
+        type Inner = &'a Rotation;
+        #[inline]
+        fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            flatbuffers::follow_cast_ref::<Rotation>(buf, loc)
+        }
+    }
+    impl<'b> flatbuffers::Push for Rotation {
+        type Output = Rotation;
+        #[inline]
+        fn push(&self, dst: &mut [u8], _rest: &[u8]) {
+            let src = unsafe {
+                ::std::slice::from_raw_parts(self as *const Rotation as *const u8, Self::size())
+            };
+            dst.copy_from_slice(src);
+        }
+    }
+    impl<'b> flatbuffers::Push for &'b Rotation {
+        type Output = Rotation;
+
+        #[inline]
+        fn push(&self, dst: &mut [u8], _rest: &[u8]) {
+            let src = unsafe {
+                ::std::slice::from_raw_parts(*self as *const Rotation as *const u8, Self::size())
+            };
+            dst.copy_from_slice(src);
+        }
+    }
+
+    impl<'a> flatbuffers::Verifiable for Rotation {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.in_buffer::<Self>(pos)
+        }
+    }
+
+    impl<'a> Rotation {
+        #[allow(clippy::too_many_arguments)]
+        pub fn new(yaw: f32, pitch: f32) -> Self {
+            let mut s = Self([0; 8]);
+            s.set_yaw(yaw);
+            s.set_pitch(pitch);
+            s
+        }
+
+        pub fn yaw(&self) -> f32 {
+            let mut mem = core::mem::MaybeUninit::<f32>::uninit();
+            unsafe {
+                core::ptr::copy_nonoverlapping(
+                    self.0[0..].as_ptr(),
+                    mem.as_mut_ptr() as *mut u8,
+                    core::mem::size_of::<f32>(),
+                );
+                mem.assume_init()
+            }
+            .from_little_endian()
+        }
+
+        pub fn set_yaw(&mut self, x: f32) {
+            let x_le = x.to_little_endian();
+            unsafe {
+                core::ptr::copy_nonoverlapping(
+                    &x_le as *const f32 as *const u8,
+                    self.0[0..].as_mut_ptr(),
+                    core::mem::size_of::<f32>(),
+                );
+            }
+        }
+
+        pub fn pitch(&self) -> f32 {
+            let mut mem = core::mem::MaybeUninit::<f32>::uninit();
+            unsafe {
+                core::ptr::copy_nonoverlapping(
+                    self.0[4..].as_ptr(),
+                    mem.as_mut_ptr() as *mut u8,
+                    core::mem::size_of::<f32>(),
+                );
+                mem.assume_init()
+            }
+            .from_little_endian()
+        }
+
+        pub fn set_pitch(&mut self, x: f32) {
+            let x_le = x.to_little_endian();
+            unsafe {
+                core::ptr::copy_nonoverlapping(
+                    &x_le as *const f32 as *const u8,
+                    self.0[4..].as_mut_ptr(),
+                    core::mem::size_of::<f32>(),
+                );
+            }
+        }
+    }
+
     // struct BlockPos, aligned to 4
     #[repr(transparent)]
     #[derive(Clone, Copy, PartialEq)]
@@ -875,69 +1389,63 @@ pub mod mcfs {
         }
     }
 
-    // struct EntityDetails, aligned to 4
+    // struct ColumnPos, aligned to 4
     #[repr(transparent)]
     #[derive(Clone, Copy, PartialEq)]
-    pub struct EntityDetails(pub [u8; 8]);
-    impl Default for EntityDetails {
+    pub struct ColumnPos(pub [u8; 8]);
+    impl Default for ColumnPos {
         fn default() -> Self {
             Self([0; 8])
         }
     }
-    impl std::fmt::Debug for EntityDetails {
+    impl std::fmt::Debug for ColumnPos {
         fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-            f.debug_struct("EntityDetails")
-                .field("id", &self.id())
-                .field("living", &self.living())
+            f.debug_struct("ColumnPos")
+                .field("x", &self.x())
+                .field("z", &self.z())
                 .finish()
         }
     }
 
-    impl flatbuffers::SimpleToVerifyInSlice for EntityDetails {}
-    impl flatbuffers::SafeSliceAccess for EntityDetails {}
-    impl<'a> flatbuffers::Follow<'a> for EntityDetails {
-        type Inner = &'a EntityDetails;
+    impl flatbuffers::SimpleToVerifyInSlice for ColumnPos {}
+    impl flatbuffers::SafeSliceAccess for ColumnPos {}
+    impl<'a> flatbuffers::Follow<'a> for ColumnPos {
+        type Inner = &'a ColumnPos;
         #[inline]
         fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-            <&'a EntityDetails>::follow(buf, loc)
+            <&'a ColumnPos>::follow(buf, loc)
         }
     }
-    impl<'a> flatbuffers::Follow<'a> for &'a EntityDetails {
-        type Inner = &'a EntityDetails;
+    impl<'a> flatbuffers::Follow<'a> for &'a ColumnPos {
+        type Inner = &'a ColumnPos;
         #[inline]
         fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-            flatbuffers::follow_cast_ref::<EntityDetails>(buf, loc)
+            flatbuffers::follow_cast_ref::<ColumnPos>(buf, loc)
         }
     }
-    impl<'b> flatbuffers::Push for EntityDetails {
-        type Output = EntityDetails;
+    impl<'b> flatbuffers::Push for ColumnPos {
+        type Output = ColumnPos;
         #[inline]
         fn push(&self, dst: &mut [u8], _rest: &[u8]) {
             let src = unsafe {
-                ::std::slice::from_raw_parts(
-                    self as *const EntityDetails as *const u8,
-                    Self::size(),
-                )
+                ::std::slice::from_raw_parts(self as *const ColumnPos as *const u8, Self::size())
             };
             dst.copy_from_slice(src);
         }
     }
-    impl<'b> flatbuffers::Push for &'b EntityDetails {
-        type Output = EntityDetails;
+    impl<'b> flatbuffers::Push for &'b ColumnPos {
+        type Output = ColumnPos;
 
         #[inline]
         fn push(&self, dst: &mut [u8], _rest: &[u8]) {
             let src = unsafe {
-                ::std::slice::from_raw_parts(
-                    *self as *const EntityDetails as *const u8,
-                    Self::size(),
-                )
+                ::std::slice::from_raw_parts(*self as *const ColumnPos as *const u8, Self::size())
             };
             dst.copy_from_slice(src);
         }
     }
 
-    impl<'a> flatbuffers::Verifiable for EntityDetails {
+    impl<'a> flatbuffers::Verifiable for ColumnPos {
         #[inline]
         fn run_verifier(
             v: &mut flatbuffers::Verifier,
@@ -948,16 +1456,16 @@ pub mod mcfs {
         }
     }
 
-    impl<'a> EntityDetails {
+    impl<'a> ColumnPos {
         #[allow(clippy::too_many_arguments)]
-        pub fn new(id: i32, living: bool) -> Self {
+        pub fn new(x: i32, z: i32) -> Self {
             let mut s = Self([0; 8]);
-            s.set_id(id);
-            s.set_living(living);
+            s.set_x(x);
+            s.set_z(z);
             s
         }
 
-        pub fn id(&self) -> i32 {
+        pub fn x(&self) -> i32 {
             let mut mem = core::mem::MaybeUninit::<i32>::uninit();
             unsafe {
                 core::ptr::copy_nonoverlapping(
@@ -970,7 +1478,7 @@ pub mod mcfs {
             .from_little_endian()
         }
 
-        pub fn set_id(&mut self, x: i32) {
+        pub fn set_x(&mut self, x: i32) {
             let x_le = x.to_little_endian();
             unsafe {
                 core::ptr::copy_nonoverlapping(
@@ -981,26 +1489,26 @@ pub mod mcfs {
             }
         }
 
-        pub fn living(&self) -> bool {
-            let mut mem = core::mem::MaybeUninit::<bool>::uninit();
+        pub fn z(&self) -> i32 {
+            let mut mem = core::mem::MaybeUninit::<i32>::uninit();
             unsafe {
                 core::ptr::copy_nonoverlapping(
                     self.0[4..].as_ptr(),
                     mem.as_mut_ptr() as *mut u8,
-                    core::mem::size_of::<bool>(),
+                    core::mem::size_of::<i32>(),
                 );
                 mem.assume_init()
             }
             .from_little_endian()
         }
 
-        pub fn set_living(&mut self, x: bool) {
+        pub fn set_z(&mut self, x: i32) {
             let x_le = x.to_little_endian();
             unsafe {
                 core::ptr::copy_nonoverlapping(
-                    &x_le as *const bool as *const u8,
+                    &x_le as *const i32 as *const u8,
                     self.0[4..].as_mut_ptr(),
-                    core::mem::size_of::<bool>(),
+                    core::mem::size_of::<i32>(),
                 );
             }
         }
@@ -1029,6 +1537,8 @@ pub mod mcfs {
         pub const VT_STRING: flatbuffers::VOffsetT = 8;
         pub const VT_VEC: flatbuffers::VOffsetT = 10;
         pub const VT_BLOCK: flatbuffers::VOffsetT = 12;
+        pub const VT_ROTATION: flatbuffers::VOffsetT = 14;
+        pub const VT_BOOL: flatbuffers::VOffsetT = 16;
 
         #[inline]
         pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -1040,6 +1550,9 @@ pub mod mcfs {
             args: &'args WriteBodyArgs<'args>,
         ) -> flatbuffers::WIPOffset<WriteBody<'bldr>> {
             let mut builder = WriteBodyBuilder::new(_fbb);
+            if let Some(x) = args.rotation {
+                builder.add_rotation(x);
+            }
             if let Some(x) = args.block {
                 builder.add_block(x);
             }
@@ -1055,6 +1568,9 @@ pub mod mcfs {
             if let Some(x) = args.float {
                 builder.add_float(x);
             }
+            if let Some(x) = args.bool {
+                builder.add_bool(x);
+            }
             builder.finish()
         }
 
@@ -1079,6 +1595,14 @@ pub mod mcfs {
         pub fn block(&self) -> Option<&'a BlockPos> {
             self._tab.get::<BlockPos>(WriteBody::VT_BLOCK, None)
         }
+        #[inline]
+        pub fn rotation(&self) -> Option<&'a Rotation> {
+            self._tab.get::<Rotation>(WriteBody::VT_ROTATION, None)
+        }
+        #[inline]
+        pub fn bool(&self) -> Option<bool> {
+            self._tab.get::<bool>(WriteBody::VT_BOOL, None)
+        }
     }
 
     impl flatbuffers::Verifiable for WriteBody<'_> {
@@ -1098,6 +1622,8 @@ pub mod mcfs {
                 )?
                 .visit_field::<Vec3>("vec", Self::VT_VEC, false)?
                 .visit_field::<BlockPos>("block", Self::VT_BLOCK, false)?
+                .visit_field::<Rotation>("rotation", Self::VT_ROTATION, false)?
+                .visit_field::<bool>("bool", Self::VT_BOOL, false)?
                 .finish();
             Ok(())
         }
@@ -1108,6 +1634,8 @@ pub mod mcfs {
         pub string: Option<flatbuffers::WIPOffset<&'a str>>,
         pub vec: Option<&'a Vec3>,
         pub block: Option<&'a BlockPos>,
+        pub rotation: Option<&'a Rotation>,
+        pub bool: Option<bool>,
     }
     impl<'a> Default for WriteBodyArgs<'a> {
         #[inline]
@@ -1118,6 +1646,8 @@ pub mod mcfs {
                 string: None,
                 vec: None,
                 block: None,
+                rotation: None,
+                bool: None,
             }
         }
     }
@@ -1151,6 +1681,15 @@ pub mod mcfs {
                 .push_slot_always::<&BlockPos>(WriteBody::VT_BLOCK, block);
         }
         #[inline]
+        pub fn add_rotation(&mut self, rotation: &Rotation) {
+            self.fbb_
+                .push_slot_always::<&Rotation>(WriteBody::VT_ROTATION, rotation);
+        }
+        #[inline]
+        pub fn add_bool(&mut self, bool: bool) {
+            self.fbb_.push_slot_always::<bool>(WriteBody::VT_BOOL, bool);
+        }
+        #[inline]
         pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> WriteBodyBuilder<'a, 'b> {
             let start = _fbb.start_table();
             WriteBodyBuilder {
@@ -1173,6 +1712,8 @@ pub mod mcfs {
             ds.field("string", &self.string());
             ds.field("vec", &self.vec());
             ds.field("block", &self.block());
+            ds.field("rotation", &self.rotation());
+            ds.field("bool", &self.bool());
             ds.finish()
         }
     }
@@ -1199,7 +1740,15 @@ pub mod mcfs {
         pub const VT_TARGET_PLAYER_ENTITY: flatbuffers::VOffsetT = 8;
         pub const VT_TARGET_WORLD: flatbuffers::VOffsetT = 10;
         pub const VT_TARGET_BLOCK: flatbuffers::VOffsetT = 12;
-        pub const VT_WRITE: flatbuffers::VOffsetT = 14;
+        pub const VT_TARGET_SLOT: flatbuffers::VOffsetT = 14;
+        pub const VT_TARGET_PROPERTY: flatbuffers::VOffsetT = 16;
+        pub const VT_TARGET_GAMERULE: flatbuffers::VOffsetT = 18;
+        pub const VT_TARGET_CHUNK: flatbuffers::VOffsetT = 20;
+        pub const VT_TARGET_EFFECT: flatbuffers::VOffsetT = 22;
+        pub const VT_TARGET_ATTRIBUTE: flatbuffers::VOffsetT = 24;
+        pub const VT_TARGET_OBJECTIVE: flatbuffers::VOffsetT = 26;
+        pub const VT_TARGET_SCORE_HOLDER: flatbuffers::VOffsetT = 28;
+        pub const VT_WRITE: flatbuffers::VOffsetT = 30;
 
         #[inline]
         pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -1214,6 +1763,30 @@ pub mod mcfs {
             if let Some(x) = args.write {
                 builder.add_write(x);
             }
+            if let Some(x) = args.target_score_holder {
+                builder.add_target_score_holder(x);
+            }
+            if let Some(x) = args.target_objective {
+                builder.add_target_objective(x);
+            }
+            if let Some(x) = args.target_attribute {
+                builder.add_target_attribute(x);
+            }
+            if let Some(x) = args.target_effect {
+                builder.add_target_effect(x);
+            }
+            if let Some(x) = args.target_chunk {
+                builder.add_target_chunk(x);
+            }
+            if let Some(x) = args.target_gamerule {
+                builder.add_target_gamerule(x);
+            }
+            if let Some(x) = args.target_property {
+                builder.add_target_property(x);
+            }
+            if let Some(x) = args.target_slot {
+                builder.add_target_slot(x);
+            }
             if let Some(x) = args.target_block {
                 builder.add_target_block(x);
             }
@@ -1253,6 +1826,44 @@ pub mod mcfs {
             self._tab.get::<BlockPos>(Command::VT_TARGET_BLOCK, None)
         }
         #[inline]
+        pub fn target_slot(&self) -> Option<i32> {
+            self._tab.get::<i32>(Command::VT_TARGET_SLOT, None)
+        }
+        #[inline]
+        pub fn target_property(&self) -> Option<&'a str> {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<&str>>(Command::VT_TARGET_PROPERTY, None)
+        }
+        #[inline]
+        pub fn target_gamerule(&self) -> Option<&'a str> {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<&str>>(Command::VT_TARGET_GAMERULE, None)
+        }
+        #[inline]
+        pub fn target_chunk(&self) -> Option<&'a ColumnPos> {
+            self._tab.get::<ColumnPos>(Command::VT_TARGET_CHUNK, None)
+        }
+        #[inline]
+        pub fn target_effect(&self) -> Option<&'a str> {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<&str>>(Command::VT_TARGET_EFFECT, None)
+        }
+        #[inline]
+        pub fn target_attribute(&self) -> Option<&'a str> {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<&str>>(Command::VT_TARGET_ATTRIBUTE, None)
+        }
+        #[inline]
+        pub fn target_objective(&self) -> Option<&'a str> {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<&str>>(Command::VT_TARGET_OBJECTIVE, None)
+        }
+        #[inline]
+        pub fn target_score_holder(&self) -> Option<&'a str> {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<&str>>(Command::VT_TARGET_SCORE_HOLDER, None)
+        }
+        #[inline]
         pub fn write(&self) -> Option<WriteBody<'a>> {
             self._tab
                 .get::<flatbuffers::ForwardsUOffset<WriteBody>>(Command::VT_WRITE, None)
@@ -1272,6 +1883,38 @@ pub mod mcfs {
                 .visit_field::<bool>("target_player_entity", Self::VT_TARGET_PLAYER_ENTITY, false)?
                 .visit_field::<Dimension>("target_world", Self::VT_TARGET_WORLD, false)?
                 .visit_field::<BlockPos>("target_block", Self::VT_TARGET_BLOCK, false)?
+                .visit_field::<i32>("target_slot", Self::VT_TARGET_SLOT, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "target_property",
+                    Self::VT_TARGET_PROPERTY,
+                    false,
+                )?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "target_gamerule",
+                    Self::VT_TARGET_GAMERULE,
+                    false,
+                )?
+                .visit_field::<ColumnPos>("target_chunk", Self::VT_TARGET_CHUNK, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "target_effect",
+                    Self::VT_TARGET_EFFECT,
+                    false,
+                )?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "target_attribute",
+                    Self::VT_TARGET_ATTRIBUTE,
+                    false,
+                )?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "target_objective",
+                    Self::VT_TARGET_OBJECTIVE,
+                    false,
+                )?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "target_score_holder",
+                    Self::VT_TARGET_SCORE_HOLDER,
+                    false,
+                )?
                 .visit_field::<flatbuffers::ForwardsUOffset<WriteBody>>(
                     "write",
                     Self::VT_WRITE,
@@ -1287,6 +1930,14 @@ pub mod mcfs {
         pub target_player_entity: bool,
         pub target_world: Option<Dimension>,
         pub target_block: Option<&'a BlockPos>,
+        pub target_slot: Option<i32>,
+        pub target_property: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub target_gamerule: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub target_chunk: Option<&'a ColumnPos>,
+        pub target_effect: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub target_attribute: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub target_objective: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub target_score_holder: Option<flatbuffers::WIPOffset<&'a str>>,
         pub write: Option<flatbuffers::WIPOffset<WriteBody<'a>>>,
     }
     impl<'a> Default for CommandArgs<'a> {
@@ -1298,6 +1949,14 @@ pub mod mcfs {
                 target_player_entity: false,
                 target_world: None,
                 target_block: None,
+                target_slot: None,
+                target_property: None,
+                target_gamerule: None,
+                target_chunk: None,
+                target_effect: None,
+                target_attribute: None,
+                target_objective: None,
+                target_score_holder: None,
                 write: None,
             }
         }
@@ -1337,6 +1996,61 @@ pub mod mcfs {
                 .push_slot_always::<&BlockPos>(Command::VT_TARGET_BLOCK, target_block);
         }
         #[inline]
+        pub fn add_target_slot(&mut self, target_slot: i32) {
+            self.fbb_
+                .push_slot_always::<i32>(Command::VT_TARGET_SLOT, target_slot);
+        }
+        #[inline]
+        pub fn add_target_property(&mut self, target_property: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                Command::VT_TARGET_PROPERTY,
+                target_property,
+            );
+        }
+        #[inline]
+        pub fn add_target_gamerule(&mut self, target_gamerule: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                Command::VT_TARGET_GAMERULE,
+                target_gamerule,
+            );
+        }
+        #[inline]
+        pub fn add_target_chunk(&mut self, target_chunk: &ColumnPos) {
+            self.fbb_
+                .push_slot_always::<&ColumnPos>(Command::VT_TARGET_CHUNK, target_chunk);
+        }
+        #[inline]
+        pub fn add_target_effect(&mut self, target_effect: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                Command::VT_TARGET_EFFECT,
+                target_effect,
+            );
+        }
+        #[inline]
+        pub fn add_target_attribute(&mut self, target_attribute: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                Command::VT_TARGET_ATTRIBUTE,
+                target_attribute,
+            );
+        }
+        #[inline]
+        pub fn add_target_objective(&mut self, target_objective: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                Command::VT_TARGET_OBJECTIVE,
+                target_objective,
+            );
+        }
+        #[inline]
+        pub fn add_target_score_holder(
+            &mut self,
+            target_score_holder: flatbuffers::WIPOffset<&'b str>,
+        ) {
+            self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                Command::VT_TARGET_SCORE_HOLDER,
+                target_score_holder,
+            );
+        }
+        #[inline]
         pub fn add_write(&mut self, write: flatbuffers::WIPOffset<WriteBody<'b>>) {
             self.fbb_
                 .push_slot_always::<flatbuffers::WIPOffset<WriteBody>>(Command::VT_WRITE, write);
@@ -1364,6 +2078,14 @@ pub mod mcfs {
             ds.field("target_player_entity", &self.target_player_entity());
             ds.field("target_world", &self.target_world());
             ds.field("target_block", &self.target_block());
+            ds.field("target_slot", &self.target_slot());
+            ds.field("target_property", &self.target_property());
+            ds.field("target_gamerule", &self.target_gamerule());
+            ds.field("target_chunk", &self.target_chunk());
+            ds.field("target_effect", &self.target_effect());
+            ds.field("target_attribute", &self.target_attribute());
+            ds.field("target_objective", &self.target_objective());
+            ds.field("target_score_holder", &self.target_score_holder());
             ds.field("write", &self.write());
             ds.finish()
         }
@@ -1387,8 +2109,22 @@ pub mod mcfs {
 
     impl<'a> StateRequest<'a> {
         pub const VT_ENTITIES_BY_ID: flatbuffers::VOffsetT = 4;
-        pub const VT_TARGET_WORLD: flatbuffers::VOffsetT = 6;
-        pub const VT_TARGET_BLOCK: flatbuffers::VOffsetT = 8;
+        pub const VT_ENTITIES_NEAR: flatbuffers::VOffsetT = 6;
+        pub const VT_ENTITIES_RADIUS: flatbuffers::VOffsetT = 8;
+        pub const VT_TARGET_WORLD: flatbuffers::VOffsetT = 10;
+        pub const VT_TARGET_BLOCK: flatbuffers::VOffsetT = 12;
+        pub const VT_TARGET_ENTITY: flatbuffers::VOffsetT = 14;
+        pub const VT_TARGET_PLAYER_ENTITY: flatbuffers::VOffsetT = 16;
+        pub const VT_INVENTORY: flatbuffers::VOffsetT = 18;
+        pub const VT_EFFECTS: flatbuffers::VOffsetT = 20;
+        pub const VT_ATTRIBUTES: flatbuffers::VOffsetT = 22;
+        pub const VT_GAMERULES: flatbuffers::VOffsetT = 24;
+        pub const VT_REGION_FROM: flatbuffers::VOffsetT = 26;
+        pub const VT_REGION_TO: flatbuffers::VOffsetT = 28;
+        pub const VT_COLUMNS_FROM: flatbuffers::VOffsetT = 30;
+        pub const VT_COLUMNS_TO: flatbuffers::VOffsetT = 32;
+        pub const VT_CHUNKS: flatbuffers::VOffsetT = 34;
+        pub const VT_OBJECTIVES: flatbuffers::VOffsetT = 36;
 
         #[inline]
         pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -1400,9 +2136,37 @@ pub mod mcfs {
             args: &'args StateRequestArgs<'args>,
         ) -> flatbuffers::WIPOffset<StateRequest<'bldr>> {
             let mut builder = StateRequestBuilder::new(_fbb);
+            if let Some(x) = args.columns_to {
+                builder.add_columns_to(x);
+            }
+            if let Some(x) = args.columns_from {
+                builder.add_columns_from(x);
+            }
+            if let Some(x) = args.region_to {
+                builder.add_region_to(x);
+            }
+            if let Some(x) = args.region_from {
+                builder.add_region_from(x);
+            }
+            if let Some(x) = args.target_entity {
+                builder.add_target_entity(x);
+            }
             if let Some(x) = args.target_block {
                 builder.add_target_block(x);
             }
+            if let Some(x) = args.entities_radius {
+                builder.add_entities_radius(x);
+            }
+            if let Some(x) = args.entities_near {
+                builder.add_entities_near(x);
+            }
+            builder.add_objectives(args.objectives);
+            builder.add_chunks(args.chunks);
+            builder.add_gamerules(args.gamerules);
+            builder.add_attributes(args.attributes);
+            builder.add_effects(args.effects);
+            builder.add_inventory(args.inventory);
+            builder.add_target_player_entity(args.target_player_entity);
             if let Some(x) = args.target_world {
                 builder.add_target_world(x);
             }
@@ -1417,6 +2181,14 @@ pub mod mcfs {
                 .unwrap()
         }
         #[inline]
+        pub fn entities_near(&self) -> Option<&'a Vec3> {
+            self._tab.get::<Vec3>(StateRequest::VT_ENTITIES_NEAR, None)
+        }
+        #[inline]
+        pub fn entities_radius(&self) -> Option<f32> {
+            self._tab.get::<f32>(StateRequest::VT_ENTITIES_RADIUS, None)
+        }
+        #[inline]
         pub fn target_world(&self) -> Option<Dimension> {
             self._tab
                 .get::<Dimension>(StateRequest::VT_TARGET_WORLD, None)
@@ -1426,6 +2198,71 @@ pub mod mcfs {
             self._tab
                 .get::<BlockPos>(StateRequest::VT_TARGET_BLOCK, None)
         }
+        #[inline]
+        pub fn target_entity(&self) -> Option<i32> {
+            self._tab.get::<i32>(StateRequest::VT_TARGET_ENTITY, None)
+        }
+        #[inline]
+        pub fn target_player_entity(&self) -> bool {
+            self._tab
+                .get::<bool>(StateRequest::VT_TARGET_PLAYER_ENTITY, Some(false))
+                .unwrap()
+        }
+        #[inline]
+        pub fn inventory(&self) -> bool {
+            self._tab
+                .get::<bool>(StateRequest::VT_INVENTORY, Some(false))
+                .unwrap()
+        }
+        #[inline]
+        pub fn effects(&self) -> bool {
+            self._tab
+                .get::<bool>(StateRequest::VT_EFFECTS, Some(false))
+                .unwrap()
+        }
+        #[inline]
+        pub fn attributes(&self) -> bool {
+            self._tab
+                .get::<bool>(StateRequest::VT_ATTRIBUTES, Some(false))
+                .unwrap()
+        }
+        #[inline]
+        pub fn gamerules(&self) -> bool {
+            self._tab
+                .get::<bool>(StateRequest::VT_GAMERULES, Some(false))
+                .unwrap()
+        }
+        #[inline]
+        pub fn region_from(&self) -> Option<&'a BlockPos> {
+            self._tab
+                .get::<BlockPos>(StateRequest::VT_REGION_FROM, None)
+        }
+        #[inline]
+        pub fn region_to(&self) -> Option<&'a BlockPos> {
+            self._tab.get::<BlockPos>(StateRequest::VT_REGION_TO, None)
+        }
+        #[inline]
+        pub fn columns_from(&self) -> Option<&'a ColumnPos> {
+            self._tab
+                .get::<ColumnPos>(StateRequest::VT_COLUMNS_FROM, None)
+        }
+        #[inline]
+        pub fn columns_to(&self) -> Option<&'a ColumnPos> {
+            self._tab
+                .get::<ColumnPos>(StateRequest::VT_COLUMNS_TO, None)
+        }
+        #[inline]
+        pub fn chunks(&self) -> bool {
+            self._tab
+                .get::<bool>(StateRequest::VT_CHUNKS, Some(false))
+                .unwrap()
+        }
+        #[inline]
+        pub fn objectives(&self) -> bool {
+            self._tab
+                .get::<bool>(StateRequest::VT_OBJECTIVES, Some(false))
+                .unwrap()
+        }
     }
 
     impl flatbuffers::Verifiable for StateRequest<'_> {
@@ -1437,24 +2274,66 @@ pub mod mcfs {
             use self::flatbuffers::Verifiable;
             v.visit_table(pos)?
                 .visit_field::<bool>("entities_by_id", Self::VT_ENTITIES_BY_ID, false)?
+                .visit_field::<Vec3>("entities_near", Self::VT_ENTITIES_NEAR, false)?
+                .visit_field::<f32>("entities_radius", Self::VT_ENTITIES_RADIUS, false)?
                 .visit_field::<Dimension>("target_world", Self::VT_TARGET_WORLD, false)?
                 .visit_field::<BlockPos>("target_block", Self::VT_TARGET_BLOCK, false)?
+                .visit_field::<i32>("target_entity", Self::VT_TARGET_ENTITY, false)?
+                .visit_field::<bool>("target_player_entity", Self::VT_TARGET_PLAYER_ENTITY, false)?
+                .visit_field::<bool>("inventory", Self::VT_INVENTORY, false)?
+                .visit_field::<bool>("effects", Self::VT_EFFECTS, false)?
+                .visit_field::<bool>("attributes", Self::VT_ATTRIBUTES, false)?
+                .visit_field::<bool>("gamerules", Self::VT_GAMERULES, false)?
+                .visit_field::<BlockPos>("region_from", Self::VT_REGION_FROM, false)?
+                .visit_field::<BlockPos>("region_to", Self::VT_REGION_TO, false)?
+                .visit_field::<ColumnPos>("columns_from", Self::VT_COLUMNS_FROM, false)?
+                .visit_field::<ColumnPos>("columns_to", Self::VT_COLUMNS_TO, false)?
+                .visit_field::<bool>("chunks", Self::VT_CHUNKS, false)?
+                .visit_field::<bool>("objectives", Self::VT_OBJECTIVES, false)?
                 .finish();
             Ok(())
         }
     }
     pub struct StateRequestArgs<'a> {
         pub entities_by_id: bool,
+        pub entities_near: Option<&'a Vec3>,
+        pub entities_radius: Option<f32>,
         pub target_world: Option<Dimension>,
         pub target_block: Option<&'a BlockPos>,
+        pub target_entity: Option<i32>,
+        pub target_player_entity: bool,
+        pub inventory: bool,
+        pub effects: bool,
+        pub attributes: bool,
+        pub gamerules: bool,
+        pub region_from: Option<&'a BlockPos>,
+        pub region_to: Option<&'a BlockPos>,
+        pub columns_from: Option<&'a ColumnPos>,
+        pub columns_to: Option<&'a ColumnPos>,
+        pub chunks: bool,
+        pub objectives: bool,
     }
     impl<'a> Default for StateRequestArgs<'a> {
         #[inline]
         fn default() -> Self {
             StateRequestArgs {
                 entities_by_id: false,
+                entities_near: None,
+                entities_radius: None,
                 target_world: None,
                 target_block: None,
+                target_entity: None,
+                target_player_entity: false,
+                inventory: false,
+                effects: false,
+                attributes: false,
+                gamerules: false,
+                region_from: None,
+                region_to: None,
+                columns_from: None,
+                columns_to: None,
+                chunks: false,
+                objectives: false,
             }
         }
     }
@@ -1470,6 +2349,16 @@ pub mod mcfs {
                 .push_slot::<bool>(StateRequest::VT_ENTITIES_BY_ID, entities_by_id, false);
         }
         #[inline]
+        pub fn add_entities_near(&mut self, entities_near: &Vec3) {
+            self.fbb_
+                .push_slot_always::<&Vec3>(StateRequest::VT_ENTITIES_NEAR, entities_near);
+        }
+        #[inline]
+        pub fn add_entities_radius(&mut self, entities_radius: f32) {
+            self.fbb_
+                .push_slot_always::<f32>(StateRequest::VT_ENTITIES_RADIUS, entities_radius);
+        }
+        #[inline]
         pub fn add_target_world(&mut self, target_world: Dimension) {
             self.fbb_
                 .push_slot_always::<Dimension>(StateRequest::VT_TARGET_WORLD, target_world);
@@ -1480,6 +2369,69 @@ pub mod mcfs {
                 .push_slot_always::<&BlockPos>(StateRequest::VT_TARGET_BLOCK, target_block);
         }
         #[inline]
+        pub fn add_target_entity(&mut self, target_entity: i32) {
+            self.fbb_
+                .push_slot_always::<i32>(StateRequest::VT_TARGET_ENTITY, target_entity);
+        }
+        #[inline]
+        pub fn add_target_player_entity(&mut self, target_player_entity: bool) {
+            self.fbb_.push_slot::<bool>(
+                StateRequest::VT_TARGET_PLAYER_ENTITY,
+                target_player_entity,
+                false,
+            );
+        }
+        #[inline]
+        pub fn add_inventory(&mut self, inventory: bool) {
+            self.fbb_
+                .push_slot::<bool>(StateRequest::VT_INVENTORY, inventory, false);
+        }
+        #[inline]
+        pub fn add_effects(&mut self, effects: bool) {
+            self.fbb_
+                .push_slot::<bool>(StateRequest::VT_EFFECTS, effects, false);
+        }
+        #[inline]
+        pub fn add_attributes(&mut self, attributes: bool) {
+            self.fbb_
+                .push_slot::<bool>(StateRequest::VT_ATTRIBUTES, attributes, false);
+        }
+        #[inline]
+        pub fn add_gamerules(&mut self, gamerules: bool) {
+            self.fbb_
+                .push_slot::<bool>(StateRequest::VT_GAMERULES, gamerules, false);
+        }
+        #[inline]
+        pub fn add_region_from(&mut self, region_from: &BlockPos) {
+            self.fbb_
+                .push_slot_always::<&BlockPos>(StateRequest::VT_REGION_FROM, region_from);
+        }
+        #[inline]
+        pub fn add_region_to(&mut self, region_to: &BlockPos) {
+            self.fbb_
+                .push_slot_always::<&BlockPos>(StateRequest::VT_REGION_TO, region_to);
+        }
+        #[inline]
+        pub fn add_columns_from(&mut self, columns_from: &ColumnPos) {
+            self.fbb_
+                .push_slot_always::<&ColumnPos>(StateRequest::VT_COLUMNS_FROM, columns_from);
+        }
+        #[inline]
+        pub fn add_columns_to(&mut self, columns_to: &ColumnPos) {
+            self.fbb_
+                .push_slot_always::<&ColumnPos>(StateRequest::VT_COLUMNS_TO, columns_to);
+        }
+        #[inline]
+        pub fn add_chunks(&mut self, chunks: bool) {
+            self.fbb_
+                .push_slot::<bool>(StateRequest::VT_CHUNKS, chunks, false);
+        }
+        #[inline]
+        pub fn add_objectives(&mut self, objectives: bool) {
+            self.fbb_
+                .push_slot::<bool>(StateRequest::VT_OBJECTIVES, objectives, false);
+        }
+        #[inline]
         pub fn new(
             _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
         ) -> StateRequestBuilder<'a, 'b> {
@@ -1500,20 +2452,34 @@ pub mod mcfs {
         fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
             let mut ds = f.debug_struct("StateRequest");
             ds.field("entities_by_id", &self.entities_by_id());
+            ds.field("entities_near", &self.entities_near());
+            ds.field("entities_radius", &self.entities_radius());
             ds.field("target_world", &self.target_world());
             ds.field("target_block", &self.target_block());
+            ds.field("target_entity", &self.target_entity());
+            ds.field("target_player_entity", &self.target_player_entity());
+            ds.field("inventory", &self.inventory());
+            ds.field("effects", &self.effects());
+            ds.field("attributes", &self.attributes());
+            ds.field("gamerules", &self.gamerules());
+            ds.field("region_from", &self.region_from());
+            ds.field("region_to", &self.region_to());
+            ds.field("columns_from", &self.columns_from());
+            ds.field("columns_to", &self.columns_to());
+            ds.field("chunks", &self.chunks());
+            ds.field("objectives", &self.objectives());
             ds.finish()
         }
     }
-    pub enum GameRequestOffset {}
+    pub enum EventSubscriptionOffset {}
     #[derive(Copy, Clone, PartialEq)]
 
-    pub struct GameRequest<'a> {
+    pub struct EventSubscription<'a> {
         pub _tab: flatbuffers::Table<'a>,
     }
 
-    impl<'a> flatbuffers::Follow<'a> for GameRequest<'a> {
-        type Inner = GameRequest<'a>;
+    impl<'a> flatbuffers::Follow<'a> for EventSubscription<'a> {
+        type Inner = EventSubscription<'a>;
         #[inline]
         fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
             Self {
@@ -1522,66 +2488,32 @@ pub mod mcfs {
         }
     }
 
-    impl<'a> GameRequest<'a> {
-        pub const VT_BODY_TYPE: flatbuffers::VOffsetT = 4;
-        pub const VT_BODY: flatbuffers::VOffsetT = 6;
+    impl<'a> EventSubscription<'a> {
+        pub const VT_SUBSCRIBE: flatbuffers::VOffsetT = 4;
 
         #[inline]
         pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-            GameRequest { _tab: table }
+            EventSubscription { _tab: table }
         }
         #[allow(unused_mut)]
         pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
             _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-            args: &'args GameRequestArgs,
-        ) -> flatbuffers::WIPOffset<GameRequest<'bldr>> {
-            let mut builder = GameRequestBuilder::new(_fbb);
-            if let Some(x) = args.body {
-                builder.add_body(x);
-            }
-            builder.add_body_type(args.body_type);
+            args: &'args EventSubscriptionArgs,
+        ) -> flatbuffers::WIPOffset<EventSubscription<'bldr>> {
+            let mut builder = EventSubscriptionBuilder::new(_fbb);
+            builder.add_subscribe(args.subscribe);
             builder.finish()
         }
 
         #[inline]
-        pub fn body_type(&self) -> GameRequestBody {
-            self._tab
-                .get::<GameRequestBody>(GameRequest::VT_BODY_TYPE, Some(GameRequestBody::NONE))
-                .unwrap()
-        }
-        #[inline]
-        pub fn body(&self) -> flatbuffers::Table<'a> {
+        pub fn subscribe(&self) -> bool {
             self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(
-                    GameRequest::VT_BODY,
-                    None,
-                )
+                .get::<bool>(EventSubscription::VT_SUBSCRIBE, Some(true))
                 .unwrap()
         }
-        #[inline]
-        #[allow(non_snake_case)]
-        pub fn body_as_command(&self) -> Option<Command<'a>> {
-            if self.body_type() == GameRequestBody::Command {
-                let u = self.body();
-                Some(Command::init_from_table(u))
-            } else {
-                None
-            }
-        }
-
-        #[inline]
-        #[allow(non_snake_case)]
-        pub fn body_as_state_request(&self) -> Option<StateRequest<'a>> {
-            if self.body_type() == GameRequestBody::StateRequest {
-                let u = self.body();
-                Some(StateRequest::init_from_table(u))
-            } else {
-                None
-            }
-        }
     }
 
-    impl flatbuffers::Verifiable for GameRequest<'_> {
+    impl flatbuffers::Verifiable for EventSubscription<'_> {
         #[inline]
         fn run_verifier(
             v: &mut flatbuffers::Verifier,
@@ -1589,30 +2521,162 @@ pub mod mcfs {
         ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
             use self::flatbuffers::Verifiable;
             v.visit_table(pos)?
-                .visit_union::<GameRequestBody, _>(
-                    "body_type",
-                    Self::VT_BODY_TYPE,
-                    "body",
-                    Self::VT_BODY,
-                    true,
-                    |key, v, pos| match key {
-                        GameRequestBody::Command => v
-                            .verify_union_variant::<flatbuffers::ForwardsUOffset<Command>>(
-                                "GameRequestBody::Command",
-                                pos,
-                            ),
-                        GameRequestBody::StateRequest => v
-                            .verify_union_variant::<flatbuffers::ForwardsUOffset<StateRequest>>(
-                                "GameRequestBody::StateRequest",
-                                pos,
-                            ),
-                        _ => Ok(()),
-                    },
-                )?
+                .visit_field::<bool>("subscribe", Self::VT_SUBSCRIBE, false)?
                 .finish();
             Ok(())
         }
     }
+    pub struct EventSubscriptionArgs {
+        pub subscribe: bool,
+    }
+    impl<'a> Default for EventSubscriptionArgs {
+        #[inline]
+        fn default() -> Self {
+            EventSubscriptionArgs { subscribe: true }
+        }
+    }
+
+    pub struct EventSubscriptionBuilder<'a: 'b, 'b> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b> EventSubscriptionBuilder<'a, 'b> {
+        #[inline]
+        pub fn add_subscribe(&mut self, subscribe: bool) {
+            self.fbb_
+                .push_slot::<bool>(EventSubscription::VT_SUBSCRIBE, subscribe, true);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        ) -> EventSubscriptionBuilder<'a, 'b> {
+            let start = _fbb.start_table();
+            EventSubscriptionBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<EventSubscription<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl std::fmt::Debug for EventSubscription<'_> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            let mut ds = f.debug_struct("EventSubscription");
+            ds.field("subscribe", &self.subscribe());
+            ds.finish()
+        }
+    }
+    pub enum GameRequestOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct GameRequest<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for GameRequest<'a> {
+        type Inner = GameRequest<'a>;
+        #[inline]
+        fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table { buf, loc },
+            }
+        }
+    }
+
+    impl<'a> GameRequest<'a> {
+        pub const VT_BODY_TYPE: flatbuffers::VOffsetT = 4;
+        pub const VT_BODY: flatbuffers::VOffsetT = 6;
+
+        #[inline]
+        pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            GameRequest { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+            args: &'args GameRequestArgs,
+        ) -> flatbuffers::WIPOffset<GameRequest<'bldr>> {
+            let mut builder = GameRequestBuilder::new(_fbb);
+            if let Some(x) = args.body {
+                builder.add_body(x);
+            }
+            builder.add_body_type(args.body_type);
+            builder.finish()
+        }
+
+        #[inline]
+        pub fn body_type(&self) -> GameRequestBody {
+            self._tab
+                .get::<GameRequestBody>(GameRequest::VT_BODY_TYPE, Some(GameRequestBody::NONE))
+                .unwrap()
+        }
+        #[inline]
+        pub fn body(&self) -> flatbuffers::Table<'a> {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(
+                    GameRequest::VT_BODY,
+                    None,
+                )
+                .unwrap()
+        }
+        #[inline]
+        #[allow(non_snake_case)]
+        pub fn body_as_command(&self) -> Option<Command<'a>> {
+            if self.body_type() == GameRequestBody::Command {
+                let u = self.body();
+                Some(Command::init_from_table(u))
+            } else {
+                None
+            }
+        }
+
+        #[inline]
+        #[allow(non_snake_case)]
+        pub fn body_as_state_request(&self) -> Option<StateRequest<'a>> {
+            if self.body_type() == GameRequestBody::StateRequest {
+                let u = self.body();
+                Some(StateRequest::init_from_table(u))
+            } else {
+                None
+            }
+        }
+
+        #[inline]
+        #[allow(non_snake_case)]
+        pub fn body_as_event_subscription(&self) -> Option<EventSubscription<'a>> {
+            if self.body_type() == GameRequestBody::EventSubscription {
+                let u = self.body();
+                Some(EventSubscription::init_from_table(u))
+            } else {
+                None
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for GameRequest<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+     .visit_union::<GameRequestBody, _>("body_type", Self::VT_BODY_TYPE, "body", Self::VT_BODY, true, |key, v, pos| {
+        match key {
+          GameRequestBody::Command => v.verify_union_variant::<flatbuffers::ForwardsUOffset<Command>>("GameRequestBody::Command", pos),
+          GameRequestBody::StateRequest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<StateRequest>>("GameRequestBody::StateRequest", pos),
+          GameRequestBody::EventSubscription => v.verify_union_variant::<flatbuffers::ForwardsUOffset<EventSubscription>>("GameRequestBody::EventSubscription", pos),
+          _ => Ok(()),
+        }
+     })?
+     .finish();
+            Ok(())
+        }
+    }
     pub struct GameRequestArgs {
         pub body_type: GameRequestBody,
         pub body: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>,
@@ -1686,6 +2750,16 @@ pub mod mcfs {
                         )
                     }
                 }
+                GameRequestBody::EventSubscription => {
+                    if let Some(x) = self.body_as_event_subscription() {
+                        ds.field("body", &x)
+                    } else {
+                        ds.field(
+                            "body",
+                            &"InvalidFlatbuffer: Union discriminant does not match value.",
+                        )
+                    }
+                }
                 _ => {
                     let x: Option<()> = None;
                     ds.field("body", &x)
@@ -1717,6 +2791,8 @@ pub mod mcfs {
         pub const VT_INT: flatbuffers::VOffsetT = 8;
         pub const VT_STRING: flatbuffers::VOffsetT = 10;
         pub const VT_VEC: flatbuffers::VOffsetT = 12;
+        pub const VT_ROTATION: flatbuffers::VOffsetT = 14;
+        pub const VT_BOOL: flatbuffers::VOffsetT = 16;
 
         #[inline]
         pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -1728,6 +2804,9 @@ pub mod mcfs {
             args: &'args ResponseArgs<'args>,
         ) -> flatbuffers::WIPOffset<Response<'bldr>> {
             let mut builder = ResponseBuilder::new(_fbb);
+            if let Some(x) = args.rotation {
+                builder.add_rotation(x);
+            }
             if let Some(x) = args.vec {
                 builder.add_vec(x);
             }
@@ -1743,6 +2822,9 @@ pub mod mcfs {
             if let Some(x) = args.error {
                 builder.add_error(x);
             }
+            if let Some(x) = args.bool {
+                builder.add_bool(x);
+            }
             builder.finish()
         }
 
@@ -1767,6 +2849,14 @@ pub mod mcfs {
         pub fn vec(&self) -> Option<&'a Vec3> {
             self._tab.get::<Vec3>(Response::VT_VEC, None)
         }
+        #[inline]
+        pub fn rotation(&self) -> Option<&'a Rotation> {
+            self._tab.get::<Rotation>(Response::VT_ROTATION, None)
+        }
+        #[inline]
+        pub fn bool(&self) -> Option<bool> {
+            self._tab.get::<bool>(Response::VT_BOOL, None)
+        }
     }
 
     impl flatbuffers::Verifiable for Response<'_> {
@@ -1786,6 +2876,8 @@ pub mod mcfs {
                     false,
                 )?
                 .visit_field::<Vec3>("vec", Self::VT_VEC, false)?
+                .visit_field::<Rotation>("rotation", Self::VT_ROTATION, false)?
+                .visit_field::<bool>("bool", Self::VT_BOOL, false)?
                 .finish();
             Ok(())
         }
@@ -1796,6 +2888,8 @@ pub mod mcfs {
         pub int: Option<i32>,
         pub string: Option<flatbuffers::WIPOffset<&'a str>>,
         pub vec: Option<&'a Vec3>,
+        pub rotation: Option<&'a Rotation>,
+        pub bool: Option<bool>,
     }
     impl<'a> Default for ResponseArgs<'a> {
         #[inline]
@@ -1806,6 +2900,8 @@ pub mod mcfs {
                 int: None,
                 string: None,
                 vec: None,
+                rotation: None,
+                bool: None,
             }
         }
     }
@@ -1838,6 +2934,15 @@ pub mod mcfs {
             self.fbb_.push_slot_always::<&Vec3>(Response::VT_VEC, vec);
         }
         #[inline]
+        pub fn add_rotation(&mut self, rotation: &Rotation) {
+            self.fbb_
+                .push_slot_always::<&Rotation>(Response::VT_ROTATION, rotation);
+        }
+        #[inline]
+        pub fn add_bool(&mut self, bool: bool) {
+            self.fbb_.push_slot_always::<bool>(Response::VT_BOOL, bool);
+        }
+        #[inline]
         pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ResponseBuilder<'a, 'b> {
             let start = _fbb.start_table();
             ResponseBuilder {
@@ -1860,6 +2965,8 @@ pub mod mcfs {
             ds.field("int", &self.int());
             ds.field("string", &self.string());
             ds.field("vec", &self.vec());
+            ds.field("rotation", &self.rotation());
+            ds.field("bool", &self.bool());
             ds.finish()
         }
     }
@@ -1885,6 +2992,14 @@ pub mod mcfs {
         pub const VT_PLAYER_WORLD: flatbuffers::VOffsetT = 6;
         pub const VT_ENTITIES: flatbuffers::VOffsetT = 8;
         pub const VT_BLOCK: flatbuffers::VOffsetT = 10;
+        pub const VT_INVENTORY: flatbuffers::VOffsetT = 12;
+        pub const VT_EFFECTS: flatbuffers::VOffsetT = 14;
+        pub const VT_ATTRIBUTES: flatbuffers::VOffsetT = 16;
+        pub const VT_GAMERULES: flatbuffers::VOffsetT = 18;
+        pub const VT_REGION: flatbuffers::VOffsetT = 20;
+        pub const VT_COLUMNS: flatbuffers::VOffsetT = 22;
+        pub const VT_CHUNKS: flatbuffers::VOffsetT = 24;
+        pub const VT_OBJECTIVES: flatbuffers::VOffsetT = 26;
 
         #[inline]
         pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -1896,6 +3011,30 @@ pub mod mcfs {
             args: &'args StateResponseArgs<'args>,
         ) -> flatbuffers::WIPOffset<StateResponse<'bldr>> {
             let mut builder = StateResponseBuilder::new(_fbb);
+            if let Some(x) = args.objectives {
+                builder.add_objectives(x);
+            }
+            if let Some(x) = args.chunks {
+                builder.add_chunks(x);
+            }
+            if let Some(x) = args.columns {
+                builder.add_columns(x);
+            }
+            if let Some(x) = args.region {
+                builder.add_region(x);
+            }
+            if let Some(x) = args.gamerules {
+                builder.add_gamerules(x);
+            }
+            if let Some(x) = args.attributes {
+                builder.add_attributes(x);
+            }
+            if let Some(x) = args.effects {
+                builder.add_effects(x);
+            }
+            if let Some(x) = args.inventory {
+                builder.add_inventory(x);
+            }
             if let Some(x) = args.block {
                 builder.add_block(x);
             }
@@ -1922,18 +3061,79 @@ pub mod mcfs {
                 .get::<Dimension>(StateResponse::VT_PLAYER_WORLD, None)
         }
         #[inline]
-        pub fn entities(&self) -> Option<&'a [EntityDetails]> {
+        pub fn entities(
+            &self,
+        ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<EntityDetails<'a>>>>
+        {
+            self._tab.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<EntityDetails>>,
+            >>(StateResponse::VT_ENTITIES, None)
+        }
+        #[inline]
+        pub fn block(&self) -> Option<BlockDetails<'a>> {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<BlockDetails>>(StateResponse::VT_BLOCK, None)
+        }
+        #[inline]
+        pub fn inventory(&self) -> Option<InventoryDetails<'a>> {
             self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, EntityDetails>>>(
-                    StateResponse::VT_ENTITIES,
+                .get::<flatbuffers::ForwardsUOffset<InventoryDetails>>(
+                    StateResponse::VT_INVENTORY,
                     None,
                 )
-                .map(|v| v.safe_slice())
         }
         #[inline]
-        pub fn block(&self) -> Option<BlockDetails<'a>> {
+        pub fn effects(
+            &self,
+        ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+            self._tab.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>,
+            >>(StateResponse::VT_EFFECTS, None)
+        }
+        #[inline]
+        pub fn attributes(
+            &self,
+        ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+            self._tab.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>,
+            >>(StateResponse::VT_ATTRIBUTES, None)
+        }
+        #[inline]
+        pub fn gamerules(
+            &self,
+        ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<GameRuleDetails<'a>>>>
+        {
+            self._tab.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<GameRuleDetails>>,
+            >>(StateResponse::VT_GAMERULES, None)
+        }
+        #[inline]
+        pub fn region(&self) -> Option<RegionDetails<'a>> {
             self._tab
-                .get::<flatbuffers::ForwardsUOffset<BlockDetails>>(StateResponse::VT_BLOCK, None)
+                .get::<flatbuffers::ForwardsUOffset<RegionDetails>>(StateResponse::VT_REGION, None)
+        }
+        #[inline]
+        pub fn columns(&self) -> Option<ColumnDetails<'a>> {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<ColumnDetails>>(StateResponse::VT_COLUMNS, None)
+        }
+        #[inline]
+        pub fn chunks(
+            &self,
+        ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<ChunkDetails<'a>>>>
+        {
+            self._tab.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<ChunkDetails>>,
+            >>(StateResponse::VT_CHUNKS, None)
+        }
+        #[inline]
+        pub fn objectives(
+            &self,
+        ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<ObjectiveDetails<'a>>>>
+        {
+            self._tab.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<ObjectiveDetails>>,
+            >>(StateResponse::VT_OBJECTIVES, None)
         }
     }
 
@@ -1945,19 +3145,83 @@ pub mod mcfs {
         ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
             use self::flatbuffers::Verifiable;
             v.visit_table(pos)?
-     .visit_field::<i32>("player_entity_id", Self::VT_PLAYER_ENTITY_ID, false)?
-     .visit_field::<Dimension>("player_world", Self::VT_PLAYER_WORLD, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, EntityDetails>>>("entities", Self::VT_ENTITIES, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<BlockDetails>>("block", Self::VT_BLOCK, false)?
-     .finish();
+                .visit_field::<i32>("player_entity_id", Self::VT_PLAYER_ENTITY_ID, false)?
+                .visit_field::<Dimension>("player_world", Self::VT_PLAYER_WORLD, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<EntityDetails>>,
+                >>("entities", Self::VT_ENTITIES, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<BlockDetails>>(
+                    "block",
+                    Self::VT_BLOCK,
+                    false,
+                )?
+                .visit_field::<flatbuffers::ForwardsUOffset<InventoryDetails>>(
+                    "inventory",
+                    Self::VT_INVENTORY,
+                    false,
+                )?
+                .visit_field::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>,
+                >>("effects", Self::VT_EFFECTS, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>,
+                >>("attributes", Self::VT_ATTRIBUTES, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<GameRuleDetails>>,
+                >>("gamerules", Self::VT_GAMERULES, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<RegionDetails>>(
+                    "region",
+                    Self::VT_REGION,
+                    false,
+                )?
+                .visit_field::<flatbuffers::ForwardsUOffset<ColumnDetails>>(
+                    "columns",
+                    Self::VT_COLUMNS,
+                    false,
+                )?
+                .visit_field::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<ChunkDetails>>,
+                >>("chunks", Self::VT_CHUNKS, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<ObjectiveDetails>>,
+                >>("objectives", Self::VT_OBJECTIVES, false)?
+                .finish();
             Ok(())
         }
     }
     pub struct StateResponseArgs<'a> {
         pub player_entity_id: Option<i32>,
         pub player_world: Option<Dimension>,
-        pub entities: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, EntityDetails>>>,
+        pub entities: Option<
+            flatbuffers::WIPOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<EntityDetails<'a>>>,
+            >,
+        >,
         pub block: Option<flatbuffers::WIPOffset<BlockDetails<'a>>>,
+        pub inventory: Option<flatbuffers::WIPOffset<InventoryDetails<'a>>>,
+        pub effects: Option<
+            flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>,
+        >,
+        pub attributes: Option<
+            flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>,
+        >,
+        pub gamerules: Option<
+            flatbuffers::WIPOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<GameRuleDetails<'a>>>,
+            >,
+        >,
+        pub region: Option<flatbuffers::WIPOffset<RegionDetails<'a>>>,
+        pub columns: Option<flatbuffers::WIPOffset<ColumnDetails<'a>>>,
+        pub chunks: Option<
+            flatbuffers::WIPOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<ChunkDetails<'a>>>,
+            >,
+        >,
+        pub objectives: Option<
+            flatbuffers::WIPOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<ObjectiveDetails<'a>>>,
+            >,
+        >,
     }
     impl<'a> Default for StateResponseArgs<'a> {
         #[inline]
@@ -1967,6 +3231,14 @@ pub mod mcfs {
                 player_world: None,
                 entities: None,
                 block: None,
+                inventory: None,
+                effects: None,
+                attributes: None,
+                gamerules: None,
+                region: None,
+                columns: None,
+                chunks: None,
+                objectives: None,
             }
         }
     }
@@ -1989,7 +3261,9 @@ pub mod mcfs {
         #[inline]
         pub fn add_entities(
             &mut self,
-            entities: flatbuffers::WIPOffset<flatbuffers::Vector<'b, EntityDetails>>,
+            entities: flatbuffers::WIPOffset<
+                flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<EntityDetails<'b>>>,
+            >,
         ) {
             self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
                 StateResponse::VT_ENTITIES,
@@ -2005,6 +3279,86 @@ pub mod mcfs {
                 );
         }
         #[inline]
+        pub fn add_inventory(&mut self, inventory: flatbuffers::WIPOffset<InventoryDetails<'b>>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<InventoryDetails>>(
+                    StateResponse::VT_INVENTORY,
+                    inventory,
+                );
+        }
+        #[inline]
+        pub fn add_effects(
+            &mut self,
+            effects: flatbuffers::WIPOffset<
+                flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<&'b str>>,
+            >,
+        ) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(StateResponse::VT_EFFECTS, effects);
+        }
+        #[inline]
+        pub fn add_attributes(
+            &mut self,
+            attributes: flatbuffers::WIPOffset<
+                flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<&'b str>>,
+            >,
+        ) {
+            self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                StateResponse::VT_ATTRIBUTES,
+                attributes,
+            );
+        }
+        #[inline]
+        pub fn add_gamerules(
+            &mut self,
+            gamerules: flatbuffers::WIPOffset<
+                flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<GameRuleDetails<'b>>>,
+            >,
+        ) {
+            self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                StateResponse::VT_GAMERULES,
+                gamerules,
+            );
+        }
+        #[inline]
+        pub fn add_region(&mut self, region: flatbuffers::WIPOffset<RegionDetails<'b>>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<RegionDetails>>(
+                    StateResponse::VT_REGION,
+                    region,
+                );
+        }
+        #[inline]
+        pub fn add_columns(&mut self, columns: flatbuffers::WIPOffset<ColumnDetails<'b>>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<ColumnDetails>>(
+                    StateResponse::VT_COLUMNS,
+                    columns,
+                );
+        }
+        #[inline]
+        pub fn add_chunks(
+            &mut self,
+            chunks: flatbuffers::WIPOffset<
+                flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<ChunkDetails<'b>>>,
+            >,
+        ) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(StateResponse::VT_CHUNKS, chunks);
+        }
+        #[inline]
+        pub fn add_objectives(
+            &mut self,
+            objectives: flatbuffers::WIPOffset<
+                flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<ObjectiveDetails<'b>>>,
+            >,
+        ) {
+            self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                StateResponse::VT_OBJECTIVES,
+                objectives,
+            );
+        }
+        #[inline]
         pub fn new(
             _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
         ) -> StateResponseBuilder<'a, 'b> {
@@ -2028,18 +3382,26 @@ pub mod mcfs {
             ds.field("player_world", &self.player_world());
             ds.field("entities", &self.entities());
             ds.field("block", &self.block());
+            ds.field("inventory", &self.inventory());
+            ds.field("effects", &self.effects());
+            ds.field("attributes", &self.attributes());
+            ds.field("gamerules", &self.gamerules());
+            ds.field("region", &self.region());
+            ds.field("columns", &self.columns());
+            ds.field("chunks", &self.chunks());
+            ds.field("objectives", &self.objectives());
             ds.finish()
         }
     }
-    pub enum BlockDetailsOffset {}
+    pub enum EntityDetailsOffset {}
     #[derive(Copy, Clone, PartialEq)]
 
-    pub struct BlockDetails<'a> {
+    pub struct EntityDetails<'a> {
         pub _tab: flatbuffers::Table<'a>,
     }
 
-    impl<'a> flatbuffers::Follow<'a> for BlockDetails<'a> {
-        type Inner = BlockDetails<'a>;
+    impl<'a> flatbuffers::Follow<'a> for EntityDetails<'a> {
+        type Inner = EntityDetails<'a>;
         #[inline]
         fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
             Self {
@@ -2048,33 +3410,261 @@ pub mod mcfs {
         }
     }
 
-    impl<'a> BlockDetails<'a> {
-        pub const VT_POS: flatbuffers::VOffsetT = 4;
+    impl<'a> EntityDetails<'a> {
+        pub const VT_ID: flatbuffers::VOffsetT = 4;
+        pub const VT_LIVING: flatbuffers::VOffsetT = 6;
+        pub const VT_TY: flatbuffers::VOffsetT = 8;
+        pub const VT_POS: flatbuffers::VOffsetT = 10;
+        pub const VT_VEHICLE: flatbuffers::VOffsetT = 12;
+        pub const VT_PASSENGERS: flatbuffers::VOffsetT = 14;
 
         #[inline]
         pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-            BlockDetails { _tab: table }
+            EntityDetails { _tab: table }
         }
         #[allow(unused_mut)]
         pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
             _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-            args: &'args BlockDetailsArgs<'args>,
-        ) -> flatbuffers::WIPOffset<BlockDetails<'bldr>> {
-            let mut builder = BlockDetailsBuilder::new(_fbb);
+            args: &'args EntityDetailsArgs<'args>,
+        ) -> flatbuffers::WIPOffset<EntityDetails<'bldr>> {
+            let mut builder = EntityDetailsBuilder::new(_fbb);
+            if let Some(x) = args.passengers {
+                builder.add_passengers(x);
+            }
+            if let Some(x) = args.vehicle {
+                builder.add_vehicle(x);
+            }
             if let Some(x) = args.pos {
                 builder.add_pos(x);
             }
+            if let Some(x) = args.ty {
+                builder.add_ty(x);
+            }
+            builder.add_id(args.id);
+            builder.add_living(args.living);
             builder.finish()
         }
 
         #[inline]
-        pub fn pos(&self) -> &'a BlockPos {
+        pub fn id(&self) -> i32 {
+            self._tab.get::<i32>(EntityDetails::VT_ID, Some(0)).unwrap()
+        }
+        #[inline]
+        pub fn living(&self) -> bool {
             self._tab
-                .get::<BlockPos>(BlockDetails::VT_POS, None)
+                .get::<bool>(EntityDetails::VT_LIVING, Some(false))
                 .unwrap()
         }
-    }
-
+        #[inline]
+        pub fn ty(&self) -> &'a str {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<&str>>(EntityDetails::VT_TY, None)
+                .unwrap()
+        }
+        #[inline]
+        pub fn pos(&self) -> &'a Vec3 {
+            self._tab.get::<Vec3>(EntityDetails::VT_POS, None).unwrap()
+        }
+        #[inline]
+        pub fn vehicle(&self) -> Option<i32> {
+            self._tab.get::<i32>(EntityDetails::VT_VEHICLE, None)
+        }
+        #[inline]
+        pub fn passengers(&self) -> Option<flatbuffers::Vector<'a, i32>> {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, i32>>>(
+                    EntityDetails::VT_PASSENGERS,
+                    None,
+                )
+        }
+    }
+
+    impl flatbuffers::Verifiable for EntityDetails<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<i32>("id", Self::VT_ID, false)?
+                .visit_field::<bool>("living", Self::VT_LIVING, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>("ty", Self::VT_TY, true)?
+                .visit_field::<Vec3>("pos", Self::VT_POS, true)?
+                .visit_field::<i32>("vehicle", Self::VT_VEHICLE, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, i32>>>(
+                    "passengers",
+                    Self::VT_PASSENGERS,
+                    false,
+                )?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct EntityDetailsArgs<'a> {
+        pub id: i32,
+        pub living: bool,
+        pub ty: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub pos: Option<&'a Vec3>,
+        pub vehicle: Option<i32>,
+        pub passengers: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, i32>>>,
+    }
+    impl<'a> Default for EntityDetailsArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            EntityDetailsArgs {
+                id: 0,
+                living: false,
+                ty: None,  // required field
+                pos: None, // required field
+                vehicle: None,
+                passengers: None,
+            }
+        }
+    }
+
+    pub struct EntityDetailsBuilder<'a: 'b, 'b> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b> EntityDetailsBuilder<'a, 'b> {
+        #[inline]
+        pub fn add_id(&mut self, id: i32) {
+            self.fbb_.push_slot::<i32>(EntityDetails::VT_ID, id, 0);
+        }
+        #[inline]
+        pub fn add_living(&mut self, living: bool) {
+            self.fbb_
+                .push_slot::<bool>(EntityDetails::VT_LIVING, living, false);
+        }
+        #[inline]
+        pub fn add_ty(&mut self, ty: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(EntityDetails::VT_TY, ty);
+        }
+        #[inline]
+        pub fn add_pos(&mut self, pos: &Vec3) {
+            self.fbb_
+                .push_slot_always::<&Vec3>(EntityDetails::VT_POS, pos);
+        }
+        #[inline]
+        pub fn add_vehicle(&mut self, vehicle: i32) {
+            self.fbb_
+                .push_slot_always::<i32>(EntityDetails::VT_VEHICLE, vehicle);
+        }
+        #[inline]
+        pub fn add_passengers(
+            &mut self,
+            passengers: flatbuffers::WIPOffset<flatbuffers::Vector<'b, i32>>,
+        ) {
+            self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                EntityDetails::VT_PASSENGERS,
+                passengers,
+            );
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        ) -> EntityDetailsBuilder<'a, 'b> {
+            let start = _fbb.start_table();
+            EntityDetailsBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<EntityDetails<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            self.fbb_.required(o, EntityDetails::VT_TY, "ty");
+            self.fbb_.required(o, EntityDetails::VT_POS, "pos");
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl std::fmt::Debug for EntityDetails<'_> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            let mut ds = f.debug_struct("EntityDetails");
+            ds.field("id", &self.id());
+            ds.field("living", &self.living());
+            ds.field("ty", &self.ty());
+            ds.field("pos", &self.pos());
+            ds.field("vehicle", &self.vehicle());
+            ds.field("passengers", &self.passengers());
+            ds.finish()
+        }
+    }
+    pub enum BlockDetailsOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct BlockDetails<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for BlockDetails<'a> {
+        type Inner = BlockDetails<'a>;
+        #[inline]
+        fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table { buf, loc },
+            }
+        }
+    }
+
+    impl<'a> BlockDetails<'a> {
+        pub const VT_POS: flatbuffers::VOffsetT = 4;
+        pub const VT_PROPERTIES: flatbuffers::VOffsetT = 6;
+        pub const VT_HAS_BLOCK_ENTITY: flatbuffers::VOffsetT = 8;
+        pub const VT_CONTAINER_SIZE: flatbuffers::VOffsetT = 10;
+
+        #[inline]
+        pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            BlockDetails { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+            args: &'args BlockDetailsArgs<'args>,
+        ) -> flatbuffers::WIPOffset<BlockDetails<'bldr>> {
+            let mut builder = BlockDetailsBuilder::new(_fbb);
+            if let Some(x) = args.container_size {
+                builder.add_container_size(x);
+            }
+            if let Some(x) = args.properties {
+                builder.add_properties(x);
+            }
+            if let Some(x) = args.pos {
+                builder.add_pos(x);
+            }
+            builder.add_has_block_entity(args.has_block_entity);
+            builder.finish()
+        }
+
+        #[inline]
+        pub fn pos(&self) -> &'a BlockPos {
+            self._tab
+                .get::<BlockPos>(BlockDetails::VT_POS, None)
+                .unwrap()
+        }
+        #[inline]
+        pub fn properties(
+            &self,
+        ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+            self._tab.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>,
+            >>(BlockDetails::VT_PROPERTIES, None)
+        }
+        #[inline]
+        pub fn has_block_entity(&self) -> bool {
+            self._tab
+                .get::<bool>(BlockDetails::VT_HAS_BLOCK_ENTITY, Some(false))
+                .unwrap()
+        }
+        #[inline]
+        pub fn container_size(&self) -> Option<i32> {
+            self._tab.get::<i32>(BlockDetails::VT_CONTAINER_SIZE, None)
+        }
+    }
+
     impl flatbuffers::Verifiable for BlockDetails<'_> {
         #[inline]
         fn run_verifier(
@@ -2083,55 +3673,1291 @@ pub mod mcfs {
         ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
             use self::flatbuffers::Verifiable;
             v.visit_table(pos)?
-                .visit_field::<BlockPos>("pos", Self::VT_POS, true)?
+                .visit_field::<BlockPos>("pos", Self::VT_POS, true)?
+                .visit_field::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>,
+                >>("properties", Self::VT_PROPERTIES, false)?
+                .visit_field::<bool>("has_block_entity", Self::VT_HAS_BLOCK_ENTITY, false)?
+                .visit_field::<i32>("container_size", Self::VT_CONTAINER_SIZE, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct BlockDetailsArgs<'a> {
+        pub pos: Option<&'a BlockPos>,
+        pub properties: Option<
+            flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>,
+        >,
+        pub has_block_entity: bool,
+        pub container_size: Option<i32>,
+    }
+    impl<'a> Default for BlockDetailsArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            BlockDetailsArgs {
+                pos: None, // required field
+                properties: None,
+                has_block_entity: false,
+                container_size: None,
+            }
+        }
+    }
+
+    pub struct BlockDetailsBuilder<'a: 'b, 'b> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b> BlockDetailsBuilder<'a, 'b> {
+        #[inline]
+        pub fn add_pos(&mut self, pos: &BlockPos) {
+            self.fbb_
+                .push_slot_always::<&BlockPos>(BlockDetails::VT_POS, pos);
+        }
+        #[inline]
+        pub fn add_properties(
+            &mut self,
+            properties: flatbuffers::WIPOffset<
+                flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<&'b str>>,
+            >,
+        ) {
+            self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                BlockDetails::VT_PROPERTIES,
+                properties,
+            );
+        }
+        #[inline]
+        pub fn add_has_block_entity(&mut self, has_block_entity: bool) {
+            self.fbb_
+                .push_slot::<bool>(BlockDetails::VT_HAS_BLOCK_ENTITY, has_block_entity, false);
+        }
+        #[inline]
+        pub fn add_container_size(&mut self, container_size: i32) {
+            self.fbb_
+                .push_slot_always::<i32>(BlockDetails::VT_CONTAINER_SIZE, container_size);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        ) -> BlockDetailsBuilder<'a, 'b> {
+            let start = _fbb.start_table();
+            BlockDetailsBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<BlockDetails<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            self.fbb_.required(o, BlockDetails::VT_POS, "pos");
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl std::fmt::Debug for BlockDetails<'_> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            let mut ds = f.debug_struct("BlockDetails");
+            ds.field("pos", &self.pos());
+            ds.field("properties", &self.properties());
+            ds.field("has_block_entity", &self.has_block_entity());
+            ds.field("container_size", &self.container_size());
+            ds.finish()
+        }
+    }
+    pub enum RegionDetailsOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct RegionDetails<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for RegionDetails<'a> {
+        type Inner = RegionDetails<'a>;
+        #[inline]
+        fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table { buf, loc },
+            }
+        }
+    }
+
+    impl<'a> RegionDetails<'a> {
+        pub const VT_FROM: flatbuffers::VOffsetT = 4;
+        pub const VT_TO: flatbuffers::VOffsetT = 6;
+        pub const VT_PALETTE: flatbuffers::VOffsetT = 8;
+        pub const VT_BLOCKS: flatbuffers::VOffsetT = 10;
+
+        #[inline]
+        pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            RegionDetails { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+            args: &'args RegionDetailsArgs<'args>,
+        ) -> flatbuffers::WIPOffset<RegionDetails<'bldr>> {
+            let mut builder = RegionDetailsBuilder::new(_fbb);
+            if let Some(x) = args.blocks {
+                builder.add_blocks(x);
+            }
+            if let Some(x) = args.palette {
+                builder.add_palette(x);
+            }
+            if let Some(x) = args.to {
+                builder.add_to(x);
+            }
+            if let Some(x) = args.from {
+                builder.add_from(x);
+            }
+            builder.finish()
+        }
+
+        #[inline]
+        pub fn from(&self) -> &'a BlockPos {
+            self._tab
+                .get::<BlockPos>(RegionDetails::VT_FROM, None)
+                .unwrap()
+        }
+        #[inline]
+        pub fn to(&self) -> &'a BlockPos {
+            self._tab
+                .get::<BlockPos>(RegionDetails::VT_TO, None)
+                .unwrap()
+        }
+        #[inline]
+        pub fn palette(&self) -> flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>> {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>,
+                >>(RegionDetails::VT_PALETTE, None)
+                .unwrap()
+        }
+        #[inline]
+        pub fn blocks(&self) -> flatbuffers::Vector<'a, u16> {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u16>>>(
+                    RegionDetails::VT_BLOCKS,
+                    None,
+                )
+                .unwrap()
+        }
+    }
+
+    impl flatbuffers::Verifiable for RegionDetails<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<BlockPos>("from", Self::VT_FROM, true)?
+                .visit_field::<BlockPos>("to", Self::VT_TO, true)?
+                .visit_field::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>,
+                >>("palette", Self::VT_PALETTE, true)?
+                .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u16>>>(
+                    "blocks",
+                    Self::VT_BLOCKS,
+                    true,
+                )?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct RegionDetailsArgs<'a> {
+        pub from: Option<&'a BlockPos>,
+        pub to: Option<&'a BlockPos>,
+        pub palette: Option<
+            flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>,
+        >,
+        pub blocks: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u16>>>,
+    }
+    impl<'a> Default for RegionDetailsArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            RegionDetailsArgs {
+                from: None,    // required field
+                to: None,      // required field
+                palette: None, // required field
+                blocks: None,  // required field
+            }
+        }
+    }
+
+    pub struct RegionDetailsBuilder<'a: 'b, 'b> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b> RegionDetailsBuilder<'a, 'b> {
+        #[inline]
+        pub fn add_from(&mut self, from: &BlockPos) {
+            self.fbb_
+                .push_slot_always::<&BlockPos>(RegionDetails::VT_FROM, from);
+        }
+        #[inline]
+        pub fn add_to(&mut self, to: &BlockPos) {
+            self.fbb_
+                .push_slot_always::<&BlockPos>(RegionDetails::VT_TO, to);
+        }
+        #[inline]
+        pub fn add_palette(
+            &mut self,
+            palette: flatbuffers::WIPOffset<
+                flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<&'b str>>,
+            >,
+        ) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(RegionDetails::VT_PALETTE, palette);
+        }
+        #[inline]
+        pub fn add_blocks(&mut self, blocks: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u16>>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(RegionDetails::VT_BLOCKS, blocks);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        ) -> RegionDetailsBuilder<'a, 'b> {
+            let start = _fbb.start_table();
+            RegionDetailsBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<RegionDetails<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            self.fbb_.required(o, RegionDetails::VT_FROM, "from");
+            self.fbb_.required(o, RegionDetails::VT_TO, "to");
+            self.fbb_.required(o, RegionDetails::VT_PALETTE, "palette");
+            self.fbb_.required(o, RegionDetails::VT_BLOCKS, "blocks");
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl std::fmt::Debug for RegionDetails<'_> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            let mut ds = f.debug_struct("RegionDetails");
+            ds.field("from", &self.from());
+            ds.field("to", &self.to());
+            ds.field("palette", &self.palette());
+            ds.field("blocks", &self.blocks());
+            ds.finish()
+        }
+    }
+    pub enum ColumnDetailsOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct ColumnDetails<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for ColumnDetails<'a> {
+        type Inner = ColumnDetails<'a>;
+        #[inline]
+        fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table { buf, loc },
+            }
+        }
+    }
+
+    impl<'a> ColumnDetails<'a> {
+        pub const VT_FROM: flatbuffers::VOffsetT = 4;
+        pub const VT_TO: flatbuffers::VOffsetT = 6;
+        pub const VT_PALETTE: flatbuffers::VOffsetT = 8;
+        pub const VT_TOPS: flatbuffers::VOffsetT = 10;
+        pub const VT_HEIGHTS: flatbuffers::VOffsetT = 12;
+
+        #[inline]
+        pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            ColumnDetails { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+            args: &'args ColumnDetailsArgs<'args>,
+        ) -> flatbuffers::WIPOffset<ColumnDetails<'bldr>> {
+            let mut builder = ColumnDetailsBuilder::new(_fbb);
+            if let Some(x) = args.heights {
+                builder.add_heights(x);
+            }
+            if let Some(x) = args.tops {
+                builder.add_tops(x);
+            }
+            if let Some(x) = args.palette {
+                builder.add_palette(x);
+            }
+            if let Some(x) = args.to {
+                builder.add_to(x);
+            }
+            if let Some(x) = args.from {
+                builder.add_from(x);
+            }
+            builder.finish()
+        }
+
+        #[inline]
+        pub fn from(&self) -> &'a ColumnPos {
+            self._tab
+                .get::<ColumnPos>(ColumnDetails::VT_FROM, None)
+                .unwrap()
+        }
+        #[inline]
+        pub fn to(&self) -> &'a ColumnPos {
+            self._tab
+                .get::<ColumnPos>(ColumnDetails::VT_TO, None)
+                .unwrap()
+        }
+        #[inline]
+        pub fn palette(&self) -> flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>> {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>,
+                >>(ColumnDetails::VT_PALETTE, None)
+                .unwrap()
+        }
+        #[inline]
+        pub fn tops(&self) -> flatbuffers::Vector<'a, u16> {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u16>>>(
+                    ColumnDetails::VT_TOPS,
+                    None,
+                )
+                .unwrap()
+        }
+        #[inline]
+        pub fn heights(&self) -> flatbuffers::Vector<'a, i16> {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, i16>>>(
+                    ColumnDetails::VT_HEIGHTS,
+                    None,
+                )
+                .unwrap()
+        }
+    }
+
+    impl flatbuffers::Verifiable for ColumnDetails<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<ColumnPos>("from", Self::VT_FROM, true)?
+                .visit_field::<ColumnPos>("to", Self::VT_TO, true)?
+                .visit_field::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>,
+                >>("palette", Self::VT_PALETTE, true)?
+                .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u16>>>(
+                    "tops",
+                    Self::VT_TOPS,
+                    true,
+                )?
+                .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, i16>>>(
+                    "heights",
+                    Self::VT_HEIGHTS,
+                    true,
+                )?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct ColumnDetailsArgs<'a> {
+        pub from: Option<&'a ColumnPos>,
+        pub to: Option<&'a ColumnPos>,
+        pub palette: Option<
+            flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>,
+        >,
+        pub tops: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u16>>>,
+        pub heights: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, i16>>>,
+    }
+    impl<'a> Default for ColumnDetailsArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            ColumnDetailsArgs {
+                from: None,    // required field
+                to: None,      // required field
+                palette: None, // required field
+                tops: None,    // required field
+                heights: None, // required field
+            }
+        }
+    }
+
+    pub struct ColumnDetailsBuilder<'a: 'b, 'b> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b> ColumnDetailsBuilder<'a, 'b> {
+        #[inline]
+        pub fn add_from(&mut self, from: &ColumnPos) {
+            self.fbb_
+                .push_slot_always::<&ColumnPos>(ColumnDetails::VT_FROM, from);
+        }
+        #[inline]
+        pub fn add_to(&mut self, to: &ColumnPos) {
+            self.fbb_
+                .push_slot_always::<&ColumnPos>(ColumnDetails::VT_TO, to);
+        }
+        #[inline]
+        pub fn add_palette(
+            &mut self,
+            palette: flatbuffers::WIPOffset<
+                flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<&'b str>>,
+            >,
+        ) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(ColumnDetails::VT_PALETTE, palette);
+        }
+        #[inline]
+        pub fn add_tops(&mut self, tops: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u16>>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(ColumnDetails::VT_TOPS, tops);
+        }
+        #[inline]
+        pub fn add_heights(
+            &mut self,
+            heights: flatbuffers::WIPOffset<flatbuffers::Vector<'b, i16>>,
+        ) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(ColumnDetails::VT_HEIGHTS, heights);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        ) -> ColumnDetailsBuilder<'a, 'b> {
+            let start = _fbb.start_table();
+            ColumnDetailsBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<ColumnDetails<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            self.fbb_.required(o, ColumnDetails::VT_FROM, "from");
+            self.fbb_.required(o, ColumnDetails::VT_TO, "to");
+            self.fbb_.required(o, ColumnDetails::VT_PALETTE, "palette");
+            self.fbb_.required(o, ColumnDetails::VT_TOPS, "tops");
+            self.fbb_.required(o, ColumnDetails::VT_HEIGHTS, "heights");
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl std::fmt::Debug for ColumnDetails<'_> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            let mut ds = f.debug_struct("ColumnDetails");
+            ds.field("from", &self.from());
+            ds.field("to", &self.to());
+            ds.field("palette", &self.palette());
+            ds.field("tops", &self.tops());
+            ds.field("heights", &self.heights());
+            ds.finish()
+        }
+    }
+    pub enum ChunkDetailsOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct ChunkDetails<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for ChunkDetails<'a> {
+        type Inner = ChunkDetails<'a>;
+        #[inline]
+        fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table { buf, loc },
+            }
+        }
+    }
+
+    impl<'a> ChunkDetails<'a> {
+        pub const VT_X: flatbuffers::VOffsetT = 4;
+        pub const VT_Z: flatbuffers::VOffsetT = 6;
+        pub const VT_ENTITIES: flatbuffers::VOffsetT = 8;
+
+        #[inline]
+        pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            ChunkDetails { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+            args: &'args ChunkDetailsArgs<'args>,
+        ) -> flatbuffers::WIPOffset<ChunkDetails<'bldr>> {
+            let mut builder = ChunkDetailsBuilder::new(_fbb);
+            if let Some(x) = args.entities {
+                builder.add_entities(x);
+            }
+            builder.add_z(args.z);
+            builder.add_x(args.x);
+            builder.finish()
+        }
+
+        #[inline]
+        pub fn x(&self) -> i32 {
+            self._tab.get::<i32>(ChunkDetails::VT_X, Some(0)).unwrap()
+        }
+        #[inline]
+        pub fn z(&self) -> i32 {
+            self._tab.get::<i32>(ChunkDetails::VT_Z, Some(0)).unwrap()
+        }
+        #[inline]
+        pub fn entities(&self) -> Option<flatbuffers::Vector<'a, i32>> {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, i32>>>(
+                    ChunkDetails::VT_ENTITIES,
+                    None,
+                )
+        }
+    }
+
+    impl flatbuffers::Verifiable for ChunkDetails<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<i32>("x", Self::VT_X, false)?
+                .visit_field::<i32>("z", Self::VT_Z, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, i32>>>(
+                    "entities",
+                    Self::VT_ENTITIES,
+                    false,
+                )?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct ChunkDetailsArgs<'a> {
+        pub x: i32,
+        pub z: i32,
+        pub entities: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, i32>>>,
+    }
+    impl<'a> Default for ChunkDetailsArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            ChunkDetailsArgs {
+                x: 0,
+                z: 0,
+                entities: None,
+            }
+        }
+    }
+
+    pub struct ChunkDetailsBuilder<'a: 'b, 'b> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b> ChunkDetailsBuilder<'a, 'b> {
+        #[inline]
+        pub fn add_x(&mut self, x: i32) {
+            self.fbb_.push_slot::<i32>(ChunkDetails::VT_X, x, 0);
+        }
+        #[inline]
+        pub fn add_z(&mut self, z: i32) {
+            self.fbb_.push_slot::<i32>(ChunkDetails::VT_Z, z, 0);
+        }
+        #[inline]
+        pub fn add_entities(
+            &mut self,
+            entities: flatbuffers::WIPOffset<flatbuffers::Vector<'b, i32>>,
+        ) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(ChunkDetails::VT_ENTITIES, entities);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        ) -> ChunkDetailsBuilder<'a, 'b> {
+            let start = _fbb.start_table();
+            ChunkDetailsBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<ChunkDetails<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl std::fmt::Debug for ChunkDetails<'_> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            let mut ds = f.debug_struct("ChunkDetails");
+            ds.field("x", &self.x());
+            ds.field("z", &self.z());
+            ds.field("entities", &self.entities());
+            ds.finish()
+        }
+    }
+    pub enum ObjectiveDetailsOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct ObjectiveDetails<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for ObjectiveDetails<'a> {
+        type Inner = ObjectiveDetails<'a>;
+        #[inline]
+        fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table { buf, loc },
+            }
+        }
+    }
+
+    impl<'a> ObjectiveDetails<'a> {
+        pub const VT_NAME: flatbuffers::VOffsetT = 4;
+        pub const VT_HOLDERS: flatbuffers::VOffsetT = 6;
+
+        #[inline]
+        pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            ObjectiveDetails { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+            args: &'args ObjectiveDetailsArgs<'args>,
+        ) -> flatbuffers::WIPOffset<ObjectiveDetails<'bldr>> {
+            let mut builder = ObjectiveDetailsBuilder::new(_fbb);
+            if let Some(x) = args.holders {
+                builder.add_holders(x);
+            }
+            if let Some(x) = args.name {
+                builder.add_name(x);
+            }
+            builder.finish()
+        }
+
+        #[inline]
+        pub fn name(&self) -> &'a str {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<&str>>(ObjectiveDetails::VT_NAME, None)
+                .unwrap()
+        }
+        #[inline]
+        pub fn holders(
+            &self,
+        ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+            self._tab.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>,
+            >>(ObjectiveDetails::VT_HOLDERS, None)
+        }
+    }
+
+    impl flatbuffers::Verifiable for ObjectiveDetails<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, true)?
+                .visit_field::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>,
+                >>("holders", Self::VT_HOLDERS, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct ObjectiveDetailsArgs<'a> {
+        pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub holders: Option<
+            flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>,
+        >,
+    }
+    impl<'a> Default for ObjectiveDetailsArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            ObjectiveDetailsArgs {
+                name: None, // required field
+                holders: None,
+            }
+        }
+    }
+
+    pub struct ObjectiveDetailsBuilder<'a: 'b, 'b> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b> ObjectiveDetailsBuilder<'a, 'b> {
+        #[inline]
+        pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(ObjectiveDetails::VT_NAME, name);
+        }
+        #[inline]
+        pub fn add_holders(
+            &mut self,
+            holders: flatbuffers::WIPOffset<
+                flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<&'b str>>,
+            >,
+        ) {
+            self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                ObjectiveDetails::VT_HOLDERS,
+                holders,
+            );
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        ) -> ObjectiveDetailsBuilder<'a, 'b> {
+            let start = _fbb.start_table();
+            ObjectiveDetailsBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<ObjectiveDetails<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            self.fbb_.required(o, ObjectiveDetails::VT_NAME, "name");
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl std::fmt::Debug for ObjectiveDetails<'_> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            let mut ds = f.debug_struct("ObjectiveDetails");
+            ds.field("name", &self.name());
+            ds.field("holders", &self.holders());
+            ds.finish()
+        }
+    }
+    pub enum GameRuleDetailsOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct GameRuleDetails<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for GameRuleDetails<'a> {
+        type Inner = GameRuleDetails<'a>;
+        #[inline]
+        fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table { buf, loc },
+            }
+        }
+    }
+
+    impl<'a> GameRuleDetails<'a> {
+        pub const VT_NAME: flatbuffers::VOffsetT = 4;
+        pub const VT_TY: flatbuffers::VOffsetT = 6;
+
+        #[inline]
+        pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            GameRuleDetails { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+            args: &'args GameRuleDetailsArgs<'args>,
+        ) -> flatbuffers::WIPOffset<GameRuleDetails<'bldr>> {
+            let mut builder = GameRuleDetailsBuilder::new(_fbb);
+            if let Some(x) = args.name {
+                builder.add_name(x);
+            }
+            builder.add_ty(args.ty);
+            builder.finish()
+        }
+
+        #[inline]
+        pub fn name(&self) -> &'a str {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<&str>>(GameRuleDetails::VT_NAME, None)
+                .unwrap()
+        }
+        #[inline]
+        pub fn ty(&self) -> GameRuleType {
+            self._tab
+                .get::<GameRuleType>(GameRuleDetails::VT_TY, Some(GameRuleType::Bool))
+                .unwrap()
+        }
+    }
+
+    impl flatbuffers::Verifiable for GameRuleDetails<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, true)?
+                .visit_field::<GameRuleType>("ty", Self::VT_TY, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct GameRuleDetailsArgs<'a> {
+        pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub ty: GameRuleType,
+    }
+    impl<'a> Default for GameRuleDetailsArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            GameRuleDetailsArgs {
+                name: None, // required field
+                ty: GameRuleType::Bool,
+            }
+        }
+    }
+
+    pub struct GameRuleDetailsBuilder<'a: 'b, 'b> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b> GameRuleDetailsBuilder<'a, 'b> {
+        #[inline]
+        pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(GameRuleDetails::VT_NAME, name);
+        }
+        #[inline]
+        pub fn add_ty(&mut self, ty: GameRuleType) {
+            self.fbb_
+                .push_slot::<GameRuleType>(GameRuleDetails::VT_TY, ty, GameRuleType::Bool);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        ) -> GameRuleDetailsBuilder<'a, 'b> {
+            let start = _fbb.start_table();
+            GameRuleDetailsBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<GameRuleDetails<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            self.fbb_.required(o, GameRuleDetails::VT_NAME, "name");
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl std::fmt::Debug for GameRuleDetails<'_> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            let mut ds = f.debug_struct("GameRuleDetails");
+            ds.field("name", &self.name());
+            ds.field("ty", &self.ty());
+            ds.finish()
+        }
+    }
+    pub enum InventoryDetailsOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct InventoryDetails<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for InventoryDetails<'a> {
+        type Inner = InventoryDetails<'a>;
+        #[inline]
+        fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table { buf, loc },
+            }
+        }
+    }
+
+    impl<'a> InventoryDetails<'a> {
+        pub const VT_SIZE: flatbuffers::VOffsetT = 4;
+        pub const VT_SELECTED: flatbuffers::VOffsetT = 6;
+        pub const VT_MAINHAND: flatbuffers::VOffsetT = 8;
+        pub const VT_OFFHAND: flatbuffers::VOffsetT = 10;
+        pub const VT_HEAD: flatbuffers::VOffsetT = 12;
+        pub const VT_CHEST: flatbuffers::VOffsetT = 14;
+        pub const VT_LEGS: flatbuffers::VOffsetT = 16;
+        pub const VT_FEET: flatbuffers::VOffsetT = 18;
+
+        #[inline]
+        pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            InventoryDetails { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+            args: &'args InventoryDetailsArgs,
+        ) -> flatbuffers::WIPOffset<InventoryDetails<'bldr>> {
+            let mut builder = InventoryDetailsBuilder::new(_fbb);
+            if let Some(x) = args.feet {
+                builder.add_feet(x);
+            }
+            if let Some(x) = args.legs {
+                builder.add_legs(x);
+            }
+            if let Some(x) = args.chest {
+                builder.add_chest(x);
+            }
+            if let Some(x) = args.head {
+                builder.add_head(x);
+            }
+            if let Some(x) = args.offhand {
+                builder.add_offhand(x);
+            }
+            if let Some(x) = args.mainhand {
+                builder.add_mainhand(x);
+            }
+            if let Some(x) = args.selected {
+                builder.add_selected(x);
+            }
+            builder.add_size(args.size);
+            builder.finish()
+        }
+
+        #[inline]
+        pub fn size(&self) -> i32 {
+            self._tab
+                .get::<i32>(InventoryDetails::VT_SIZE, Some(0))
+                .unwrap()
+        }
+        #[inline]
+        pub fn selected(&self) -> Option<i32> {
+            self._tab.get::<i32>(InventoryDetails::VT_SELECTED, None)
+        }
+        #[inline]
+        pub fn mainhand(&self) -> Option<i32> {
+            self._tab.get::<i32>(InventoryDetails::VT_MAINHAND, None)
+        }
+        #[inline]
+        pub fn offhand(&self) -> Option<i32> {
+            self._tab.get::<i32>(InventoryDetails::VT_OFFHAND, None)
+        }
+        #[inline]
+        pub fn head(&self) -> Option<i32> {
+            self._tab.get::<i32>(InventoryDetails::VT_HEAD, None)
+        }
+        #[inline]
+        pub fn chest(&self) -> Option<i32> {
+            self._tab.get::<i32>(InventoryDetails::VT_CHEST, None)
+        }
+        #[inline]
+        pub fn legs(&self) -> Option<i32> {
+            self._tab.get::<i32>(InventoryDetails::VT_LEGS, None)
+        }
+        #[inline]
+        pub fn feet(&self) -> Option<i32> {
+            self._tab.get::<i32>(InventoryDetails::VT_FEET, None)
+        }
+    }
+
+    impl flatbuffers::Verifiable for InventoryDetails<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<i32>("size", Self::VT_SIZE, false)?
+                .visit_field::<i32>("selected", Self::VT_SELECTED, false)?
+                .visit_field::<i32>("mainhand", Self::VT_MAINHAND, false)?
+                .visit_field::<i32>("offhand", Self::VT_OFFHAND, false)?
+                .visit_field::<i32>("head", Self::VT_HEAD, false)?
+                .visit_field::<i32>("chest", Self::VT_CHEST, false)?
+                .visit_field::<i32>("legs", Self::VT_LEGS, false)?
+                .visit_field::<i32>("feet", Self::VT_FEET, false)?
                 .finish();
             Ok(())
         }
     }
-    pub struct BlockDetailsArgs<'a> {
-        pub pos: Option<&'a BlockPos>,
+    pub struct InventoryDetailsArgs {
+        pub size: i32,
+        pub selected: Option<i32>,
+        pub mainhand: Option<i32>,
+        pub offhand: Option<i32>,
+        pub head: Option<i32>,
+        pub chest: Option<i32>,
+        pub legs: Option<i32>,
+        pub feet: Option<i32>,
     }
-    impl<'a> Default for BlockDetailsArgs<'a> {
+    impl<'a> Default for InventoryDetailsArgs {
         #[inline]
         fn default() -> Self {
-            BlockDetailsArgs {
-                pos: None, // required field
+            InventoryDetailsArgs {
+                size: 0,
+                selected: None,
+                mainhand: None,
+                offhand: None,
+                head: None,
+                chest: None,
+                legs: None,
+                feet: None,
             }
         }
     }
 
-    pub struct BlockDetailsBuilder<'a: 'b, 'b> {
+    pub struct InventoryDetailsBuilder<'a: 'b, 'b> {
         fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
         start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
     }
-    impl<'a: 'b, 'b> BlockDetailsBuilder<'a, 'b> {
+    impl<'a: 'b, 'b> InventoryDetailsBuilder<'a, 'b> {
         #[inline]
-        pub fn add_pos(&mut self, pos: &BlockPos) {
+        pub fn add_size(&mut self, size: i32) {
             self.fbb_
-                .push_slot_always::<&BlockPos>(BlockDetails::VT_POS, pos);
+                .push_slot::<i32>(InventoryDetails::VT_SIZE, size, 0);
+        }
+        #[inline]
+        pub fn add_selected(&mut self, selected: i32) {
+            self.fbb_
+                .push_slot_always::<i32>(InventoryDetails::VT_SELECTED, selected);
+        }
+        #[inline]
+        pub fn add_mainhand(&mut self, mainhand: i32) {
+            self.fbb_
+                .push_slot_always::<i32>(InventoryDetails::VT_MAINHAND, mainhand);
+        }
+        #[inline]
+        pub fn add_offhand(&mut self, offhand: i32) {
+            self.fbb_
+                .push_slot_always::<i32>(InventoryDetails::VT_OFFHAND, offhand);
+        }
+        #[inline]
+        pub fn add_head(&mut self, head: i32) {
+            self.fbb_
+                .push_slot_always::<i32>(InventoryDetails::VT_HEAD, head);
+        }
+        #[inline]
+        pub fn add_chest(&mut self, chest: i32) {
+            self.fbb_
+                .push_slot_always::<i32>(InventoryDetails::VT_CHEST, chest);
+        }
+        #[inline]
+        pub fn add_legs(&mut self, legs: i32) {
+            self.fbb_
+                .push_slot_always::<i32>(InventoryDetails::VT_LEGS, legs);
+        }
+        #[inline]
+        pub fn add_feet(&mut self, feet: i32) {
+            self.fbb_
+                .push_slot_always::<i32>(InventoryDetails::VT_FEET, feet);
         }
         #[inline]
         pub fn new(
             _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
-        ) -> BlockDetailsBuilder<'a, 'b> {
+        ) -> InventoryDetailsBuilder<'a, 'b> {
             let start = _fbb.start_table();
-            BlockDetailsBuilder {
+            InventoryDetailsBuilder {
                 fbb_: _fbb,
                 start_: start,
             }
         }
         #[inline]
-        pub fn finish(self) -> flatbuffers::WIPOffset<BlockDetails<'a>> {
+        pub fn finish(self) -> flatbuffers::WIPOffset<InventoryDetails<'a>> {
             let o = self.fbb_.end_table(self.start_);
-            self.fbb_.required(o, BlockDetails::VT_POS, "pos");
             flatbuffers::WIPOffset::new(o.value())
         }
     }
 
-    impl std::fmt::Debug for BlockDetails<'_> {
+    impl std::fmt::Debug for InventoryDetails<'_> {
         fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-            let mut ds = f.debug_struct("BlockDetails");
-            ds.field("pos", &self.pos());
+            let mut ds = f.debug_struct("InventoryDetails");
+            ds.field("size", &self.size());
+            ds.field("selected", &self.selected());
+            ds.field("mainhand", &self.mainhand());
+            ds.field("offhand", &self.offhand());
+            ds.field("head", &self.head());
+            ds.field("chest", &self.chest());
+            ds.field("legs", &self.legs());
+            ds.field("feet", &self.feet());
+            ds.finish()
+        }
+    }
+    pub enum EventOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct Event<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for Event<'a> {
+        type Inner = Event<'a>;
+        #[inline]
+        fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table { buf, loc },
+            }
+        }
+    }
+
+    impl<'a> Event<'a> {
+        pub const VT_TY: flatbuffers::VOffsetT = 4;
+        pub const VT_WORLD: flatbuffers::VOffsetT = 6;
+        pub const VT_ENTITY: flatbuffers::VOffsetT = 8;
+        pub const VT_BLOCK: flatbuffers::VOffsetT = 10;
+        pub const VT_MESSAGE: flatbuffers::VOffsetT = 12;
+        pub const VT_AMOUNT: flatbuffers::VOffsetT = 14;
+
+        #[inline]
+        pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            Event { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+            args: &'args EventArgs<'args>,
+        ) -> flatbuffers::WIPOffset<Event<'bldr>> {
+            let mut builder = EventBuilder::new(_fbb);
+            if let Some(x) = args.amount {
+                builder.add_amount(x);
+            }
+            if let Some(x) = args.message {
+                builder.add_message(x);
+            }
+            if let Some(x) = args.block {
+                builder.add_block(x);
+            }
+            if let Some(x) = args.entity {
+                builder.add_entity(x);
+            }
+            builder.add_ty(args.ty);
+            if let Some(x) = args.world {
+                builder.add_world(x);
+            }
+            builder.finish()
+        }
+
+        #[inline]
+        pub fn ty(&self) -> EventType {
+            self._tab
+                .get::<EventType>(Event::VT_TY, Some(EventType::Chat))
+                .unwrap()
+        }
+        #[inline]
+        pub fn world(&self) -> Option<Dimension> {
+            self._tab.get::<Dimension>(Event::VT_WORLD, None)
+        }
+        #[inline]
+        pub fn entity(&self) -> Option<i32> {
+            self._tab.get::<i32>(Event::VT_ENTITY, None)
+        }
+        #[inline]
+        pub fn block(&self) -> Option<&'a BlockPos> {
+            self._tab.get::<BlockPos>(Event::VT_BLOCK, None)
+        }
+        #[inline]
+        pub fn message(&self) -> Option<&'a str> {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<&str>>(Event::VT_MESSAGE, None)
+        }
+        #[inline]
+        pub fn amount(&self) -> Option<f32> {
+            self._tab.get::<f32>(Event::VT_AMOUNT, None)
+        }
+    }
+
+    impl flatbuffers::Verifiable for Event<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<EventType>("ty", Self::VT_TY, false)?
+                .visit_field::<Dimension>("world", Self::VT_WORLD, false)?
+                .visit_field::<i32>("entity", Self::VT_ENTITY, false)?
+                .visit_field::<BlockPos>("block", Self::VT_BLOCK, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "message",
+                    Self::VT_MESSAGE,
+                    false,
+                )?
+                .visit_field::<f32>("amount", Self::VT_AMOUNT, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct EventArgs<'a> {
+        pub ty: EventType,
+        pub world: Option<Dimension>,
+        pub entity: Option<i32>,
+        pub block: Option<&'a BlockPos>,
+        pub message: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub amount: Option<f32>,
+    }
+    impl<'a> Default for EventArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            EventArgs {
+                ty: EventType::Chat,
+                world: None,
+                entity: None,
+                block: None,
+                message: None,
+                amount: None,
+            }
+        }
+    }
+
+    pub struct EventBuilder<'a: 'b, 'b> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b> EventBuilder<'a, 'b> {
+        #[inline]
+        pub fn add_ty(&mut self, ty: EventType) {
+            self.fbb_
+                .push_slot::<EventType>(Event::VT_TY, ty, EventType::Chat);
+        }
+        #[inline]
+        pub fn add_world(&mut self, world: Dimension) {
+            self.fbb_
+                .push_slot_always::<Dimension>(Event::VT_WORLD, world);
+        }
+        #[inline]
+        pub fn add_entity(&mut self, entity: i32) {
+            self.fbb_.push_slot_always::<i32>(Event::VT_ENTITY, entity);
+        }
+        #[inline]
+        pub fn add_block(&mut self, block: &BlockPos) {
+            self.fbb_
+                .push_slot_always::<&BlockPos>(Event::VT_BLOCK, block);
+        }
+        #[inline]
+        pub fn add_message(&mut self, message: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(Event::VT_MESSAGE, message);
+        }
+        #[inline]
+        pub fn add_amount(&mut self, amount: f32) {
+            self.fbb_.push_slot_always::<f32>(Event::VT_AMOUNT, amount);
+        }
+        #[inline]
+        pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> EventBuilder<'a, 'b> {
+            let start = _fbb.start_table();
+            EventBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<Event<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl std::fmt::Debug for Event<'_> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            let mut ds = f.debug_struct("Event");
+            ds.field("ty", &self.ty());
+            ds.field("world", &self.world());
+            ds.field("entity", &self.entity());
+            ds.field("block", &self.block());
+            ds.field("message", &self.message());
+            ds.field("amount", &self.amount());
             ds.finish()
         }
     }
@@ -2209,6 +5035,17 @@ pub mod mcfs {
                 None
             }
         }
+
+        #[inline]
+        #[allow(non_snake_case)]
+        pub fn body_as_event(&self) -> Option<Event<'a>> {
+            if self.body_type() == GameResponseBody::Event {
+                let u = self.body();
+                Some(Event::init_from_table(u))
+            } else {
+                None
+            }
+        }
     }
 
     impl flatbuffers::Verifiable for GameResponse<'_> {
@@ -2236,6 +5073,11 @@ pub mod mcfs {
                                 "GameResponseBody::StateResponse",
                                 pos,
                             ),
+                        GameResponseBody::Event => v
+                            .verify_union_variant::<flatbuffers::ForwardsUOffset<Event>>(
+                                "GameResponseBody::Event",
+                                pos,
+                            ),
                         _ => Ok(()),
                     },
                 )?
@@ -2318,6 +5160,16 @@ pub mod mcfs {
                         )
                     }
                 }
+                GameResponseBody::Event => {
+                    if let Some(x) = self.body_as_event() {
+                        ds.field("body", &x)
+                    } else {
+                        ds.field(
+                            "body",
+                            &"InvalidFlatbuffer: Union discriminant does not match value.",
+                        )
+                    }
+                }
                 _ => {
                     let x: Option<()> = None;
                     ds.field("body", &x)
@@ -1,8 +1,10 @@
 mod channel;
 mod command;
+mod event;
 pub mod generated;
 
 #[cfg(feature = "client")]
 pub use channel::recv::{ConnectedIpcClient, IpcClient};
 pub use channel::{IpcChannel, IpcError};
 pub use command::{Body, BodyType, Command, CommandState, TargetEntity};
+pub use event::GameEvent;
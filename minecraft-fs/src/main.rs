@@ -2,6 +2,7 @@ use std::error::Error;
 use std::fmt::{Display, Formatter};
 use std::process::exit;
 
+use filesystem::MountOptions;
 use ipc::IpcChannel;
 
 fn main() {
@@ -9,7 +10,7 @@ fn main() {
 
     if let Err(err) = run() {
         println!(
-            "error: {}\nusage: {} <mnt point>",
+            "error: {}\nusage: {} [--exact-sizes] <mnt point>",
             err,
             std::env::args().next().as_deref().unwrap_or("mcfuse")
         );
@@ -21,12 +22,20 @@ fn main() {
 struct ArgError;
 
 fn run() -> Result<(), Box<dyn Error>> {
-    // get mnt point from args
-    let mnt_point = std::env::args().nth(1).ok_or(ArgError)?;
+    let mut opts = MountOptions::default();
+    let mut mnt_point = None;
+    for arg in std::env::args().skip(1) {
+        match arg.as_str() {
+            "--exact-sizes" => opts.exact_file_sizes = true,
+            _ => mnt_point = Some(arg),
+        }
+    }
+
+    let mnt_point = mnt_point.ok_or(ArgError)?;
 
     // connect to game
     let ipc = IpcChannel::open_existing()?;
-    let mnted = filesystem::mount(ipc, mnt_point.as_ref())?;
+    let mnted = filesystem::mount(ipc, mnt_point.as_ref(), opts)?;
 
     println!("mounted! ctrl c to exit");
     mnted.wait_for_unmount();
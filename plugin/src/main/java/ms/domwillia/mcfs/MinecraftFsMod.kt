@@ -1,5 +1,6 @@
 package ms.domwillia.mcfs
 
+import ms.domwillia.mcfs.ipc.GameEvents
 import ms.domwillia.mcfs.ipc.IpcChannel
 import net.fabricmc.api.ModInitializer
 import org.apache.logging.log4j.LogManager
@@ -8,6 +9,8 @@ import kotlin.io.path.exists
 
 class MinecraftFsMod : ModInitializer {
     override fun onInitialize() = try {
+        GameEvents.register()
+
         // close on shutdown
         Runtime.getRuntime().addShutdownHook(Thread {
             try {
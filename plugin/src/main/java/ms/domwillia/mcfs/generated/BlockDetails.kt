@@ -25,6 +25,28 @@ class BlockDetails : Table() {
             null
         }
     }
+    fun properties(j: Int) : String? {
+        val o = __offset(6)
+        return if (o != 0) {
+            __string(__vector(o) + j * 4)
+        } else {
+            null
+        }
+    }
+    val propertiesLength : Int
+        get() {
+            val o = __offset(6); return if (o != 0) __vector_len(o) else 0
+        }
+    val hasBlockEntity : Boolean
+        get() {
+            val o = __offset(8)
+            return if(o != 0) 0.toByte() != bb.get(o + bb_pos) else false
+        }
+    val containerSize : Int?
+        get() {
+            val o = __offset(10)
+            return if(o != 0) bb.getInt(o + bb_pos) else null
+        }
     companion object {
         fun validateVersion() = Constants.FLATBUFFERS_2_0_0()
         fun getRootAsBlockDetails(_bb: ByteBuffer): BlockDetails = getRootAsBlockDetails(_bb, BlockDetails())
@@ -32,8 +54,19 @@ class BlockDetails : Table() {
             _bb.order(ByteOrder.LITTLE_ENDIAN)
             return (obj.__assign(_bb.getInt(_bb.position()) + _bb.position(), _bb))
         }
-        fun startBlockDetails(builder: FlatBufferBuilder) = builder.startTable(1)
+        fun startBlockDetails(builder: FlatBufferBuilder) = builder.startTable(4)
         fun addPos(builder: FlatBufferBuilder, pos: Int) = builder.addStruct(0, pos, 0)
+        fun addProperties(builder: FlatBufferBuilder, properties: Int) = builder.addOffset(1, properties, 0)
+        fun createPropertiesVector(builder: FlatBufferBuilder, data: IntArray) : Int {
+            builder.startVector(4, data.size, 4)
+            for (i in data.size - 1 downTo 0) {
+                builder.addOffset(data[i])
+            }
+            return builder.endVector()
+        }
+        fun startPropertiesVector(builder: FlatBufferBuilder, numElems: Int) = builder.startVector(4, numElems, 4)
+        fun addHasBlockEntity(builder: FlatBufferBuilder, hasBlockEntity: Boolean) = builder.addBoolean(2, hasBlockEntity, false)
+        fun addContainerSize(builder: FlatBufferBuilder, containerSize: Int) = builder.addInt(3, containerSize, 0)
         fun endBlockDetails(builder: FlatBufferBuilder) : Int {
             val o = builder.endTable()
                 builder.required(o, 4)
@@ -0,0 +1,74 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+package MCFS
+
+import java.nio.*
+import kotlin.math.sign
+import com.google.flatbuffers.*
+
+@Suppress("unused")
+class ChunkDetails : Table() {
+
+    fun __init(_i: Int, _bb: ByteBuffer)  {
+        __reset(_i, _bb)
+    }
+    fun __assign(_i: Int, _bb: ByteBuffer) : ChunkDetails {
+        __init(_i, _bb)
+        return this
+    }
+    val x : Int
+        get() {
+            val o = __offset(4)
+            return if(o != 0) bb.getInt(o + bb_pos) else 0
+        }
+    val z : Int
+        get() {
+            val o = __offset(6)
+            return if(o != 0) bb.getInt(o + bb_pos) else 0
+        }
+    fun entities(j: Int) : Int {
+        val o = __offset(8)
+        return if (o != 0) {
+            bb.getInt(__vector(o) + j * 4)
+        } else {
+            0
+        }
+    }
+    val entitiesLength : Int
+        get() {
+            val o = __offset(8); return if (o != 0) __vector_len(o) else 0
+        }
+    val entitiesAsByteBuffer : ByteBuffer get() = __vector_as_bytebuffer(8, 4)
+    fun entitiesInByteBuffer(_bb: ByteBuffer) : ByteBuffer = __vector_in_bytebuffer(_bb, 8, 4)
+    companion object {
+        fun validateVersion() = Constants.FLATBUFFERS_2_0_0()
+        fun getRootAsChunkDetails(_bb: ByteBuffer): ChunkDetails = getRootAsChunkDetails(_bb, ChunkDetails())
+        fun getRootAsChunkDetails(_bb: ByteBuffer, obj: ChunkDetails): ChunkDetails {
+            _bb.order(ByteOrder.LITTLE_ENDIAN)
+            return (obj.__assign(_bb.getInt(_bb.position()) + _bb.position(), _bb))
+        }
+        fun createChunkDetails(builder: FlatBufferBuilder, x: Int, z: Int, entitiesOffset: Int) : Int {
+            builder.startTable(3)
+            addEntities(builder, entitiesOffset)
+            addZ(builder, z)
+            addX(builder, x)
+            return endChunkDetails(builder)
+        }
+        fun startChunkDetails(builder: FlatBufferBuilder) = builder.startTable(3)
+        fun addX(builder: FlatBufferBuilder, x: Int) = builder.addInt(0, x, 0)
+        fun addZ(builder: FlatBufferBuilder, z: Int) = builder.addInt(1, z, 0)
+        fun addEntities(builder: FlatBufferBuilder, entities: Int) = builder.addOffset(2, entities, 0)
+        fun createEntitiesVector(builder: FlatBufferBuilder, data: IntArray) : Int {
+            builder.startVector(4, data.size, 4)
+            for (i in data.size - 1 downTo 0) {
+                builder.addInt(data[i])
+            }
+            return builder.endVector()
+        }
+        fun startEntitiesVector(builder: FlatBufferBuilder, numElems: Int) = builder.startVector(4, numElems, 4)
+        fun endChunkDetails(builder: FlatBufferBuilder) : Int {
+            val o = builder.endTable()
+            return o
+        }
+    }
+}
@@ -0,0 +1,124 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+package MCFS
+
+import java.nio.*
+import kotlin.math.sign
+import com.google.flatbuffers.*
+
+@Suppress("unused")
+class ColumnDetails : Table() {
+
+    fun __init(_i: Int, _bb: ByteBuffer)  {
+        __reset(_i, _bb)
+    }
+    fun __assign(_i: Int, _bb: ByteBuffer) : ColumnDetails {
+        __init(_i, _bb)
+        return this
+    }
+    val from : MCFS.ColumnPos? get() = from(MCFS.ColumnPos())
+    fun from(obj: MCFS.ColumnPos) : MCFS.ColumnPos? {
+        val o = __offset(4)
+        return if (o != 0) {
+            obj.__assign(o + bb_pos, bb)
+        } else {
+            null
+        }
+    }
+    val to : MCFS.ColumnPos? get() = to(MCFS.ColumnPos())
+    fun to(obj: MCFS.ColumnPos) : MCFS.ColumnPos? {
+        val o = __offset(6)
+        return if (o != 0) {
+            obj.__assign(o + bb_pos, bb)
+        } else {
+            null
+        }
+    }
+    fun palette(j: Int) : String? {
+        val o = __offset(8)
+        return if (o != 0) {
+            __string(__vector(o) + j * 4)
+        } else {
+            null
+        }
+    }
+    val paletteLength : Int
+        get() {
+            val o = __offset(8); return if (o != 0) __vector_len(o) else 0
+        }
+    fun tops(j: Int) : UShort {
+        val o = __offset(10)
+        return if (o != 0) {
+            bb.getShort(__vector(o) + j * 2).toUShort()
+        } else {
+            0u
+        }
+    }
+    val topsLength : Int
+        get() {
+            val o = __offset(10); return if (o != 0) __vector_len(o) else 0
+        }
+    val topsAsByteBuffer : ByteBuffer get() = __vector_as_bytebuffer(10, 2)
+    fun topsInByteBuffer(_bb: ByteBuffer) : ByteBuffer = __vector_in_bytebuffer(_bb, 10, 2)
+    fun heights(j: Int) : Short {
+        val o = __offset(12)
+        return if (o != 0) {
+            bb.getShort(__vector(o) + j * 2)
+        } else {
+            0
+        }
+    }
+    val heightsLength : Int
+        get() {
+            val o = __offset(12); return if (o != 0) __vector_len(o) else 0
+        }
+    val heightsAsByteBuffer : ByteBuffer get() = __vector_as_bytebuffer(12, 2)
+    fun heightsInByteBuffer(_bb: ByteBuffer) : ByteBuffer = __vector_in_bytebuffer(_bb, 12, 2)
+    companion object {
+        fun validateVersion() = Constants.FLATBUFFERS_2_0_0()
+        fun getRootAsColumnDetails(_bb: ByteBuffer): ColumnDetails = getRootAsColumnDetails(_bb, ColumnDetails())
+        fun getRootAsColumnDetails(_bb: ByteBuffer, obj: ColumnDetails): ColumnDetails {
+            _bb.order(ByteOrder.LITTLE_ENDIAN)
+            return (obj.__assign(_bb.getInt(_bb.position()) + _bb.position(), _bb))
+        }
+        fun startColumnDetails(builder: FlatBufferBuilder) = builder.startTable(5)
+        fun addFrom(builder: FlatBufferBuilder, from: Int) = builder.addStruct(0, from, 0)
+        fun addTo(builder: FlatBufferBuilder, to: Int) = builder.addStruct(1, to, 0)
+        fun addPalette(builder: FlatBufferBuilder, palette: Int) = builder.addOffset(2, palette, 0)
+        fun createPaletteVector(builder: FlatBufferBuilder, data: IntArray) : Int {
+            builder.startVector(4, data.size, 4)
+            for (i in data.size - 1 downTo 0) {
+                builder.addOffset(data[i])
+            }
+            return builder.endVector()
+        }
+        fun startPaletteVector(builder: FlatBufferBuilder, numElems: Int) = builder.startVector(4, numElems, 4)
+        fun addTops(builder: FlatBufferBuilder, tops: Int) = builder.addOffset(3, tops, 0)
+        fun createTopsVector(builder: FlatBufferBuilder, data: UShortArray) : Int {
+            builder.startVector(2, data.size, 2)
+            for (i in data.size - 1 downTo 0) {
+                builder.addShort(data[i].toShort())
+            }
+            return builder.endVector()
+        }
+        fun startTopsVector(builder: FlatBufferBuilder, numElems: Int) = builder.startVector(2, numElems, 2)
+        fun addHeights(builder: FlatBufferBuilder, heights: Int) = builder.addOffset(4, heights, 0)
+        fun createHeightsVector(builder: FlatBufferBuilder, data: ShortArray) : Int {
+            builder.startVector(2, data.size, 2)
+            for (i in data.size - 1 downTo 0) {
+                builder.addShort(data[i])
+            }
+            return builder.endVector()
+        }
+        fun startHeightsVector(builder: FlatBufferBuilder, numElems: Int) = builder.startVector(2, numElems, 2)
+        fun endColumnDetails(builder: FlatBufferBuilder) : Int {
+            val o = builder.endTable()
+                builder.required(o, 4)
+                builder.required(o, 6)
+                builder.required(o, 8)
+                builder.required(o, 10)
+                builder.required(o, 12)
+            return o
+        }
+    }
+}
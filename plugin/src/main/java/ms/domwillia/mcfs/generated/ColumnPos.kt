@@ -0,0 +1,29 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+package MCFS
+
+import java.nio.*
+import kotlin.math.sign
+import com.google.flatbuffers.*
+
+@Suppress("unused")
+class ColumnPos : Struct() {
+
+    fun __init(_i: Int, _bb: ByteBuffer)  {
+        __reset(_i, _bb)
+    }
+    fun __assign(_i: Int, _bb: ByteBuffer) : ColumnPos {
+        __init(_i, _bb)
+        return this
+    }
+    val x : Int get() = bb.getInt(bb_pos + 0)
+    val z : Int get() = bb.getInt(bb_pos + 4)
+    companion object {
+        fun createColumnPos(builder: FlatBufferBuilder, x: Int, z: Int) : Int {
+            builder.prep(4, 8)
+            builder.putInt(z)
+            builder.putInt(x)
+            return builder.offset()
+        }
+    }
+}
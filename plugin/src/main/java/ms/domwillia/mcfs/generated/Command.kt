@@ -45,9 +45,65 @@ class Command : Table() {
             null
         }
     }
+    val targetSlot : Int?
+        get() {
+            val o = __offset(14)
+            return if(o != 0) bb.getInt(o + bb_pos) else null
+        }
+    val targetProperty : String?
+        get() {
+            val o = __offset(16)
+            return if (o != 0) __string(o + bb_pos) else null
+        }
+    val targetPropertyAsByteBuffer : ByteBuffer get() = __vector_as_bytebuffer(16, 1)
+    fun targetPropertyInByteBuffer(_bb: ByteBuffer) : ByteBuffer = __vector_in_bytebuffer(_bb, 16, 1)
+    val targetGamerule : String?
+        get() {
+            val o = __offset(18)
+            return if (o != 0) __string(o + bb_pos) else null
+        }
+    val targetGameruleAsByteBuffer : ByteBuffer get() = __vector_as_bytebuffer(18, 1)
+    fun targetGameruleInByteBuffer(_bb: ByteBuffer) : ByteBuffer = __vector_in_bytebuffer(_bb, 18, 1)
+    val targetChunk : MCFS.ColumnPos? get() = targetChunk(MCFS.ColumnPos())
+    fun targetChunk(obj: MCFS.ColumnPos) : MCFS.ColumnPos? {
+        val o = __offset(20)
+        return if (o != 0) {
+            obj.__assign(o + bb_pos, bb)
+        } else {
+            null
+        }
+    }
+    val targetEffect : String?
+        get() {
+            val o = __offset(22)
+            return if (o != 0) __string(o + bb_pos) else null
+        }
+    val targetEffectAsByteBuffer : ByteBuffer get() = __vector_as_bytebuffer(22, 1)
+    fun targetEffectInByteBuffer(_bb: ByteBuffer) : ByteBuffer = __vector_in_bytebuffer(_bb, 22, 1)
+    val targetAttribute : String?
+        get() {
+            val o = __offset(24)
+            return if (o != 0) __string(o + bb_pos) else null
+        }
+    val targetAttributeAsByteBuffer : ByteBuffer get() = __vector_as_bytebuffer(24, 1)
+    fun targetAttributeInByteBuffer(_bb: ByteBuffer) : ByteBuffer = __vector_in_bytebuffer(_bb, 24, 1)
+    val targetObjective : String?
+        get() {
+            val o = __offset(26)
+            return if (o != 0) __string(o + bb_pos) else null
+        }
+    val targetObjectiveAsByteBuffer : ByteBuffer get() = __vector_as_bytebuffer(26, 1)
+    fun targetObjectiveInByteBuffer(_bb: ByteBuffer) : ByteBuffer = __vector_in_bytebuffer(_bb, 26, 1)
+    val targetScoreHolder : String?
+        get() {
+            val o = __offset(28)
+            return if (o != 0) __string(o + bb_pos) else null
+        }
+    val targetScoreHolderAsByteBuffer : ByteBuffer get() = __vector_as_bytebuffer(28, 1)
+    fun targetScoreHolderInByteBuffer(_bb: ByteBuffer) : ByteBuffer = __vector_in_bytebuffer(_bb, 28, 1)
     val write : MCFS.WriteBody? get() = write(MCFS.WriteBody())
     fun write(obj: MCFS.WriteBody) : MCFS.WriteBody? {
-        val o = __offset(14)
+        val o = __offset(30)
         return if (o != 0) {
             obj.__assign(__indirect(o + bb_pos), bb)
         } else {
@@ -61,13 +117,21 @@ class Command : Table() {
             _bb.order(ByteOrder.LITTLE_ENDIAN)
             return (obj.__assign(_bb.getInt(_bb.position()) + _bb.position(), _bb))
         }
-        fun startCommand(builder: FlatBufferBuilder) = builder.startTable(6)
+        fun startCommand(builder: FlatBufferBuilder) = builder.startTable(14)
         fun addCmd(builder: FlatBufferBuilder, cmd: Int) = builder.addInt(0, cmd, 0)
         fun addTargetEntity(builder: FlatBufferBuilder, targetEntity: Int) = builder.addInt(1, targetEntity, 0)
         fun addTargetPlayerEntity(builder: FlatBufferBuilder, targetPlayerEntity: Boolean) = builder.addBoolean(2, targetPlayerEntity, false)
         fun addTargetWorld(builder: FlatBufferBuilder, targetWorld: UByte) = builder.addByte(3, targetWorld.toByte(), 0)
         fun addTargetBlock(builder: FlatBufferBuilder, targetBlock: Int) = builder.addStruct(4, targetBlock, 0)
-        fun addWrite(builder: FlatBufferBuilder, write: Int) = builder.addOffset(5, write, 0)
+        fun addTargetSlot(builder: FlatBufferBuilder, targetSlot: Int) = builder.addInt(5, targetSlot, 0)
+        fun addTargetProperty(builder: FlatBufferBuilder, targetProperty: Int) = builder.addOffset(6, targetProperty, 0)
+        fun addTargetGamerule(builder: FlatBufferBuilder, targetGamerule: Int) = builder.addOffset(7, targetGamerule, 0)
+        fun addTargetChunk(builder: FlatBufferBuilder, targetChunk: Int) = builder.addStruct(8, targetChunk, 0)
+        fun addTargetEffect(builder: FlatBufferBuilder, targetEffect: Int) = builder.addOffset(9, targetEffect, 0)
+        fun addTargetAttribute(builder: FlatBufferBuilder, targetAttribute: Int) = builder.addOffset(10, targetAttribute, 0)
+        fun addTargetObjective(builder: FlatBufferBuilder, targetObjective: Int) = builder.addOffset(11, targetObjective, 0)
+        fun addTargetScoreHolder(builder: FlatBufferBuilder, targetScoreHolder: Int) = builder.addOffset(12, targetScoreHolder, 0)
+        fun addWrite(builder: FlatBufferBuilder, write: Int) = builder.addOffset(13, write, 0)
         fun endCommand(builder: FlatBufferBuilder) : Int {
             val o = builder.endTable()
             return o
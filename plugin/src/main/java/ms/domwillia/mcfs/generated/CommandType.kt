@@ -14,13 +14,53 @@ class CommandType private constructor() {
         const val EntityPosition: Int = 6
         const val EntityHealth: Int = 7
         const val EntityTarget: Int = 8
-        const val WorldTime: Int = 9
-        const val BlockType: Int = 10
-        const val ControlSay: Int = 11
-        const val ControlJump: Int = 12
-        const val ControlMove: Int = 13
-        const val ServerCommand: Int = 14
-        val names : Array<String> = arrayOf("PlayerName", "PlayerGamemode", "PlayerHunger", "PlayerSaturation", "PlayerExhaustion", "EntityType", "EntityPosition", "EntityHealth", "EntityTarget", "WorldTime", "BlockType", "ControlSay", "ControlJump", "ControlMove", "ServerCommand")
+        const val EntityRotation: Int = 9
+        const val EntityVelocity: Int = 10
+        const val EntityOnGround: Int = 11
+        const val EntityEyePosition: Int = 12
+        const val EntityVehicle: Int = 13
+        const val EntityEffectAmplifier: Int = 14
+        const val EntityEffectDuration: Int = 15
+        const val EntityEffectAmbient: Int = 16
+        const val EntityEffectParticles: Int = 17
+        const val EntityEffectRemove: Int = 18
+        const val EntityAttributeBase: Int = 19
+        const val EntityAttributeValue: Int = 20
+        const val EntityNbt: Int = 21
+        const val EntityCustomName: Int = 22
+        const val EntityTags: Int = 23
+        const val EntityGlowing: Int = 24
+        const val EntitySilent: Int = 25
+        const val EntityNoAi: Int = 26
+        const val EntityTeam: Int = 27
+        const val InventorySlotItem: Int = 28
+        const val InventorySlotCount: Int = 29
+        const val InventorySlotNbt: Int = 30
+        const val WorldTime: Int = 31
+        const val WorldWeather: Int = 32
+        const val WorldFill: Int = 33
+        const val WorldPaste: Int = 34
+        const val BlockType: Int = 35
+        const val BlockProperty: Int = 36
+        const val BlockNbt: Int = 37
+        const val ChunkLoaded: Int = 38
+        const val ChunkBiomes: Int = 39
+        const val ChunkInhabitedTime: Int = 40
+        const val ChunkForceLoad: Int = 41
+        const val ControlSay: Int = 42
+        const val ControlJump: Int = 43
+        const val ControlMove: Int = 44
+        const val ServerCommand: Int = 45
+        const val ServerGameRule: Int = 46
+        const val ServerDifficulty: Int = 47
+        const val ServerPvp: Int = 48
+        const val ScoreboardObjectiveAdd: Int = 49
+        const val ScoreboardObjectiveCriteria: Int = 50
+        const val ScoreboardObjectiveDisplayName: Int = 51
+        const val ScoreboardObjectiveDisplaySlot: Int = 52
+        const val ScoreboardObjectiveRemove: Int = 53
+        const val ScoreboardScore: Int = 54
+        val names : Array<String> = arrayOf("PlayerName", "PlayerGamemode", "PlayerHunger", "PlayerSaturation", "PlayerExhaustion", "EntityType", "EntityPosition", "EntityHealth", "EntityTarget", "EntityRotation", "EntityVelocity", "EntityOnGround", "EntityEyePosition", "EntityVehicle", "EntityEffectAmplifier", "EntityEffectDuration", "EntityEffectAmbient", "EntityEffectParticles", "EntityEffectRemove", "EntityAttributeBase", "EntityAttributeValue", "EntityNbt", "EntityCustomName", "EntityTags", "EntityGlowing", "EntitySilent", "EntityNoAi", "EntityTeam", "InventorySlotItem", "InventorySlotCount", "InventorySlotNbt", "WorldTime", "WorldWeather", "WorldFill", "WorldPaste", "BlockType", "BlockProperty", "BlockNbt", "ChunkLoaded", "ChunkBiomes", "ChunkInhabitedTime", "ChunkForceLoad", "ControlSay", "ControlJump", "ControlMove", "ServerCommand", "ServerGameRule", "ServerDifficulty", "ServerPvp", "ScoreboardObjectiveAdd", "ScoreboardObjectiveCriteria", "ScoreboardObjectiveDisplayName", "ScoreboardObjectiveDisplaySlot", "ScoreboardObjectiveRemove", "ScoreboardScore")
         fun name(e: Int) : String = names[e]
     }
 }
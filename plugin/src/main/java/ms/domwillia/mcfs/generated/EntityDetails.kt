@@ -7,7 +7,7 @@ import kotlin.math.sign
 import com.google.flatbuffers.*
 
 @Suppress("unused")
-class EntityDetails : Struct() {
+class EntityDetails : Table() {
 
     fun __init(_i: Int, _bb: ByteBuffer)  {
         __reset(_i, _bb)
@@ -16,15 +16,78 @@ class EntityDetails : Struct() {
         __init(_i, _bb)
         return this
     }
-    val id : Int get() = bb.getInt(bb_pos + 0)
-    val living : Boolean get() = 0.toByte() != bb.get(bb_pos + 4)
+    val id : Int
+        get() {
+            val o = __offset(4)
+            return if(o != 0) bb.getInt(o + bb_pos) else 0
+        }
+    val living : Boolean
+        get() {
+            val o = __offset(6)
+            return if(o != 0) 0.toByte() != bb.get(o + bb_pos) else false
+        }
+    val ty : String?
+        get() {
+            val o = __offset(8)
+            return if (o != 0) __string(o + bb_pos) else null
+        }
+    val tyAsByteBuffer : ByteBuffer get() = __vector_as_bytebuffer(8, 1)
+    fun tyInByteBuffer(_bb: ByteBuffer) : ByteBuffer = __vector_in_bytebuffer(_bb, 8, 1)
+    val pos : MCFS.Vec3? get() = pos(MCFS.Vec3())
+    fun pos(obj: MCFS.Vec3) : MCFS.Vec3? {
+        val o = __offset(10)
+        return if (o != 0) {
+            obj.__assign(o + bb_pos, bb)
+        } else {
+            null
+        }
+    }
+    val vehicle : Int?
+        get() {
+            val o = __offset(12)
+            return if(o != 0) bb.getInt(o + bb_pos) else null
+        }
+    fun passengers(j: Int) : Int {
+        val o = __offset(14)
+        return if (o != 0) {
+            bb.getInt(__vector(o) + j * 4)
+        } else {
+            0
+        }
+    }
+    val passengersLength : Int
+        get() {
+            val o = __offset(14); return if (o != 0) __vector_len(o) else 0
+        }
+    val passengersAsByteBuffer : ByteBuffer get() = __vector_as_bytebuffer(14, 4)
+    fun passengersInByteBuffer(_bb: ByteBuffer) : ByteBuffer = __vector_in_bytebuffer(_bb, 14, 4)
     companion object {
-        fun createEntityDetails(builder: FlatBufferBuilder, id: Int, living: Boolean) : Int {
-            builder.prep(4, 8)
-            builder.pad(3)
-            builder.putBoolean(living)
-            builder.putInt(id)
-            return builder.offset()
+        fun validateVersion() = Constants.FLATBUFFERS_2_0_0()
+        fun getRootAsEntityDetails(_bb: ByteBuffer): EntityDetails = getRootAsEntityDetails(_bb, EntityDetails())
+        fun getRootAsEntityDetails(_bb: ByteBuffer, obj: EntityDetails): EntityDetails {
+            _bb.order(ByteOrder.LITTLE_ENDIAN)
+            return (obj.__assign(_bb.getInt(_bb.position()) + _bb.position(), _bb))
+        }
+        fun startEntityDetails(builder: FlatBufferBuilder) = builder.startTable(6)
+        fun addId(builder: FlatBufferBuilder, id: Int) = builder.addInt(0, id, 0)
+        fun addLiving(builder: FlatBufferBuilder, living: Boolean) = builder.addBoolean(1, living, false)
+        fun addTy(builder: FlatBufferBuilder, ty: Int) = builder.addOffset(2, ty, 0)
+        fun addPos(builder: FlatBufferBuilder, pos: Int) = builder.addStruct(3, pos, 0)
+        fun addVehicle(builder: FlatBufferBuilder, vehicle: Int) = builder.addInt(4, vehicle, 0)
+        fun addPassengers(builder: FlatBufferBuilder, passengers: Int) = builder.addOffset(5, passengers, 0)
+        fun createPassengersVector(builder: FlatBufferBuilder, data: IntArray) : Int {
+            builder.startVector(4, data.size, 4)
+            for (i in data.size - 1 downTo 0) {
+                builder.addInt(data[i])
+            }
+            return builder.endVector()
+        }
+        fun startPassengersVector(builder: FlatBufferBuilder, numElems: Int) = builder.startVector(4, numElems, 4)
+        fun endEntityDetails(builder: FlatBufferBuilder) : Int {
+            val o = builder.endTable()
+                builder.required(o, 8)
+                builder.required(o, 10)
+            return o
         }
     }
 }
@@ -12,7 +12,8 @@ class Error private constructor() {
         const val NoSuchEntity: Int = 4
         const val NoSuchBlock: Int = 5
         const val BadInput: Int = 6
-        val names : Array<String> = arrayOf("Unknown", "UnknownCommand", "NoGame", "MalformedRequest", "NoSuchEntity", "NoSuchBlock", "BadInput")
+        const val NoSuchChunk: Int = 7
+        val names : Array<String> = arrayOf("Unknown", "UnknownCommand", "NoGame", "MalformedRequest", "NoSuchEntity", "NoSuchBlock", "BadInput", "NoSuchChunk")
         fun name(e: Int) : String = names[e]
     }
 }
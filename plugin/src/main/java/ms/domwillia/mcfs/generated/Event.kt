@@ -0,0 +1,74 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+package MCFS
+
+import java.nio.*
+import kotlin.math.sign
+import com.google.flatbuffers.*
+
+@Suppress("unused")
+class Event : Table() {
+
+    fun __init(_i: Int, _bb: ByteBuffer)  {
+        __reset(_i, _bb)
+    }
+    fun __assign(_i: Int, _bb: ByteBuffer) : Event {
+        __init(_i, _bb)
+        return this
+    }
+    val ty : Int
+        get() {
+            val o = __offset(4)
+            return if(o != 0) bb.getInt(o + bb_pos) else 0
+        }
+    val world : UByte?
+        get() {
+            val o = __offset(6)
+            return if(o != 0) bb.get(o + bb_pos).toUByte() else null
+        }
+    val entity : Int?
+        get() {
+            val o = __offset(8)
+            return if(o != 0) bb.getInt(o + bb_pos) else null
+        }
+    val block : MCFS.BlockPos? get() = block(MCFS.BlockPos())
+    fun block(obj: MCFS.BlockPos) : MCFS.BlockPos? {
+        val o = __offset(10)
+        return if (o != 0) {
+            obj.__assign(o + bb_pos, bb)
+        } else {
+            null
+        }
+    }
+    val message : String?
+        get() {
+            val o = __offset(12)
+            return if (o != 0) __string(o + bb_pos) else null
+        }
+    val messageAsByteBuffer : ByteBuffer get() = __vector_as_bytebuffer(12, 1)
+    fun messageInByteBuffer(_bb: ByteBuffer) : ByteBuffer = __vector_in_bytebuffer(_bb, 12, 1)
+    val amount : Float?
+        get() {
+            val o = __offset(14)
+            return if(o != 0) bb.getFloat(o + bb_pos) else null
+        }
+    companion object {
+        fun validateVersion() = Constants.FLATBUFFERS_2_0_0()
+        fun getRootAsEvent(_bb: ByteBuffer): Event = getRootAsEvent(_bb, Event())
+        fun getRootAsEvent(_bb: ByteBuffer, obj: Event): Event {
+            _bb.order(ByteOrder.LITTLE_ENDIAN)
+            return (obj.__assign(_bb.getInt(_bb.position()) + _bb.position(), _bb))
+        }
+        fun startEvent(builder: FlatBufferBuilder) = builder.startTable(6)
+        fun addTy(builder: FlatBufferBuilder, ty: Int) = builder.addInt(0, ty, 0)
+        fun addWorld(builder: FlatBufferBuilder, world: UByte) = builder.addByte(1, world.toByte(), 0)
+        fun addEntity(builder: FlatBufferBuilder, entity: Int) = builder.addInt(2, entity, 0)
+        fun addBlock(builder: FlatBufferBuilder, block: Int) = builder.addStruct(3, block, 0)
+        fun addMessage(builder: FlatBufferBuilder, message: Int) = builder.addOffset(4, message, 0)
+        fun addAmount(builder: FlatBufferBuilder, amount: Float) = builder.addFloat(5, amount, 0.0)
+        fun endEvent(builder: FlatBufferBuilder) : Int {
+            val o = builder.endTable()
+            return o
+        }
+    }
+}
@@ -0,0 +1,43 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+package MCFS
+
+import java.nio.*
+import kotlin.math.sign
+import com.google.flatbuffers.*
+
+@Suppress("unused")
+class EventSubscription : Table() {
+
+    fun __init(_i: Int, _bb: ByteBuffer)  {
+        __reset(_i, _bb)
+    }
+    fun __assign(_i: Int, _bb: ByteBuffer) : EventSubscription {
+        __init(_i, _bb)
+        return this
+    }
+    val subscribe : Boolean
+        get() {
+            val o = __offset(4)
+            return if(o != 0) 0.toByte() != bb.get(o + bb_pos) else true
+        }
+    companion object {
+        fun validateVersion() = Constants.FLATBUFFERS_2_0_0()
+        fun getRootAsEventSubscription(_bb: ByteBuffer): EventSubscription = getRootAsEventSubscription(_bb, EventSubscription())
+        fun getRootAsEventSubscription(_bb: ByteBuffer, obj: EventSubscription): EventSubscription {
+            _bb.order(ByteOrder.LITTLE_ENDIAN)
+            return (obj.__assign(_bb.getInt(_bb.position()) + _bb.position(), _bb))
+        }
+        fun createEventSubscription(builder: FlatBufferBuilder, subscribe: Boolean) : Int {
+            builder.startTable(1)
+            addSubscribe(builder, subscribe)
+            return endEventSubscription(builder)
+        }
+        fun startEventSubscription(builder: FlatBufferBuilder) = builder.startTable(1)
+        fun addSubscribe(builder: FlatBufferBuilder, subscribe: Boolean) = builder.addBoolean(0, subscribe, true)
+        fun endEventSubscription(builder: FlatBufferBuilder) : Int {
+            val o = builder.endTable()
+            return o
+        }
+    }
+}
@@ -0,0 +1,19 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+package MCFS
+
+@Suppress("unused")
+class EventType private constructor() {
+    companion object {
+        const val Chat: Int = 0
+        const val Death: Int = 1
+        const val Damage: Int = 2
+        const val DimensionChange: Int = 3
+        const val EntitySpawn: Int = 4
+        const val EntityDespawn: Int = 5
+        const val BlockBreak: Int = 6
+        const val BlockPlace: Int = 7
+        val names : Array<String> = arrayOf("Chat", "Death", "Damage", "DimensionChange", "EntitySpawn", "EntityDespawn", "BlockBreak", "BlockPlace")
+        fun name(e: Int) : String = names[e]
+    }
+}
@@ -8,7 +8,8 @@ class GameRequestBody private constructor() {
         const val NONE: UByte = 0u
         const val Command: UByte = 1u
         const val StateRequest: UByte = 2u
-        val names : Array<String> = arrayOf("NONE", "Command", "StateRequest")
+        const val EventSubscription: UByte = 3u
+        val names : Array<String> = arrayOf("NONE", "Command", "StateRequest", "EventSubscription")
         fun name(e: Int) : String = names[e]
     }
 }
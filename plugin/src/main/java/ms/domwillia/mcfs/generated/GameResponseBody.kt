@@ -8,7 +8,8 @@ class GameResponseBody private constructor() {
         const val NONE: UByte = 0u
         const val Response: UByte = 1u
         const val StateResponse: UByte = 2u
-        val names : Array<String> = arrayOf("NONE", "Response", "StateResponse")
+        const val Event: UByte = 3u
+        val names : Array<String> = arrayOf("NONE", "Response", "StateResponse", "Event")
         fun name(e: Int) : String = names[e]
     }
 }
@@ -0,0 +1,53 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+package MCFS
+
+import java.nio.*
+import kotlin.math.sign
+import com.google.flatbuffers.*
+
+@Suppress("unused")
+class GameRuleDetails : Table() {
+
+    fun __init(_i: Int, _bb: ByteBuffer)  {
+        __reset(_i, _bb)
+    }
+    fun __assign(_i: Int, _bb: ByteBuffer) : GameRuleDetails {
+        __init(_i, _bb)
+        return this
+    }
+    val name : String?
+        get() {
+            val o = __offset(4)
+            return if (o != 0) __string(o + bb_pos) else null
+        }
+    val nameAsByteBuffer : ByteBuffer get() = __vector_as_bytebuffer(4, 1)
+    fun nameInByteBuffer(_bb: ByteBuffer) : ByteBuffer = __vector_in_bytebuffer(_bb, 4, 1)
+    val ty : UByte
+        get() {
+            val o = __offset(6)
+            return if(o != 0) bb.get(o + bb_pos).toUByte() else 0u
+        }
+    companion object {
+        fun validateVersion() = Constants.FLATBUFFERS_2_0_0()
+        fun getRootAsGameRuleDetails(_bb: ByteBuffer): GameRuleDetails = getRootAsGameRuleDetails(_bb, GameRuleDetails())
+        fun getRootAsGameRuleDetails(_bb: ByteBuffer, obj: GameRuleDetails): GameRuleDetails {
+            _bb.order(ByteOrder.LITTLE_ENDIAN)
+            return (obj.__assign(_bb.getInt(_bb.position()) + _bb.position(), _bb))
+        }
+        fun createGameRuleDetails(builder: FlatBufferBuilder, nameOffset: Int, ty: UByte) : Int {
+            builder.startTable(2)
+            addName(builder, nameOffset)
+            addTy(builder, ty)
+            return endGameRuleDetails(builder)
+        }
+        fun startGameRuleDetails(builder: FlatBufferBuilder) = builder.startTable(2)
+        fun addName(builder: FlatBufferBuilder, name: Int) = builder.addOffset(0, name, 0)
+        fun addTy(builder: FlatBufferBuilder, ty: UByte) = builder.addByte(1, ty.toByte(), 0)
+        fun endGameRuleDetails(builder: FlatBufferBuilder) : Int {
+            val o = builder.endTable()
+                builder.required(o, 4)
+            return o
+        }
+    }
+}
@@ -0,0 +1,13 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+package MCFS
+
+@Suppress("unused")
+class GameRuleType private constructor() {
+    companion object {
+        const val Bool: UByte = 0u
+        const val Int: UByte = 1u
+        val names : Array<String> = arrayOf("Bool", "Int")
+        fun name(e: Int) : String = names[e]
+    }
+}
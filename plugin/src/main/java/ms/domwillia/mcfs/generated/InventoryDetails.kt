@@ -0,0 +1,92 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+package MCFS
+
+import java.nio.*
+import kotlin.math.sign
+import com.google.flatbuffers.*
+
+@Suppress("unused")
+class InventoryDetails : Table() {
+
+    fun __init(_i: Int, _bb: ByteBuffer)  {
+        __reset(_i, _bb)
+    }
+    fun __assign(_i: Int, _bb: ByteBuffer) : InventoryDetails {
+        __init(_i, _bb)
+        return this
+    }
+    val size : Int
+        get() {
+            val o = __offset(4)
+            return if(o != 0) bb.getInt(o + bb_pos) else 0
+        }
+    val selected : Int?
+        get() {
+            val o = __offset(6)
+            return if(o != 0) bb.getInt(o + bb_pos) else null
+        }
+    val mainhand : Int?
+        get() {
+            val o = __offset(8)
+            return if(o != 0) bb.getInt(o + bb_pos) else null
+        }
+    val offhand : Int?
+        get() {
+            val o = __offset(10)
+            return if(o != 0) bb.getInt(o + bb_pos) else null
+        }
+    val head : Int?
+        get() {
+            val o = __offset(12)
+            return if(o != 0) bb.getInt(o + bb_pos) else null
+        }
+    val chest : Int?
+        get() {
+            val o = __offset(14)
+            return if(o != 0) bb.getInt(o + bb_pos) else null
+        }
+    val legs : Int?
+        get() {
+            val o = __offset(16)
+            return if(o != 0) bb.getInt(o + bb_pos) else null
+        }
+    val feet : Int?
+        get() {
+            val o = __offset(18)
+            return if(o != 0) bb.getInt(o + bb_pos) else null
+        }
+    companion object {
+        fun validateVersion() = Constants.FLATBUFFERS_2_0_0()
+        fun getRootAsInventoryDetails(_bb: ByteBuffer): InventoryDetails = getRootAsInventoryDetails(_bb, InventoryDetails())
+        fun getRootAsInventoryDetails(_bb: ByteBuffer, obj: InventoryDetails): InventoryDetails {
+            _bb.order(ByteOrder.LITTLE_ENDIAN)
+            return (obj.__assign(_bb.getInt(_bb.position()) + _bb.position(), _bb))
+        }
+        fun createInventoryDetails(builder: FlatBufferBuilder, size: Int, selected: Int?, mainhand: Int?, offhand: Int?, head: Int?, chest: Int?, legs: Int?, feet: Int?) : Int {
+            builder.startTable(8)
+            feet?.run { addFeet(builder, feet) }
+            legs?.run { addLegs(builder, legs) }
+            chest?.run { addChest(builder, chest) }
+            head?.run { addHead(builder, head) }
+            offhand?.run { addOffhand(builder, offhand) }
+            mainhand?.run { addMainhand(builder, mainhand) }
+            selected?.run { addSelected(builder, selected) }
+            addSize(builder, size)
+            return endInventoryDetails(builder)
+        }
+        fun startInventoryDetails(builder: FlatBufferBuilder) = builder.startTable(8)
+        fun addSize(builder: FlatBufferBuilder, size: Int) = builder.addInt(0, size, 0)
+        fun addSelected(builder: FlatBufferBuilder, selected: Int) = builder.addInt(1, selected, 0)
+        fun addMainhand(builder: FlatBufferBuilder, mainhand: Int) = builder.addInt(2, mainhand, 0)
+        fun addOffhand(builder: FlatBufferBuilder, offhand: Int) = builder.addInt(3, offhand, 0)
+        fun addHead(builder: FlatBufferBuilder, head: Int) = builder.addInt(4, head, 0)
+        fun addChest(builder: FlatBufferBuilder, chest: Int) = builder.addInt(5, chest, 0)
+        fun addLegs(builder: FlatBufferBuilder, legs: Int) = builder.addInt(6, legs, 0)
+        fun addFeet(builder: FlatBufferBuilder, feet: Int) = builder.addInt(7, feet, 0)
+        fun endInventoryDetails(builder: FlatBufferBuilder) : Int {
+            val o = builder.endTable()
+            return o
+        }
+    }
+}
@@ -0,0 +1,68 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+package MCFS
+
+import java.nio.*
+import kotlin.math.sign
+import com.google.flatbuffers.*
+
+@Suppress("unused")
+class ObjectiveDetails : Table() {
+
+    fun __init(_i: Int, _bb: ByteBuffer)  {
+        __reset(_i, _bb)
+    }
+    fun __assign(_i: Int, _bb: ByteBuffer) : ObjectiveDetails {
+        __init(_i, _bb)
+        return this
+    }
+    val name : String?
+        get() {
+            val o = __offset(4)
+            return if (o != 0) __string(o + bb_pos) else null
+        }
+    val nameAsByteBuffer : ByteBuffer get() = __vector_as_bytebuffer(4, 1)
+    fun nameInByteBuffer(_bb: ByteBuffer) : ByteBuffer = __vector_in_bytebuffer(_bb, 4, 1)
+    fun holders(j: Int) : String? {
+        val o = __offset(6)
+        return if (o != 0) {
+            __string(__vector(o) + j * 4)
+        } else {
+            null
+        }
+    }
+    val holdersLength : Int
+        get() {
+            val o = __offset(6); return if (o != 0) __vector_len(o) else 0
+        }
+    companion object {
+        fun validateVersion() = Constants.FLATBUFFERS_2_0_0()
+        fun getRootAsObjectiveDetails(_bb: ByteBuffer): ObjectiveDetails = getRootAsObjectiveDetails(_bb, ObjectiveDetails())
+        fun getRootAsObjectiveDetails(_bb: ByteBuffer, obj: ObjectiveDetails): ObjectiveDetails {
+            _bb.order(ByteOrder.LITTLE_ENDIAN)
+            return (obj.__assign(_bb.getInt(_bb.position()) + _bb.position(), _bb))
+        }
+        fun createObjectiveDetails(builder: FlatBufferBuilder, nameOffset: Int, holdersOffset: Int) : Int {
+            builder.startTable(2)
+            addHolders(builder, holdersOffset)
+            addName(builder, nameOffset)
+            return endObjectiveDetails(builder)
+        }
+        fun startObjectiveDetails(builder: FlatBufferBuilder) = builder.startTable(2)
+        fun addName(builder: FlatBufferBuilder, name: Int) = builder.addOffset(0, name, 0)
+        fun addHolders(builder: FlatBufferBuilder, holders: Int) = builder.addOffset(1, holders, 0)
+        fun createHoldersVector(builder: FlatBufferBuilder, data: IntArray) : Int {
+            builder.startVector(4, data.size, 4)
+            for (i in data.size - 1 downTo 0) {
+                builder.addOffset(data[i])
+            }
+            return builder.endVector()
+        }
+        fun startHoldersVector(builder: FlatBufferBuilder, numElems: Int) = builder.startVector(4, numElems, 4)
+        fun endObjectiveDetails(builder: FlatBufferBuilder) : Int {
+            val o = builder.endTable()
+                builder.required(o, 4)
+            return o
+        }
+    }
+}
@@ -0,0 +1,100 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+package MCFS
+
+import java.nio.*
+import kotlin.math.sign
+import com.google.flatbuffers.*
+
+@Suppress("unused")
+class RegionDetails : Table() {
+
+    fun __init(_i: Int, _bb: ByteBuffer)  {
+        __reset(_i, _bb)
+    }
+    fun __assign(_i: Int, _bb: ByteBuffer) : RegionDetails {
+        __init(_i, _bb)
+        return this
+    }
+    val from : MCFS.BlockPos? get() = from(MCFS.BlockPos())
+    fun from(obj: MCFS.BlockPos) : MCFS.BlockPos? {
+        val o = __offset(4)
+        return if (o != 0) {
+            obj.__assign(o + bb_pos, bb)
+        } else {
+            null
+        }
+    }
+    val to : MCFS.BlockPos? get() = to(MCFS.BlockPos())
+    fun to(obj: MCFS.BlockPos) : MCFS.BlockPos? {
+        val o = __offset(6)
+        return if (o != 0) {
+            obj.__assign(o + bb_pos, bb)
+        } else {
+            null
+        }
+    }
+    fun palette(j: Int) : String? {
+        val o = __offset(8)
+        return if (o != 0) {
+            __string(__vector(o) + j * 4)
+        } else {
+            null
+        }
+    }
+    val paletteLength : Int
+        get() {
+            val o = __offset(8); return if (o != 0) __vector_len(o) else 0
+        }
+    fun blocks(j: Int) : UShort {
+        val o = __offset(10)
+        return if (o != 0) {
+            bb.getShort(__vector(o) + j * 2).toUShort()
+        } else {
+            0u
+        }
+    }
+    val blocksLength : Int
+        get() {
+            val o = __offset(10); return if (o != 0) __vector_len(o) else 0
+        }
+    val blocksAsByteBuffer : ByteBuffer get() = __vector_as_bytebuffer(10, 2)
+    fun blocksInByteBuffer(_bb: ByteBuffer) : ByteBuffer = __vector_in_bytebuffer(_bb, 10, 2)
+    companion object {
+        fun validateVersion() = Constants.FLATBUFFERS_2_0_0()
+        fun getRootAsRegionDetails(_bb: ByteBuffer): RegionDetails = getRootAsRegionDetails(_bb, RegionDetails())
+        fun getRootAsRegionDetails(_bb: ByteBuffer, obj: RegionDetails): RegionDetails {
+            _bb.order(ByteOrder.LITTLE_ENDIAN)
+            return (obj.__assign(_bb.getInt(_bb.position()) + _bb.position(), _bb))
+        }
+        fun startRegionDetails(builder: FlatBufferBuilder) = builder.startTable(4)
+        fun addFrom(builder: FlatBufferBuilder, from: Int) = builder.addStruct(0, from, 0)
+        fun addTo(builder: FlatBufferBuilder, to: Int) = builder.addStruct(1, to, 0)
+        fun addPalette(builder: FlatBufferBuilder, palette: Int) = builder.addOffset(2, palette, 0)
+        fun createPaletteVector(builder: FlatBufferBuilder, data: IntArray) : Int {
+            builder.startVector(4, data.size, 4)
+            for (i in data.size - 1 downTo 0) {
+                builder.addOffset(data[i])
+            }
+            return builder.endVector()
+        }
+        fun startPaletteVector(builder: FlatBufferBuilder, numElems: Int) = builder.startVector(4, numElems, 4)
+        fun addBlocks(builder: FlatBufferBuilder, blocks: Int) = builder.addOffset(3, blocks, 0)
+        fun createBlocksVector(builder: FlatBufferBuilder, data: UShortArray) : Int {
+            builder.startVector(2, data.size, 2)
+            for (i in data.size - 1 downTo 0) {
+                builder.addShort(data[i].toShort())
+            }
+            return builder.endVector()
+        }
+        fun startBlocksVector(builder: FlatBufferBuilder, numElems: Int) = builder.startVector(2, numElems, 2)
+        fun endRegionDetails(builder: FlatBufferBuilder) : Int {
+            val o = builder.endTable()
+                builder.required(o, 4)
+                builder.required(o, 6)
+                builder.required(o, 8)
+                builder.required(o, 10)
+            return o
+        }
+    }
+}
@@ -47,6 +47,20 @@ class Response : Table() {
             null
         }
     }
+    val rotation : MCFS.Rotation? get() = rotation(MCFS.Rotation())
+    fun rotation(obj: MCFS.Rotation) : MCFS.Rotation? {
+        val o = __offset(14)
+        return if (o != 0) {
+            obj.__assign(o + bb_pos, bb)
+        } else {
+            null
+        }
+    }
+    val bool : Boolean?
+        get() {
+            val o = __offset(16)
+            return if(o != 0) 0.toByte() != bb.get(o + bb_pos) else null
+        }
     companion object {
         fun validateVersion() = Constants.FLATBUFFERS_2_0_0()
         fun getRootAsResponse(_bb: ByteBuffer): Response = getRootAsResponse(_bb, Response())
@@ -54,12 +68,14 @@ class Response : Table() {
             _bb.order(ByteOrder.LITTLE_ENDIAN)
             return (obj.__assign(_bb.getInt(_bb.position()) + _bb.position(), _bb))
         }
-        fun startResponse(builder: FlatBufferBuilder) = builder.startTable(5)
+        fun startResponse(builder: FlatBufferBuilder) = builder.startTable(7)
         fun addError(builder: FlatBufferBuilder, error: Int) = builder.addInt(0, error, 0)
         fun addFloat(builder: FlatBufferBuilder, float: Float) = builder.addFloat(1, float, 0.0)
         fun addInt(builder: FlatBufferBuilder, int: Int) = builder.addInt(2, int, 0)
         fun addString(builder: FlatBufferBuilder, string: Int) = builder.addOffset(3, string, 0)
         fun addVec(builder: FlatBufferBuilder, vec: Int) = builder.addStruct(4, vec, 0)
+        fun addRotation(builder: FlatBufferBuilder, rotation: Int) = builder.addStruct(5, rotation, 0)
+        fun addBool(builder: FlatBufferBuilder, bool: Boolean) = builder.addBoolean(6, bool, false)
         fun endResponse(builder: FlatBufferBuilder) : Int {
             val o = builder.endTable()
             return o
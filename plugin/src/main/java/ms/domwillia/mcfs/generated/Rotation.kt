@@ -0,0 +1,29 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+package MCFS
+
+import java.nio.*
+import kotlin.math.sign
+import com.google.flatbuffers.*
+
+@Suppress("unused")
+class Rotation : Struct() {
+
+    fun __init(_i: Int, _bb: ByteBuffer)  {
+        __reset(_i, _bb)
+    }
+    fun __assign(_i: Int, _bb: ByteBuffer) : Rotation {
+        __init(_i, _bb)
+        return this
+    }
+    val yaw : Float get() = bb.getFloat(bb_pos + 0)
+    val pitch : Float get() = bb.getFloat(bb_pos + 4)
+    companion object {
+        fun createRotation(builder: FlatBufferBuilder, yaw: Float, pitch: Float) : Int {
+            builder.prep(4, 8)
+            builder.putFloat(pitch)
+            builder.putFloat(yaw)
+            return builder.offset()
+        }
+    }
+}
@@ -21,20 +21,110 @@ class StateRequest : Table() {
             val o = __offset(4)
             return if(o != 0) 0.toByte() != bb.get(o + bb_pos) else false
         }
+    val entitiesNear : MCFS.Vec3? get() = entitiesNear(MCFS.Vec3())
+    fun entitiesNear(obj: MCFS.Vec3) : MCFS.Vec3? {
+        val o = __offset(6)
+        return if (o != 0) {
+            obj.__assign(o + bb_pos, bb)
+        } else {
+            null
+        }
+    }
+    val entitiesRadius : Float?
+        get() {
+            val o = __offset(8)
+            return if(o != 0) bb.getFloat(o + bb_pos) else null
+        }
     val targetWorld : UByte?
         get() {
-            val o = __offset(6)
+            val o = __offset(10)
             return if(o != 0) bb.get(o + bb_pos).toUByte() else null
         }
     val targetBlock : MCFS.BlockPos? get() = targetBlock(MCFS.BlockPos())
     fun targetBlock(obj: MCFS.BlockPos) : MCFS.BlockPos? {
-        val o = __offset(8)
+        val o = __offset(12)
         return if (o != 0) {
             obj.__assign(o + bb_pos, bb)
         } else {
             null
         }
     }
+    val targetEntity : Int?
+        get() {
+            val o = __offset(14)
+            return if(o != 0) bb.getInt(o + bb_pos) else null
+        }
+    val targetPlayerEntity : Boolean
+        get() {
+            val o = __offset(16)
+            return if(o != 0) 0.toByte() != bb.get(o + bb_pos) else false
+        }
+    val inventory : Boolean
+        get() {
+            val o = __offset(18)
+            return if(o != 0) 0.toByte() != bb.get(o + bb_pos) else false
+        }
+    val effects : Boolean
+        get() {
+            val o = __offset(20)
+            return if(o != 0) 0.toByte() != bb.get(o + bb_pos) else false
+        }
+    val attributes : Boolean
+        get() {
+            val o = __offset(22)
+            return if(o != 0) 0.toByte() != bb.get(o + bb_pos) else false
+        }
+    val gamerules : Boolean
+        get() {
+            val o = __offset(24)
+            return if(o != 0) 0.toByte() != bb.get(o + bb_pos) else false
+        }
+    val regionFrom : MCFS.BlockPos? get() = regionFrom(MCFS.BlockPos())
+    fun regionFrom(obj: MCFS.BlockPos) : MCFS.BlockPos? {
+        val o = __offset(26)
+        return if (o != 0) {
+            obj.__assign(o + bb_pos, bb)
+        } else {
+            null
+        }
+    }
+    val regionTo : MCFS.BlockPos? get() = regionTo(MCFS.BlockPos())
+    fun regionTo(obj: MCFS.BlockPos) : MCFS.BlockPos? {
+        val o = __offset(28)
+        return if (o != 0) {
+            obj.__assign(o + bb_pos, bb)
+        } else {
+            null
+        }
+    }
+    val columnsFrom : MCFS.ColumnPos? get() = columnsFrom(MCFS.ColumnPos())
+    fun columnsFrom(obj: MCFS.ColumnPos) : MCFS.ColumnPos? {
+        val o = __offset(30)
+        return if (o != 0) {
+            obj.__assign(o + bb_pos, bb)
+        } else {
+            null
+        }
+    }
+    val columnsTo : MCFS.ColumnPos? get() = columnsTo(MCFS.ColumnPos())
+    fun columnsTo(obj: MCFS.ColumnPos) : MCFS.ColumnPos? {
+        val o = __offset(32)
+        return if (o != 0) {
+            obj.__assign(o + bb_pos, bb)
+        } else {
+            null
+        }
+    }
+    val chunks : Boolean
+        get() {
+            val o = __offset(34)
+            return if(o != 0) 0.toByte() != bb.get(o + bb_pos) else false
+        }
+    val objectives : Boolean
+        get() {
+            val o = __offset(36)
+            return if(o != 0) 0.toByte() != bb.get(o + bb_pos) else false
+        }
     companion object {
         fun validateVersion() = Constants.FLATBUFFERS_2_0_0()
         fun getRootAsStateRequest(_bb: ByteBuffer): StateRequest = getRootAsStateRequest(_bb, StateRequest())
@@ -42,10 +132,24 @@ class StateRequest : Table() {
             _bb.order(ByteOrder.LITTLE_ENDIAN)
             return (obj.__assign(_bb.getInt(_bb.position()) + _bb.position(), _bb))
         }
-        fun startStateRequest(builder: FlatBufferBuilder) = builder.startTable(3)
+        fun startStateRequest(builder: FlatBufferBuilder) = builder.startTable(17)
         fun addEntitiesById(builder: FlatBufferBuilder, entitiesById: Boolean) = builder.addBoolean(0, entitiesById, false)
-        fun addTargetWorld(builder: FlatBufferBuilder, targetWorld: UByte) = builder.addByte(1, targetWorld.toByte(), 0)
-        fun addTargetBlock(builder: FlatBufferBuilder, targetBlock: Int) = builder.addStruct(2, targetBlock, 0)
+        fun addEntitiesNear(builder: FlatBufferBuilder, entitiesNear: Int) = builder.addStruct(1, entitiesNear, 0)
+        fun addEntitiesRadius(builder: FlatBufferBuilder, entitiesRadius: Float) = builder.addFloat(2, entitiesRadius, 0.0)
+        fun addTargetWorld(builder: FlatBufferBuilder, targetWorld: UByte) = builder.addByte(3, targetWorld.toByte(), 0)
+        fun addTargetBlock(builder: FlatBufferBuilder, targetBlock: Int) = builder.addStruct(4, targetBlock, 0)
+        fun addTargetEntity(builder: FlatBufferBuilder, targetEntity: Int) = builder.addInt(5, targetEntity, 0)
+        fun addTargetPlayerEntity(builder: FlatBufferBuilder, targetPlayerEntity: Boolean) = builder.addBoolean(6, targetPlayerEntity, false)
+        fun addInventory(builder: FlatBufferBuilder, inventory: Boolean) = builder.addBoolean(7, inventory, false)
+        fun addEffects(builder: FlatBufferBuilder, effects: Boolean) = builder.addBoolean(8, effects, false)
+        fun addAttributes(builder: FlatBufferBuilder, attributes: Boolean) = builder.addBoolean(9, attributes, false)
+        fun addGamerules(builder: FlatBufferBuilder, gamerules: Boolean) = builder.addBoolean(10, gamerules, false)
+        fun addRegionFrom(builder: FlatBufferBuilder, regionFrom: Int) = builder.addStruct(11, regionFrom, 0)
+        fun addRegionTo(builder: FlatBufferBuilder, regionTo: Int) = builder.addStruct(12, regionTo, 0)
+        fun addColumnsFrom(builder: FlatBufferBuilder, columnsFrom: Int) = builder.addStruct(13, columnsFrom, 0)
+        fun addColumnsTo(builder: FlatBufferBuilder, columnsTo: Int) = builder.addStruct(14, columnsTo, 0)
+        fun addChunks(builder: FlatBufferBuilder, chunks: Boolean) = builder.addBoolean(15, chunks, false)
+        fun addObjectives(builder: FlatBufferBuilder, objectives: Boolean) = builder.addBoolean(16, objectives, false)
         fun endStateRequest(builder: FlatBufferBuilder) : Int {
             val o = builder.endTable()
             return o
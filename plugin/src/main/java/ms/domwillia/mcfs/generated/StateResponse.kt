@@ -30,7 +30,7 @@ class StateResponse : Table() {
     fun entities(obj: MCFS.EntityDetails, j: Int) : MCFS.EntityDetails? {
         val o = __offset(8)
         return if (o != 0) {
-            obj.__assign(__vector(o) + j * 8, bb)
+            obj.__assign(__indirect(__vector(o) + j * 4), bb)
         } else {
             null
         }
@@ -48,6 +48,96 @@ class StateResponse : Table() {
             null
         }
     }
+    val inventory : MCFS.InventoryDetails? get() = inventory(MCFS.InventoryDetails())
+    fun inventory(obj: MCFS.InventoryDetails) : MCFS.InventoryDetails? {
+        val o = __offset(12)
+        return if (o != 0) {
+            obj.__assign(__indirect(o + bb_pos), bb)
+        } else {
+            null
+        }
+    }
+    fun effects(j: Int) : String? {
+        val o = __offset(14)
+        return if (o != 0) {
+            __string(__vector(o) + j * 4)
+        } else {
+            null
+        }
+    }
+    val effectsLength : Int
+        get() {
+            val o = __offset(14); return if (o != 0) __vector_len(o) else 0
+        }
+    fun attributes(j: Int) : String? {
+        val o = __offset(16)
+        return if (o != 0) {
+            __string(__vector(o) + j * 4)
+        } else {
+            null
+        }
+    }
+    val attributesLength : Int
+        get() {
+            val o = __offset(16); return if (o != 0) __vector_len(o) else 0
+        }
+    fun gamerules(j: Int) : MCFS.GameRuleDetails? = gamerules(MCFS.GameRuleDetails(), j)
+    fun gamerules(obj: MCFS.GameRuleDetails, j: Int) : MCFS.GameRuleDetails? {
+        val o = __offset(18)
+        return if (o != 0) {
+            obj.__assign(__indirect(__vector(o) + j * 4), bb)
+        } else {
+            null
+        }
+    }
+    val gamerulesLength : Int
+        get() {
+            val o = __offset(18); return if (o != 0) __vector_len(o) else 0
+        }
+    val region : MCFS.RegionDetails? get() = region(MCFS.RegionDetails())
+    fun region(obj: MCFS.RegionDetails) : MCFS.RegionDetails? {
+        val o = __offset(20)
+        return if (o != 0) {
+            obj.__assign(__indirect(o + bb_pos), bb)
+        } else {
+            null
+        }
+    }
+    val columns : MCFS.ColumnDetails? get() = columns(MCFS.ColumnDetails())
+    fun columns(obj: MCFS.ColumnDetails) : MCFS.ColumnDetails? {
+        val o = __offset(22)
+        return if (o != 0) {
+            obj.__assign(__indirect(o + bb_pos), bb)
+        } else {
+            null
+        }
+    }
+    fun chunks(j: Int) : MCFS.ChunkDetails? = chunks(MCFS.ChunkDetails(), j)
+    fun chunks(obj: MCFS.ChunkDetails, j: Int) : MCFS.ChunkDetails? {
+        val o = __offset(24)
+        return if (o != 0) {
+            obj.__assign(__indirect(__vector(o) + j * 4), bb)
+        } else {
+            null
+        }
+    }
+    val chunksLength : Int
+        get() {
+            val o = __offset(24); return if (o != 0) __vector_len(o) else 0
+        }
+    fun objectives(j: Int) : MCFS.ObjectiveDetails? = objectives(MCFS.ObjectiveDetails(), j)
+    fun objectives(obj: MCFS.ObjectiveDetails, j: Int) : MCFS.ObjectiveDetails? {
+        val o = __offset(26)
+        return if (o != 0) {
+            obj.__assign(__indirect(__vector(o) + j * 4), bb)
+        } else {
+            null
+        }
+    }
+    val objectivesLength : Int
+        get() {
+            val o = __offset(26); return if (o != 0) __vector_len(o) else 0
+        }
     companion object {
         fun validateVersion() = Constants.FLATBUFFERS_2_0_0()
         fun getRootAsStateResponse(_bb: ByteBuffer): StateResponse = getRootAsStateResponse(_bb, StateResponse())
@@ -55,20 +145,83 @@ class StateResponse : Table() {
             _bb.order(ByteOrder.LITTLE_ENDIAN)
             return (obj.__assign(_bb.getInt(_bb.position()) + _bb.position(), _bb))
         }
-        fun createStateResponse(builder: FlatBufferBuilder, playerEntityId: Int?, playerWorld: UByte?, entitiesOffset: Int, blockOffset: Int) : Int {
-            builder.startTable(4)
+        fun createStateResponse(builder: FlatBufferBuilder, playerEntityId: Int?, playerWorld: UByte?, entitiesOffset: Int, blockOffset: Int, inventoryOffset: Int, effectsOffset: Int, attributesOffset: Int, gamerulesOffset: Int, regionOffset: Int, columnsOffset: Int, chunksOffset: Int, objectivesOffset: Int) : Int {
+            builder.startTable(12)
+            addObjectives(builder, objectivesOffset)
+            addChunks(builder, chunksOffset)
+            addColumns(builder, columnsOffset)
+            addRegion(builder, regionOffset)
+            addGamerules(builder, gamerulesOffset)
+            addAttributes(builder, attributesOffset)
+            addEffects(builder, effectsOffset)
+            addInventory(builder, inventoryOffset)
             addBlock(builder, blockOffset)
             addEntities(builder, entitiesOffset)
             playerEntityId?.run { addPlayerEntityId(builder, playerEntityId) }
             playerWorld?.run { addPlayerWorld(builder, playerWorld) }
             return endStateResponse(builder)
         }
-        fun startStateResponse(builder: FlatBufferBuilder) = builder.startTable(4)
+        fun startStateResponse(builder: FlatBufferBuilder) = builder.startTable(12)
         fun addPlayerEntityId(builder: FlatBufferBuilder, playerEntityId: Int) = builder.addInt(0, playerEntityId, 0)
         fun addPlayerWorld(builder: FlatBufferBuilder, playerWorld: UByte) = builder.addByte(1, playerWorld.toByte(), 0)
         fun addEntities(builder: FlatBufferBuilder, entities: Int) = builder.addOffset(2, entities, 0)
-        fun startEntitiesVector(builder: FlatBufferBuilder, numElems: Int) = builder.startVector(8, numElems, 4)
+        fun createEntitiesVector(builder: FlatBufferBuilder, data: IntArray) : Int {
+            builder.startVector(4, data.size, 4)
+            for (i in data.size - 1 downTo 0) {
+                builder.addOffset(data[i])
+            }
+            return builder.endVector()
+        }
+        fun startEntitiesVector(builder: FlatBufferBuilder, numElems: Int) = builder.startVector(4, numElems, 4)
         fun addBlock(builder: FlatBufferBuilder, block: Int) = builder.addOffset(3, block, 0)
+        fun addInventory(builder: FlatBufferBuilder, inventory: Int) = builder.addOffset(4, inventory, 0)
+        fun addEffects(builder: FlatBufferBuilder, effects: Int) = builder.addOffset(5, effects, 0)
+        fun createEffectsVector(builder: FlatBufferBuilder, data: IntArray) : Int {
+            builder.startVector(4, data.size, 4)
+            for (i in data.size - 1 downTo 0) {
+                builder.addOffset(data[i])
+            }
+            return builder.endVector()
+        }
+        fun startEffectsVector(builder: FlatBufferBuilder, numElems: Int) = builder.startVector(4, numElems, 4)
+        fun addAttributes(builder: FlatBufferBuilder, attributes: Int) = builder.addOffset(6, attributes, 0)
+        fun createAttributesVector(builder: FlatBufferBuilder, data: IntArray) : Int {
+            builder.startVector(4, data.size, 4)
+            for (i in data.size - 1 downTo 0) {
+                builder.addOffset(data[i])
+            }
+            return builder.endVector()
+        }
+        fun startAttributesVector(builder: FlatBufferBuilder, numElems: Int) = builder.startVector(4, numElems, 4)
+        fun addGamerules(builder: FlatBufferBuilder, gamerules: Int) = builder.addOffset(7, gamerules, 0)
+        fun createGamerulesVector(builder: FlatBufferBuilder, data: IntArray) : Int {
+            builder.startVector(4, data.size, 4)
+            for (i in data.size - 1 downTo 0) {
+                builder.addOffset(data[i])
+            }
+            return builder.endVector()
+        }
+        fun startGamerulesVector(builder: FlatBufferBuilder, numElems: Int) = builder.startVector(4, numElems, 4)
+        fun addRegion(builder: FlatBufferBuilder, region: Int) = builder.addOffset(8, region, 0)
+        fun addColumns(builder: FlatBufferBuilder, columns: Int) = builder.addOffset(9, columns, 0)
+        fun addChunks(builder: FlatBufferBuilder, chunks: Int) = builder.addOffset(10, chunks, 0)
+        fun createChunksVector(builder: FlatBufferBuilder, data: IntArray) : Int {
+            builder.startVector(4, data.size, 4)
+            for (i in data.size - 1 downTo 0) {
+                builder.addOffset(data[i])
+            }
+            return builder.endVector()
+        }
+        fun startChunksVector(builder: FlatBufferBuilder, numElems: Int) = builder.startVector(4, numElems, 4)
+        fun addObjectives(builder: FlatBufferBuilder, objectives: Int) = builder.addOffset(11, objectives, 0)
+        fun createObjectivesVector(builder: FlatBufferBuilder, data: IntArray) : Int {
+            builder.startVector(4, data.size, 4)
+            for (i in data.size - 1 downTo 0) {
+                builder.addOffset(data[i])
+            }
+            return builder.endVector()
+        }
+        fun startObjectivesVector(builder: FlatBufferBuilder, numElems: Int) = builder.startVector(4, numElems, 4)
         fun endStateResponse(builder: FlatBufferBuilder) : Int {
             val o = builder.endTable()
             return o
@@ -51,6 +51,20 @@ class WriteBody : Table() {
             null
         }
     }
+    val rotation : MCFS.Rotation? get() = rotation(MCFS.Rotation())
+    fun rotation(obj: MCFS.Rotation) : MCFS.Rotation? {
+        val o = __offset(14)
+        return if (o != 0) {
+            obj.__assign(o + bb_pos, bb)
+        } else {
+            null
+        }
+    }
+    val bool : Boolean?
+        get() {
+            val o = __offset(16)
+            return if(o != 0) 0.toByte() != bb.get(o + bb_pos) else null
+        }
     companion object {
         fun validateVersion() = Constants.FLATBUFFERS_2_0_0()
         fun getRootAsWriteBody(_bb: ByteBuffer): WriteBody = getRootAsWriteBody(_bb, WriteBody())
@@ -58,12 +72,14 @@ class WriteBody : Table() {
             _bb.order(ByteOrder.LITTLE_ENDIAN)
             return (obj.__assign(_bb.getInt(_bb.position()) + _bb.position(), _bb))
         }
-        fun startWriteBody(builder: FlatBufferBuilder) = builder.startTable(5)
+        fun startWriteBody(builder: FlatBufferBuilder) = builder.startTable(7)
         fun addFloat(builder: FlatBufferBuilder, float: Float) = builder.addFloat(0, float, 0.0)
         fun addInt(builder: FlatBufferBuilder, int: Int) = builder.addInt(1, int, 0)
         fun addString(builder: FlatBufferBuilder, string: Int) = builder.addOffset(2, string, 0)
         fun addVec(builder: FlatBufferBuilder, vec: Int) = builder.addStruct(3, vec, 0)
         fun addBlock(builder: FlatBufferBuilder, block: Int) = builder.addStruct(4, block, 0)
+        fun addRotation(builder: FlatBufferBuilder, rotation: Int) = builder.addStruct(5, rotation, 0)
+        fun addBool(builder: FlatBufferBuilder, bool: Boolean) = builder.addBoolean(6, bool, false)
         fun endWriteBody(builder: FlatBufferBuilder) : Int {
             val o = builder.endTable()
             return o
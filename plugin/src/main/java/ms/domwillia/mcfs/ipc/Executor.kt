@@ -2,25 +2,50 @@ package ms.domwillia.mcfs.ipc
 
 import MCFS.*
 import com.google.flatbuffers.FlatBufferBuilder
+import com.mojang.brigadier.StringReader
+import com.mojang.brigadier.exceptions.CommandSyntaxException
 import ms.domwillia.mcfs.MinecraftFsMod
 import net.minecraft.block.Block
+import net.minecraft.block.BlockState
 import net.minecraft.client.MinecraftClient
 import net.minecraft.client.network.ClientPlayerEntity
+import net.minecraft.command.argument.BlockArgumentParser
 import net.minecraft.command.argument.EntityAnchorArgumentType
 import net.minecraft.entity.Entity
+import net.minecraft.entity.EquipmentSlot
 import net.minecraft.entity.LivingEntity
+import net.minecraft.entity.attribute.EntityAttributeInstance
 import net.minecraft.entity.damage.DamageSource
+import net.minecraft.entity.effect.StatusEffectInstance
+import net.minecraft.entity.mob.MobEntity
+import net.minecraft.entity.player.PlayerEntity
+import net.minecraft.inventory.Inventory
+import net.minecraft.item.ItemStack
+import net.minecraft.nbt.StringNbtReader
+import net.minecraft.predicate.NbtPredicate
+import net.minecraft.scoreboard.Scoreboard
+import net.minecraft.scoreboard.ScoreboardCriterion
+import net.minecraft.scoreboard.ScoreboardObjective
 import net.minecraft.server.MinecraftServer
 import net.minecraft.server.network.ServerPlayerEntity
 import net.minecraft.server.world.ServerWorld
+import net.minecraft.state.property.Property
+import net.minecraft.text.LiteralText
+import net.minecraft.text.Text
 import net.minecraft.util.Identifier
 import net.minecraft.util.math.BlockPos
 import net.minecraft.util.math.Box
+import net.minecraft.util.math.ChunkPos
+import net.minecraft.util.math.ChunkSectionPos
 import net.minecraft.util.math.Vec3d
 import net.minecraft.util.registry.Registry
 import net.minecraft.util.registry.SimpleRegistry
+import net.minecraft.world.Difficulty
 import net.minecraft.world.GameMode
+import net.minecraft.world.GameRules
+import net.minecraft.world.Heightmap
 import net.minecraft.world.World
+import net.minecraft.world.chunk.WorldChunk
 import java.nio.ByteBuffer
 
 class NoGameException : Exception()
@@ -28,6 +53,7 @@ class MissingTargetException : Exception()
 class NotLivingException : Exception()
 class BadBlockException(val block: String) : Exception()
 class UnknownEntityException(val id: Int) : Exception()
+class UnloadedChunkException(val pos: ChunkPos) : Exception()
 class UnsupportedOperationException : Exception()
 class InvalidTypeForWriteException : Exception()
 class InvalidInputException(val wat: String) : Exception()
@@ -53,6 +79,9 @@ class Executor(private val responseBuilder: FlatBufferBuilder) {
                 } catch (e: BadBlockException) {
                     MinecraftFsMod.LOGGER.error("Bad block: ${e.block}")
                     mkError(Error.NoSuchBlock)
+                } catch (e: UnloadedChunkException) {
+                    MinecraftFsMod.LOGGER.error("Chunk not loaded: ${e.pos}")
+                    mkError(Error.NoSuchChunk)
                 } catch (e: InvalidInputException) {
                     MinecraftFsMod.LOGGER.error("Invalid value for '${e.wat}'")
                     mkError(Error.BadInput)
@@ -83,6 +112,15 @@ class Executor(private val responseBuilder: FlatBufferBuilder) {
                 val respBody = executeStateRequest(request.body(StateRequest()) as StateRequest)
                 GameResponse.createGameResponse(responseBuilder, GameResponseBody.StateResponse, respBody)
             }
+            GameRequestBody.EventSubscription -> {
+                val sub = request.body(EventSubscription()) as EventSubscription
+                MinecraftFsMod.LOGGER.info("Event subscription: ${sub.subscribe}")
+                GameEvents.subscribed = sub.subscribe
+
+                Response.startResponse(responseBuilder)
+                val respBody = Response.endResponse(responseBuilder)
+                GameResponse.createGameResponse(responseBuilder, GameResponseBody.Response, respBody)
+            }
 
             else -> {
                 MinecraftFsMod.LOGGER.error("Invalid request type");
@@ -171,6 +209,237 @@ class Executor(private val responseBuilder: FlatBufferBuilder) {
                 val entity = getTargetEntity(command)
                 entity.lookAt(EntityAnchorArgumentType.EntityAnchor.EYES, value)
             }
+            CommandType.EntityRotation -> {
+                val value = command.rwRotation()
+                val entity = getTargetEntity(command)
+                if (value == null) {
+                    mkRotation(entity.yaw, entity.pitch)
+                } else {
+                    val (yaw, pitch) = value
+                    if (entity is ServerPlayerEntity) {
+                        // the client is authoritative over its own rotation
+                        entity.networkHandler.requestTeleport(entity.x, entity.y, entity.z, yaw, pitch)
+                    } else {
+                        entity.refreshPositionAndAngles(entity.x, entity.y, entity.z, yaw, pitch)
+                        entity.headYaw = yaw
+                    }
+                }
+            }
+            CommandType.EntityVelocity -> {
+                val value = command.rwPos()
+                val entity = getTargetEntity(command)
+                if (value == null) {
+                    mkPosition(entity.velocity)
+                } else {
+                    entity.velocity = value
+                    entity.velocityModified = true
+                }
+            }
+            CommandType.EntityVehicle -> {
+                val value = command.woString().trim()
+                val entity = getTargetEntity(command)
+                if (value.isEmpty()) {
+                    entity.stopRiding()
+                } else {
+                    val id = value.toIntOrNull() ?: throw InvalidInputException("vehicle")
+                    val vehicle = entity.world.getEntityById(id) ?: throw UnknownEntityException(id)
+                    if (!entity.startRiding(vehicle, true)) throw InvalidInputException("vehicle")
+                }
+            }
+            CommandType.EntityOnGround -> {
+                command.ro()
+                mkBool(getTargetEntity(command).isOnGround)
+            }
+            CommandType.EntityEyePosition -> {
+                command.ro()
+                mkPosition(getTargetEntity(command).eyePos)
+            }
+            CommandType.EntityEffectAmplifier -> {
+                command.ro()
+                mkInt(getTargetEffect(command).amplifier)
+            }
+
+            CommandType.EntityEffectDuration -> {
+                command.ro()
+                mkInt(getTargetEffect(command).duration / 20)
+            }
+
+            CommandType.EntityEffectAmbient -> {
+                command.ro()
+                mkBool(getTargetEffect(command).isAmbient)
+            }
+
+            CommandType.EntityEffectParticles -> {
+                command.ro()
+                mkBool(getTargetEffect(command).shouldShowParticles())
+            }
+
+            CommandType.EntityEffectRemove -> {
+                val effect = getTargetEffect(command)
+                getTargetLivingEntity(command).removeStatusEffect(effect.effectType)
+            }
+
+            CommandType.EntityAttributeBase -> {
+                val value = command.rwFloat()
+                val attribute = getTargetAttribute(command)
+                if (value == null) {
+                    mkFloat(attribute.baseValue.toFloat())
+                } else {
+                    attribute.baseValue = value.toDouble()
+                }
+            }
+
+            CommandType.EntityAttributeValue -> {
+                command.ro()
+                mkFloat(getTargetAttribute(command).value.toFloat())
+            }
+
+            CommandType.EntityNbt -> {
+                val value = command.rwString()
+                val entity = getTargetEntity(command)
+                val current = NbtPredicate.entityToNbt(entity)
+                if (value == null) {
+                    mkString(current.toString())
+                } else {
+                    // like the data command, players can't be modified this way
+                    if (entity is PlayerEntity) throw InvalidInputException("player nbt")
+
+                    val nbt = try {
+                        StringNbtReader.parse(value)
+                    } catch (e: Exception) {
+                        throw InvalidInputException("nbt")
+                    }
+                    val uuid = entity.uuid
+                    entity.readNbt(current.copy().copyFrom(nbt))
+                    entity.uuid = uuid
+                }
+            }
+
+            CommandType.EntityCustomName -> {
+                val value = command.rwString()
+                val entity = getTargetEntity(command)
+                if (value == null) {
+                    mkString(entity.customName?.let { Text.Serializer.toJson(it) } ?: "")
+                } else if (value.isBlank()) {
+                    entity.customName = null
+                } else {
+                    entity.customName = try {
+                        Text.Serializer.fromJson(value)
+                    } catch (e: Exception) {
+                        null
+                    } ?: throw InvalidInputException("custom name")
+                }
+            }
+
+            CommandType.EntityTags -> {
+                val value = command.rwString()
+                val entity = getTargetEntity(command)
+                if (value == null) {
+                    mkString(entity.scoreboardTags.joinToString("") { "$it\n" })
+                } else {
+                    val tags = value.lines().filter { it.isNotEmpty() }
+                    if (!tags.all { TAG_REGEX.matches(it) }) throw InvalidInputException("tag")
+
+                    entity.scoreboardTags.toList().forEach { entity.removeScoreboardTag(it) }
+                    if (!tags.all { entity.addScoreboardTag(it) }) throw InvalidInputException("too many tags")
+                }
+            }
+
+            CommandType.EntityGlowing -> {
+                val value = command.rwBool()
+                val entity = getTargetEntity(command)
+                if (value == null) {
+                    mkBool(entity.isGlowing)
+                } else {
+                    entity.isGlowing = value
+                }
+            }
+
+            CommandType.EntitySilent -> {
+                val value = command.rwBool()
+                val entity = getTargetEntity(command)
+                if (value == null) {
+                    mkBool(entity.isSilent)
+                } else {
+                    entity.isSilent = value
+                }
+            }
+
+            CommandType.EntityNoAi -> {
+                val value = command.rwBool()
+                val mob = getTargetEntity(command) as? MobEntity ?: throw InvalidInputException("ai of a non-mob")
+                if (value == null) {
+                    mkBool(mob.isAiDisabled)
+                } else {
+                    mob.isAiDisabled = value
+                }
+            }
+
+            CommandType.EntityTeam -> {
+                val value = command.rwString()
+                val entity = getTargetEntity(command)
+                val scoreboard = theServer.scoreboard
+                if (value == null) {
+                    mkString(entity.scoreboardTeam?.name ?: "")
+                } else if (value.isBlank()) {
+                    scoreboard.clearPlayerTeam(entity.entityName); Unit
+                } else {
+                    val team = scoreboard.getTeam(value.trim()) ?: throw InvalidInputException("team")
+                    scoreboard.addPlayerToTeam(entity.entityName, team); Unit
+                }
+            }
+
+            CommandType.InventorySlotItem -> {
+                val value = command.rwString()
+                val (owner, slot) = getTargetSlot(command)
+                val stack = owner.getStack(slot)
+                if (value == null) {
+                    mkString(Registry.ITEM.getId(stack.item).toString())
+                } else if (value.isBlank()) {
+                    owner.setStack(slot, ItemStack.EMPTY)
+                } else {
+                    val id = Identifier.tryParse(value.trim().lowercase()) ?: throw InvalidInputException("item")
+                    val item = Registry.ITEM.getOrEmpty(id).orElseThrow { InvalidInputException("item") }
+                    val count = stack.count.coerceIn(1, item.maxCount)
+                    owner.setStack(slot, ItemStack(item, count))
+                }
+            }
+            CommandType.InventorySlotCount -> {
+                val value = command.rwInt()
+                val (owner, slot) = getTargetSlot(command)
+                val stack = owner.getStack(slot)
+                if (value == null) {
+                    mkInt(stack.count)
+                } else if (value <= 0) {
+                    owner.setStack(slot, ItemStack.EMPTY)
+                } else {
+                    if (stack.isEmpty) throw InvalidInputException("count of empty slot")
+                    val newStack = stack.copy()
+                    newStack.count = value.coerceAtMost(stack.maxCount)
+                    owner.setStack(slot, newStack)
+                }
+            }
+            CommandType.InventorySlotNbt -> {
+                val value = command.rwString()
+                val (owner, slot) = getTargetSlot(command)
+                val stack = owner.getStack(slot)
+                if (value == null) {
+                    mkString(stack.nbt?.toString() ?: "")
+                } else {
+                    if (stack.isEmpty) throw InvalidInputException("nbt of empty slot")
+                    val newStack = stack.copy()
+                    newStack.nbt = if (value.isBlank()) {
+                        null
+                    } else {
+                        try {
+                            StringNbtReader.parse(value)
+                        } catch (e: Exception) {
+                            throw InvalidInputException("nbt")
+                        }
+                    }
+                    owner.setStack(slot, newStack)
+                }
+            }
             CommandType.WorldTime -> {
                 val value = command.rwInt()
                 val world = getTargetWorld(command)
@@ -181,6 +450,76 @@ class Executor(private val responseBuilder: FlatBufferBuilder) {
                 }
             }
 
+            CommandType.WorldFill -> {
+                // already validated by the filesystem
+                val args = command.woString()
+                val server = theServer
+                val src = server.commandSource
+                    .withWorld(getTargetWorld(command))
+                    .withSilent()
+
+                // run directly rather than through the command manager to get the number of blocks changed
+                val changed = try {
+                    server.commandManager.dispatcher.execute("fill $args", src)
+                } catch (e: CommandSyntaxException) {
+                    MinecraftFsMod.LOGGER.warn("Fill failed: ${e.message}")
+                    throw InvalidInputException("fill")
+                }
+                mkInt(changed)
+            }
+
+            CommandType.WorldPaste -> {
+                // already decoded from a structure file by the filesystem
+                val lines = command.woString()
+                val world = getTargetWorld(command)
+                val origin = getTargetBlockPos(command)
+                val blocks = lines.lineSequence().filter { it.isNotEmpty() }.map { line ->
+                    val parts = line.split(' ', limit = 4)
+                    val offset = parts.take(3).mapNotNull { it.toIntOrNull() }
+                    if (parts.size != 4 || offset.size != 3) throw InvalidInputException("paste")
+
+                    val state = try {
+                        BlockArgumentParser(StringReader(parts[3]), false).parse(false).blockState
+                    } catch (e: CommandSyntaxException) {
+                        null
+                    } ?: throw BadBlockException(parts[3])
+                    Pair(origin.add(offset[0], offset[1], offset[2]), state)
+                }.toList()
+
+                // only place once every block is known to be valid
+                for ((pos, state) in blocks) {
+                    world.setBlockState(pos, state, Block.NOTIFY_LISTENERS)
+                }
+            }
+
+            CommandType.WorldWeather -> {
+                val value = command.rwString()
+                val world = getTargetWorld(command)
+                if (value == null) {
+                    // weather is shared between all worlds
+                    val props = theServer.saveProperties.mainWorldProperties
+                    val (weather, ticks) = when {
+                        world.isThundering -> Pair("thunder", props.thunderTime)
+                        world.isRaining -> Pair("rain", props.rainTime)
+                        else -> Pair("clear", props.clearWeatherTime)
+                    }
+                    mkString("$weather ${ticks / 20}")
+                } else {
+                    val parts = value.trim().split(Regex("\\s+"))
+                    val seconds = parts.getOrNull(1)?.let { it.toIntOrNull() ?: throw InvalidInputException("duration") }
+                        ?: DEFAULT_WEATHER_SECONDS
+                    if (seconds < 0) throw InvalidInputException("duration")
+
+                    val ticks = seconds * 20
+                    when (parts[0]) {
+                        "clear" -> world.setWeather(ticks, 0, false, false)
+                        "rain" -> world.setWeather(0, ticks, true, false)
+                        "thunder" -> world.setWeather(0, ticks, true, true)
+                        else -> throw InvalidInputException("weather")
+                    }
+                }
+            }
+
             CommandType.BlockType -> {
                 val value = command.rwString();
                 val pos = getTargetBlockPos(command)
@@ -210,6 +549,88 @@ class Executor(private val responseBuilder: FlatBufferBuilder) {
                 }
             }
 
+            CommandType.BlockProperty -> {
+                val value = command.rwString()
+                val pos = getTargetBlockPos(command)
+                val world = getTargetWorld(command)
+                val name = command.targetProperty ?: throw MissingTargetException()
+                val state = world.getBlockState(pos)
+                val property = state.properties.find { p -> p.name == name } ?: throw InvalidInputException("property")
+
+                // the value type is erased, this only satisfies the type checker and is never relied on
+                @Suppress("UNCHECKED_CAST")
+                val typed = property as Property<Int>
+                if (value == null) {
+                    mkString(propertyValue(state, typed))
+                } else {
+                    val newState = withProperty(state, typed, value.trim().lowercase())
+                        ?: throw InvalidInputException("property value")
+                    world.setBlockState(pos, newState); Unit
+                }
+            }
+
+            CommandType.BlockNbt -> {
+                val value = command.rwString()
+                val pos = getTargetBlockPos(command)
+                val world = getTargetWorld(command)
+                val blockEntity = world.getBlockEntity(pos) ?: throw BadBlockException(pos.toShortString())
+                val current = blockEntity.createNbt()
+                if (value == null) {
+                    mkString(current.toString())
+                } else {
+                    val nbt = try {
+                        StringNbtReader.parse(value)
+                    } catch (e: Exception) {
+                        throw InvalidInputException("nbt")
+                    }
+                    // merged like the data command, so a partial write keeps everything else
+                    blockEntity.readNbt(current.copy().copyFrom(nbt))
+                    blockEntity.markDirty()
+
+                    // let clients see the change, e.g. sign text
+                    val state = world.getBlockState(pos)
+                    world.updateListeners(pos, state, state, Block.NOTIFY_ALL)
+                }
+            }
+
+            CommandType.ChunkLoaded -> {
+                command.ro()
+                val pos = getTargetChunkPos(command)
+                mkBool(getTargetWorld(command).chunkManager.isChunkLoaded(pos.x, pos.z))
+            }
+
+            CommandType.ChunkBiomes -> {
+                command.ro()
+                val chunk = getTargetChunk(command)
+                val lines = chunk.sectionArray.mapIndexed { i, section ->
+                    // biomes are stored per 4x4x4 cell
+                    val counts = HashMap<String, Int>()
+                    for (x in 0 until 4) {
+                        for (y in 0 until 4) {
+                            for (z in 0 until 4) {
+                                val biome = section.getBiome(x, y, z).key.map { it.value.toString() }.orElse("?")
+                                counts.merge(biome, 1, Int::plus)
+                            }
+                        }
+                    }
+
+                    val minY = ChunkSectionPos.getBlockCoord(chunk.sectionIndexToCoord(i))
+                    "$minY ${counts.maxByOrNull { it.value }!!.key}\n"
+                }
+                mkString(lines.joinToString(""))
+            }
+
+            CommandType.ChunkInhabitedTime -> {
+                command.ro()
+                mkInt(getTargetChunk(command).inhabitedTime.toInt())
+            }
+
+            CommandType.ChunkForceLoad -> {
+                val value = command.rwBool() ?: throw UnsupportedOperationException()
+                val pos = getTargetChunkPos(command)
+                getTargetWorld(command).setChunkForced(pos.x, pos.z, value)
+            }
+
             CommandType.ControlSay -> {
                 val value = command.woString()
                 val player = MinecraftClient.getInstance().player ?: throw NoGameException()
@@ -229,10 +650,16 @@ class Executor(private val responseBuilder: FlatBufferBuilder) {
                 val cmd = command.woString()
                 val world = getTargetWorldOpt(command)
                 val server = theServer
-                val player = server.thePlayer
+                // @s is the target entity if there is one, e.g. for effects/add
+                val entity = if (command.targetEntity != null || command.targetPlayerEntity) {
+                    getTargetEntity(command)
+                } else {
+                    server.thePlayer
+                }
                 var src = server.commandSource
-                    .withPosition(player.pos)
-                    .withEntity(player)
+                    .withWorld(entity.world as ServerWorld)
+                    .withPosition(entity.pos)
+                    .withEntity(entity)
                     .withSilent()
 
                 if (world != null) {
@@ -248,6 +675,123 @@ class Executor(private val responseBuilder: FlatBufferBuilder) {
                 }
             }
 
+            CommandType.ServerDifficulty -> {
+                val value = command.rwString()
+                val server = theServer
+                if (value == null) {
+                    val lock = if (server.saveProperties.isDifficultyLocked) "locked" else "unlocked"
+                    mkString("${server.saveProperties.difficulty.getName()} $lock")
+                } else {
+                    val parts = value.trim().split(Regex("\\s+"))
+                    val difficulty = Difficulty.byName(parts[0]) ?: throw InvalidInputException("difficulty")
+                    val locked = when (parts.getOrNull(1)) {
+                        null -> null
+                        "locked" -> true
+                        "unlocked" -> false
+                        else -> throw InvalidInputException("difficulty lock")
+                    }
+
+                    // forced, as writing to the file is explicit enough even if it's locked
+                    server.setDifficulty(difficulty, true)
+                    locked?.let(server::setDifficultyLocked)
+                    Unit
+                }
+            }
+
+            CommandType.ServerPvp -> {
+                val value = command.rwBool()
+                val server = theServer
+                if (value == null) {
+                    mkBool(server.isPvpEnabled)
+                } else {
+                    server.setPvpEnabled(value)
+                }
+            }
+
+            CommandType.ServerGameRule -> {
+                val server = theServer
+                val name = command.targetGamerule ?: throw MissingTargetException()
+                when (val key = GAME_RULES[name] ?: throw InvalidInputException("game rule")) {
+                    is GameRuleKey.BoolRule -> {
+                        val value = command.rwBool()
+                        val rule = server.gameRules.get(key.key)
+                        if (value == null) mkBool(rule.get()) else rule.set(value, server)
+                    }
+                    is GameRuleKey.IntRule -> {
+                        val value = command.rwInt()
+                        val rule = server.gameRules.get(key.key)
+                        if (value == null) mkInt(rule.get()) else rule.set(value, server)
+                    }
+                }
+            }
+
+            CommandType.ScoreboardObjectiveAdd -> {
+                val name = command.woString().trim()
+                val scoreboard = theServer.scoreboard
+                if (name.length > 16 || !TAG_REGEX.matches(name) || scoreboard.containsObjective(name)) {
+                    throw InvalidInputException("objective")
+                }
+
+                val criterion = ScoreboardCriterion.DUMMY
+                scoreboard.addObjective(name, criterion, LiteralText(name), criterion.defaultRenderType)
+                Unit
+            }
+
+            CommandType.ScoreboardObjectiveCriteria -> {
+                command.ro()
+                mkString(getTargetObjective(command).criterion.name)
+            }
+
+            CommandType.ScoreboardObjectiveDisplayName -> {
+                val value = command.rwString()
+                val objective = getTargetObjective(command)
+                if (value == null) {
+                    mkString(Text.Serializer.toJson(objective.displayName))
+                } else {
+                    objective.displayName = try {
+                        Text.Serializer.fromJson(value)
+                    } catch (e: Exception) {
+                        null
+                    } ?: throw InvalidInputException("display name")
+                }
+            }
+
+            CommandType.ScoreboardObjectiveDisplaySlot -> {
+                val value = command.rwString()
+                val scoreboard = theServer.scoreboard
+                val objective = getTargetObjective(command)
+                val shownIn = Scoreboard.getDisplaySlotNames().indices.filter { slot ->
+                    scoreboard.getObjectiveForSlot(slot) == objective
+                }
+
+                if (value == null) {
+                    mkString(shownIn.joinToString("") { slot -> "${Scoreboard.getDisplaySlotName(slot)}\n" })
+                } else if (value.isBlank()) {
+                    shownIn.forEach { slot -> scoreboard.setObjectiveSlot(slot, null) }
+                } else {
+                    val slot = Scoreboard.getDisplaySlotId(value.trim())
+                    if (slot < 0) throw InvalidInputException("display slot")
+                    scoreboard.setObjectiveSlot(slot, objective)
+                }
+            }
+
+            CommandType.ScoreboardObjectiveRemove -> {
+                theServer.scoreboard.removeObjective(getTargetObjective(command))
+            }
+
+            CommandType.ScoreboardScore -> {
+                val value = command.rwInt()
+                val scoreboard = theServer.scoreboard
+                val objective = getTargetObjective(command)
+                val holder = command.targetScoreHolder ?: throw MissingTargetException()
+                if (value == null) {
+                    if (!scoreboard.playerHasObjective(holder, objective)) throw InvalidInputException("score holder")
+                    mkInt(scoreboard.getPlayerScore(holder, objective).score)
+                } else {
+                    scoreboard.getPlayerScore(holder, objective).score = value
+                }
+            }
+
             else -> {
                 MinecraftFsMod.LOGGER.warn("Unknown command '$command'")
                 mkError(Error.UnknownCommand)
@@ -266,29 +810,214 @@ class Executor(private val responseBuilder: FlatBufferBuilder) {
         val entities = if (world != null && req.entitiesById) {
             val bounds = -100_000.0
             val box = Box(Vec3d(-bounds, -bounds, -bounds), Vec3d(bounds, bounds, bounds))
+            val near = req.entitiesNear?.let { Vec3d(it.x, it.y, it.z) }
+            val radius = req.entitiesRadius
             val entities = world.getOtherEntities(null, box)
                 .filter { e -> e.isAlive }
+                .filter { e -> near == null || radius == null || e.squaredDistanceTo(near) <= radius * radius }
+
+            // tables can't be nested, so build them all before the vector
+            val offsets = entities.map { e ->
+                val ty = responseBuilder.createString(Registry.ENTITY_TYPE.getId(e.type).toString())
+                val passengers = if (e.hasPassengers()) {
+                    EntityDetails.createPassengersVector(responseBuilder, e.passengerList.map { p -> p.id }.toIntArray())
+                } else {
+                    null
+                }
+                EntityDetails.startEntityDetails(responseBuilder)
+                EntityDetails.addId(responseBuilder, e.id)
+                EntityDetails.addLiving(responseBuilder, e.isLiving)
+                EntityDetails.addTy(responseBuilder, ty)
+                EntityDetails.addPos(responseBuilder, MCFS.Vec3.createVec3(responseBuilder, e.x, e.y, e.z))
+                e.vehicle?.let { v -> EntityDetails.addVehicle(responseBuilder, v.id) }
+                passengers?.let { EntityDetails.addPassengers(responseBuilder, it) }
+                EntityDetails.endEntityDetails(responseBuilder)
+            }
+
+            StateResponse.createEntitiesVector(responseBuilder, offsets.toIntArray())
+        } else {
+            null
+        }
+
+        val inventory = if (req.inventory) {
+            val targetId = req.targetEntity
+            val entity = if (targetId != null) world?.getEntityById(targetId) else if (req.targetPlayerEntity) player else null
+            (entity as? LivingEntity)?.let(this::mkInventoryDetails)
+        } else {
+            null
+        }
+
+        val effects = if (req.effects) {
+            val targetId = req.targetEntity
+            val entity = if (targetId != null) world?.getEntityById(targetId) else if (req.targetPlayerEntity) player else null
+            (entity as? LivingEntity)?.let {
+                val offsets = it.statusEffects.map { effect ->
+                    responseBuilder.createString(Registry.STATUS_EFFECT.getId(effect.effectType).toString())
+                }
+                StateResponse.createEffectsVector(responseBuilder, offsets.toIntArray())
+            }
+        } else {
+            null
+        }
 
-            StateResponse.startEntitiesVector(responseBuilder, entities.size)
-            for (e in entities) {
-                EntityDetails.createEntityDetails(responseBuilder, e.id, e.isLiving)
+        val attributes = if (req.attributes) {
+            val targetId = req.targetEntity
+            val entity = if (targetId != null) world?.getEntityById(targetId) else if (req.targetPlayerEntity) player else null
+            (entity as? LivingEntity)?.let {
+                val offsets = Registry.ATTRIBUTE
+                    .filter { attribute -> it.attributes.hasAttribute(attribute) }
+                    .map { attribute -> responseBuilder.createString(Registry.ATTRIBUTE.getId(attribute).toString()) }
+                StateResponse.createAttributesVector(responseBuilder, offsets.toIntArray())
             }
-            responseBuilder.endVector()
         } else {
             null
         }
 
         val block = if (world != null && req.targetBlock != null) {
             val tgt = req.targetBlock!!
-            // val state = world.getBlockState(BlockPos(tgt.x, tgt.y, tgt.z))
+            val state = world.getBlockState(BlockPos(tgt.x, tgt.y, tgt.z))
+            val properties = state.properties.map { p -> responseBuilder.createString(p.name) }.toIntArray()
+            val propertiesVec = BlockDetails.createPropertiesVector(responseBuilder, properties)
 
             BlockDetails.startBlockDetails(responseBuilder)
             BlockDetails.addPos(responseBuilder, MCFS.BlockPos.createBlockPos(responseBuilder, tgt.x, tgt.y, tgt.z))
+            BlockDetails.addProperties(responseBuilder, propertiesVec)
+            val blockEntity = world.getBlockEntity(BlockPos(tgt.x, tgt.y, tgt.z))
+            BlockDetails.addHasBlockEntity(responseBuilder, blockEntity != null)
+            (blockEntity as? Inventory)?.let { BlockDetails.addContainerSize(responseBuilder, it.size()) }
             BlockDetails.endBlockDetails(responseBuilder)
         } else {
             null
         }
 
+        val regionFrom = req.regionFrom
+        val regionTo = req.regionTo
+        val region = if (world != null && regionFrom != null && regionTo != null) {
+            // palette in order of first appearance, blocks ordered by y, then z, then x
+            val palette = LinkedHashMap<BlockState, Int>()
+            val blocks = ArrayList<UShort>()
+            val pos = BlockPos.Mutable()
+            for (y in regionFrom.y..regionTo.y) {
+                for (z in regionFrom.z..regionTo.z) {
+                    for (x in regionFrom.x..regionTo.x) {
+                        val state = world.getBlockState(pos.set(x, y, z))
+                        blocks.add(palette.getOrPut(state) { palette.size }.toUShort())
+                    }
+                }
+            }
+
+            val paletteOffsets = palette.keys.map { state ->
+                responseBuilder.createString(blockStateString(state))
+            }.toIntArray()
+            val paletteVec = RegionDetails.createPaletteVector(responseBuilder, paletteOffsets)
+            val blocksVec = RegionDetails.createBlocksVector(responseBuilder, blocks.toUShortArray())
+
+            RegionDetails.startRegionDetails(responseBuilder)
+            RegionDetails.addFrom(
+                responseBuilder,
+                MCFS.BlockPos.createBlockPos(responseBuilder, regionFrom.x, regionFrom.y, regionFrom.z)
+            )
+            RegionDetails.addTo(
+                responseBuilder,
+                MCFS.BlockPos.createBlockPos(responseBuilder, regionTo.x, regionTo.y, regionTo.z)
+            )
+            RegionDetails.addPalette(responseBuilder, paletteVec)
+            RegionDetails.addBlocks(responseBuilder, blocksVec)
+            RegionDetails.endRegionDetails(responseBuilder)
+        } else {
+            null
+        }
+
+        val columnsFrom = req.columnsFrom
+        val columnsTo = req.columnsTo
+        val columns = if (world != null && columnsFrom != null && columnsTo != null) {
+            // palette in order of first appearance, columns ordered by z, then x
+            val palette = LinkedHashMap<String, Int>()
+            val tops = ArrayList<UShort>()
+            val heights = ArrayList<Short>()
+            val pos = BlockPos.Mutable()
+            for (z in columnsFrom.z..columnsTo.z) {
+                for (x in columnsFrom.x..columnsTo.x) {
+                    // the first free block above the top one, loading the chunk if needed
+                    val y = world.getTopY(Heightmap.Type.WORLD_SURFACE, x, z) - 1
+                    val id = Registry.BLOCK.getId(world.getBlockState(pos.set(x, y, z)).block).toString()
+                    tops.add(palette.getOrPut(id) { palette.size }.toUShort())
+                    heights.add(y.toShort())
+                }
+            }
+
+            val paletteOffsets = palette.keys.map { id -> responseBuilder.createString(id) }.toIntArray()
+            val paletteVec = ColumnDetails.createPaletteVector(responseBuilder, paletteOffsets)
+            val topsVec = ColumnDetails.createTopsVector(responseBuilder, tops.toUShortArray())
+            val heightsVec = ColumnDetails.createHeightsVector(responseBuilder, heights.toShortArray())
+
+            ColumnDetails.startColumnDetails(responseBuilder)
+            ColumnDetails.addFrom(responseBuilder, ColumnPos.createColumnPos(responseBuilder, columnsFrom.x, columnsFrom.z))
+            ColumnDetails.addTo(responseBuilder, ColumnPos.createColumnPos(responseBuilder, columnsTo.x, columnsTo.z))
+            ColumnDetails.addPalette(responseBuilder, paletteVec)
+            ColumnDetails.addTops(responseBuilder, topsVec)
+            ColumnDetails.addHeights(responseBuilder, heightsVec)
+            ColumnDetails.endColumnDetails(responseBuilder)
+        } else {
+            null
+        }
+
+        val chunks = if (world != null && req.chunks) {
+            // loaded chunks aren't exposed directly, so check those that could be loaded by a player or
+            // by being forced
+            val viewDistance = world.server.playerManager.viewDistance
+            val candidates = HashSet<ChunkPos>()
+            for (p in world.players) {
+                val centre = p.chunkPos
+                for (x in -viewDistance..viewDistance) {
+                    for (z in -viewDistance..viewDistance) {
+                        candidates.add(ChunkPos(centre.x + x, centre.z + z))
+                    }
+                }
+            }
+            world.forcedChunks.forEach { candidates.add(ChunkPos(it)) }
+
+            val entities = world.iterateEntities().groupBy({ e -> e.chunkPos }, { e -> e.id })
+            val offsets = candidates
+                .filter { pos -> world.chunkManager.isChunkLoaded(pos.x, pos.z) }
+                .map { pos ->
+                    val ids = entities[pos]?.toIntArray() ?: IntArray(0)
+                    val idsVec = ChunkDetails.createEntitiesVector(responseBuilder, ids)
+                    ChunkDetails.createChunkDetails(responseBuilder, pos.x, pos.z, idsVec)
+                }
+            StateResponse.createChunksVector(responseBuilder, offsets.toIntArray())
+        } else {
+            null
+        }
+
+        val gamerules = if (req.gamerules) {
+            val offsets = GAME_RULES.map { (name, key) ->
+                val nameOffset = responseBuilder.createString(name)
+                val ty = when (key) {
+                    is GameRuleKey.BoolRule -> GameRuleType.Bool
+                    is GameRuleKey.IntRule -> GameRuleType.Int
+                }
+                GameRuleDetails.createGameRuleDetails(responseBuilder, nameOffset, ty)
+            }
+            StateResponse.createGamerulesVector(responseBuilder, offsets.toIntArray())
+        } else {
+            null
+        }
+
+        val objectives = if (server != null && req.objectives) {
+            val scoreboard = server.scoreboard
+            val offsets = scoreboard.objectives.map { objective ->
+                val nameOffset = responseBuilder.createString(objective.name)
+                val holders = scoreboard.getAllPlayerScores(objective)
+                    .map { score -> responseBuilder.createString(score.playerName) }
+                val holdersVec = ObjectiveDetails.createHoldersVector(responseBuilder, holders.toIntArray())
+                ObjectiveDetails.createObjectiveDetails(responseBuilder, nameOffset, holdersVec)
+            }
+            StateResponse.createObjectivesVector(responseBuilder, offsets.toIntArray())
+        } else {
+            null
+        }
+
         StateResponse.startStateResponse(responseBuilder)
         if (player != null) {
             StateResponse.addPlayerEntityId(responseBuilder, player.id)
@@ -310,6 +1039,38 @@ class Executor(private val responseBuilder: FlatBufferBuilder) {
             StateResponse.addBlock(responseBuilder, block)
         }
 
+        if (inventory != null) {
+            StateResponse.addInventory(responseBuilder, inventory)
+        }
+
+        if (effects != null) {
+            StateResponse.addEffects(responseBuilder, effects)
+        }
+
+        if (attributes != null) {
+            StateResponse.addAttributes(responseBuilder, attributes)
+        }
+
+        if (gamerules != null) {
+            StateResponse.addGamerules(responseBuilder, gamerules)
+        }
+
+        if (region != null) {
+            StateResponse.addRegion(responseBuilder, region)
+        }
+
+        if (columns != null) {
+            StateResponse.addColumns(responseBuilder, columns)
+        }
+
+        if (chunks != null) {
+            StateResponse.addChunks(responseBuilder, chunks)
+        }
+
+        if (objectives != null) {
+            StateResponse.addObjectives(responseBuilder, objectives)
+        }
+
         return StateResponse.endStateResponse(responseBuilder)
     }
 
@@ -355,6 +1116,12 @@ class Executor(private val responseBuilder: FlatBufferBuilder) {
         return Response.endResponse(responseBuilder)
     }
 
+    private fun mkBool(bool: Boolean): Int {
+        Response.startResponse(responseBuilder)
+        Response.addBool(responseBuilder, bool)
+        return Response.endResponse(responseBuilder)
+    }
+
     private fun mkPosition(pos: Vec3d): Int {
         val v = Vec3.createVec3(responseBuilder, pos.x, pos.y, pos.z)
         Response.startResponse(responseBuilder)
@@ -362,6 +1129,37 @@ class Executor(private val responseBuilder: FlatBufferBuilder) {
         return Response.endResponse(responseBuilder)
     }
 
+    private fun mkRotation(yaw: Float, pitch: Float): Int {
+        val r = Rotation.createRotation(responseBuilder, yaw, pitch)
+        Response.startResponse(responseBuilder)
+        Response.addRotation(responseBuilder, r)
+        return Response.endResponse(responseBuilder)
+    }
+
+    /** e.g. minecraft:oak_stairs[facing=east,half=bottom] */
+    private fun blockStateString(state: BlockState): String {
+        val id = Registry.BLOCK.getId(state.block).toString()
+        if (state.properties.isEmpty()) {
+            return id
+        }
+
+        // the value type is erased, as for BlockProperty
+        @Suppress("UNCHECKED_CAST")
+        val properties = state.properties.joinToString(",") { p ->
+            "${p.name}=${propertyValue(state, p as Property<Int>)}"
+        }
+        return "$id[$properties]"
+    }
+
+    private fun <T : Comparable<T>> propertyValue(state: BlockState, property: Property<T>): String =
+        property.name(state.get(property))
+
+    /**
+     * Null if the value isn't valid for the property. Other properties are kept as they are
+     */
+    private fun <T : Comparable<T>> withProperty(state: BlockState, property: Property<T>, value: String): BlockState? =
+        property.parse(value).map { v -> state.with(property, v) }.orElse(null)
+
     private fun getTargetEntity(command: Command): Entity {
         val id = command.targetEntity
         return if (id != null) {
@@ -378,6 +1176,107 @@ class Executor(private val responseBuilder: FlatBufferBuilder) {
         return getTargetEntity(command) as? LivingEntity? ?: throw NotLivingException()
     }
 
+    /**
+     * The target entity's inventory if there is one, otherwise the target container block's
+     */
+    private fun getTargetEffect(command: Command): StatusEffectInstance {
+        val name = command.targetEffect ?: throw MissingTargetException()
+        val effect = Identifier.tryParse(name)?.let { Registry.STATUS_EFFECT.get(it) }
+            ?: throw InvalidInputException("effect")
+        return getTargetLivingEntity(command).getStatusEffect(effect) ?: throw InvalidInputException("effect")
+    }
+
+    private fun getTargetAttribute(command: Command): EntityAttributeInstance {
+        val name = command.targetAttribute ?: throw MissingTargetException()
+        val attribute = Identifier.tryParse(name)?.let { Registry.ATTRIBUTE.get(it) }
+            ?: throw InvalidInputException("attribute")
+        return getTargetLivingEntity(command).getAttributeInstance(attribute) ?: throw InvalidInputException("attribute")
+    }
+
+    private fun getTargetObjective(command: Command): ScoreboardObjective {
+        val name = command.targetObjective ?: throw MissingTargetException()
+        return theServer.scoreboard.getNullableObjective(name) ?: throw InvalidInputException("objective")
+    }
+
+    private fun getTargetSlot(command: Command): Pair<SlotOwner, Int> {
+        val owner = if (command.targetEntity == null && !command.targetPlayerEntity) {
+            val pos = getTargetBlockPos(command)
+            val inventory = getTargetWorld(command).getBlockEntity(pos) as? Inventory
+                ?: throw BadBlockException(pos.toShortString())
+            SlotOwner.Container(inventory)
+        } else {
+            val entity = getTargetLivingEntity(command)
+            if (entity is PlayerEntity) SlotOwner.Container(entity.inventory) else SlotOwner.Equipment(entity)
+        }
+
+        val slot = command.targetSlot ?: throw MissingTargetException()
+        if (slot < 0 || slot >= owner.size) throw InvalidInputException("slot")
+        return Pair(owner, slot)
+    }
+
+    /**
+     * Players and containers expose their whole inventory, other living entities only their equipment in
+     * [EQUIPMENT_SLOTS] order
+     */
+    private sealed class SlotOwner {
+        abstract val size: Int
+        abstract fun getStack(slot: Int): ItemStack
+        abstract fun setStack(slot: Int, stack: ItemStack)
+
+        class Container(private val inventory: Inventory) : SlotOwner() {
+            override val size: Int
+                get() = inventory.size()
+
+            override fun getStack(slot: Int): ItemStack = inventory.getStack(slot)
+
+            override fun setStack(slot: Int, stack: ItemStack) {
+                inventory.setStack(slot, stack)
+                inventory.markDirty()
+            }
+        }
+
+        class Equipment(private val entity: LivingEntity) : SlotOwner() {
+            override val size: Int
+                get() = EQUIPMENT_SLOTS.size
+
+            override fun getStack(slot: Int): ItemStack = entity.getEquippedStack(EQUIPMENT_SLOTS[slot])
+
+            override fun setStack(slot: Int, stack: ItemStack) = entity.equipStack(EQUIPMENT_SLOTS[slot], stack)
+        }
+    }
+
+    private fun mkInventoryDetails(entity: LivingEntity): Int {
+        return if (entity is PlayerEntity) {
+            val inv = entity.inventory
+            // armour is stored after the 36 main slots, from feet up
+            val armour = inv.main.size
+            InventoryDetails.createInventoryDetails(
+                responseBuilder,
+                size = inv.size(),
+                selected = inv.selectedSlot,
+                mainhand = inv.selectedSlot,
+                offhand = armour + inv.armor.size,
+                head = armour + 3,
+                chest = armour + 2,
+                legs = armour + 1,
+                feet = armour,
+            )
+        } else {
+            fun slot(s: EquipmentSlot) = EQUIPMENT_SLOTS.indexOf(s)
+            InventoryDetails.createInventoryDetails(
+                responseBuilder,
+                size = EQUIPMENT_SLOTS.size,
+                selected = null,
+                mainhand = slot(EquipmentSlot.MAINHAND),
+                offhand = slot(EquipmentSlot.OFFHAND),
+                head = slot(EquipmentSlot.HEAD),
+                chest = slot(EquipmentSlot.CHEST),
+                legs = slot(EquipmentSlot.LEGS),
+                feet = slot(EquipmentSlot.FEET),
+            )
+        }
+    }
+
     private fun getTargetWorld(command: Command): ServerWorld {
         return getTargetWorldOpt(command) ?: throw MissingTargetException()
     }
@@ -393,7 +1292,19 @@ class Executor(private val responseBuilder: FlatBufferBuilder) {
         return BlockPos(block.x, block.y, block.z)
     }
 
-    private fun resolveWorld(dim: UByte): ServerWorld? {
+    private fun getTargetChunkPos(command: Command): ChunkPos {
+        val chunk = command.targetChunk ?: throw MissingTargetException()
+        return ChunkPos(chunk.x, chunk.z)
+    }
+
+    /** Only if already loaded */
+    private fun getTargetChunk(command: Command): WorldChunk {
+        val pos = getTargetChunkPos(command)
+        return getTargetWorld(command).chunkManager.getWorldChunk(pos.x, pos.z, false)
+            ?: throw UnloadedChunkException(pos)
+    }
+
+    private fun resolveWorld(dim: UByte): ServerWorld? {: ServerWorld? {
         val server = theServer
         return when (dim) {
             Dimension.Overworld -> server.getWorld(World.OVERWORLD)
@@ -426,6 +1337,15 @@ class Executor(private val responseBuilder: FlatBufferBuilder) {
         }
     }
 
+    private fun Command.rwBool(): Boolean? {
+        val writeBody = this.write
+        return if (writeBody != null) {
+            writeBody.bool ?: throw InvalidTypeForWriteException()
+        } else {
+            null
+        }
+    }
+
     private fun Command.rwString(): String? {
         val writeBody = this.write
         return if (writeBody != null) {
@@ -445,6 +1365,16 @@ class Executor(private val responseBuilder: FlatBufferBuilder) {
         }
     }
 
+    private fun Command.rwRotation(): Pair<Float, Float>? {
+        val writeBody = this.write
+        return if (writeBody != null) {
+            val rot = writeBody.rotation ?: throw InvalidTypeForWriteException()
+            Pair(rot.yaw, rot.pitch)
+        } else {
+            null
+        }
+    }
+
     private fun Command.woString(): String {
         val writeBody = this.write ?: throw UnsupportedOperationException()
         return writeBody.string ?: throw InvalidTypeForWriteException()
@@ -455,4 +1385,42 @@ class Executor(private val responseBuilder: FlatBufferBuilder) {
         val vec = writeBody.vec ?: throw InvalidTypeForWriteException()
         return Vec3d(vec.x, vec.y, vec.z)
     }
-}
\ No newline at end of file
+
+    private sealed class GameRuleKey {
+        class BoolRule(val key: GameRules.Key<GameRules.BooleanRule>) : GameRuleKey()
+        class IntRule(val key: GameRules.Key<GameRules.IntRule>) : GameRuleKey()
+    }
+
+    companion object {
+        /** Same as the weather command */
+        private const val DEFAULT_WEATHER_SECONDS = 300
+
+        /** All registered game rules by name, e.g. doDaylightCycle */
+        private val GAME_RULES: Map<String, GameRuleKey> by lazy {
+            val rules = sortedMapOf<String, GameRuleKey>()
+            GameRules.accept(object : GameRules.Visitor {
+                override fun visitBoolean(key: GameRules.Key<GameRules.BooleanRule>, type: GameRules.Type<GameRules.BooleanRule>) {
+                    rules[key.name] = GameRuleKey.BoolRule(key)
+                }
+
+                override fun visitInt(key: GameRules.Key<GameRules.IntRule>, type: GameRules.Type<GameRules.IntRule>) {
+                    rules[key.name] = GameRuleKey.IntRule(key)
+                }
+            })
+            rules
+        }
+
+        /** Characters allowed in scoreboard tags by the tag command */
+        private val TAG_REGEX = Regex("[0-9A-Za-z_\\-.+]+")
+
+        /** Slot order of non-player entity inventories */
+        private val EQUIPMENT_SLOTS = arrayOf(
+            EquipmentSlot.MAINHAND,
+            EquipmentSlot.OFFHAND,
+            EquipmentSlot.FEET,
+            EquipmentSlot.LEGS,
+            EquipmentSlot.CHEST,
+            EquipmentSlot.HEAD,
+        )
+    }
+}
@@ -0,0 +1,111 @@
+package ms.domwillia.mcfs.ipc
+
+import MCFS.Dimension
+import MCFS.Event
+import MCFS.EventType
+import MCFS.GameResponse
+import MCFS.GameResponseBody
+import com.google.flatbuffers.FlatBufferBuilder
+import net.fabricmc.fabric.api.entity.event.v1.ServerEntityWorldChangeEvents
+import net.fabricmc.fabric.api.event.lifecycle.v1.ServerEntityEvents
+import net.fabricmc.fabric.api.event.player.PlayerBlockBreakEvents
+import net.minecraft.util.math.BlockPos
+import net.minecraft.util.registry.Registry
+import net.minecraft.world.World
+import java.nio.ByteBuffer
+import java.util.concurrent.LinkedBlockingQueue
+import java.util.concurrent.TimeUnit
+
+class PendingEvent(
+    val ty: Int,
+    val world: UByte?,
+    val entity: Int?,
+    val block: BlockPos?,
+    val message: String?,
+    val amount: Float?,
+)
+
+/**
+ * Events are only queued while the filesystem is subscribed, and are pushed to it by the IPC channel
+ */
+object GameEvents {
+    private const val MAX_QUEUED = 1024
+
+    @Volatile
+    var subscribed = false
+        set(value) {
+            field = value
+            if (!value) {
+                queue.clear()
+            }
+        }
+
+    private val queue = LinkedBlockingQueue<PendingEvent>(MAX_QUEUED)
+
+    fun register() {
+        ServerEntityEvents.ENTITY_LOAD.register { entity, world ->
+            push(EventType.EntitySpawn, world, entity.id, message = entityType(entity.type))
+        }
+        ServerEntityEvents.ENTITY_UNLOAD.register { entity, world ->
+            push(EventType.EntityDespawn, world, entity.id, message = entityType(entity.type))
+        }
+        ServerEntityWorldChangeEvents.AFTER_PLAYER_CHANGE_WORLD.register { player, _, destination ->
+            push(EventType.DimensionChange, destination, player.id)
+        }
+        PlayerBlockBreakEvents.AFTER.register { world, player, pos, state, _ ->
+            push(EventType.BlockBreak, world, player.id, pos, Registry.BLOCK.getId(state.block).toString())
+        }
+    }
+
+    @JvmStatic
+    @JvmOverloads
+    fun push(
+        ty: Int,
+        world: World?,
+        entity: Int? = null,
+        block: BlockPos? = null,
+        message: String? = null,
+        amount: Float? = null
+    ) {
+        if (!subscribed || world?.isClient == true) {
+            return
+        }
+
+        // drop new events if nothing is reading them
+        queue.offer(PendingEvent(ty, world?.let(::dimension), entity, block?.toImmutable(), message, amount))
+    }
+
+    /**
+     * Blocks for up to the given timeout for the next event, serialising it as a [GameResponse]
+     */
+    fun next(builder: FlatBufferBuilder, timeoutMillis: Long): ByteBuffer? {
+        val event = queue.poll(timeoutMillis, TimeUnit.MILLISECONDS) ?: return null
+
+        builder.clear()
+        // optional scalars would otherwise be dropped if they match the default of 0
+        builder.forceDefaults(true)
+
+        val message = event.message?.let(builder::createString)
+        Event.startEvent(builder)
+        Event.addTy(builder, event.ty)
+        event.world?.let { Event.addWorld(builder, it) }
+        event.entity?.let { Event.addEntity(builder, it) }
+        event.block?.let { Event.addBlock(builder, MCFS.BlockPos.createBlockPos(builder, it.x, it.y, it.z)) }
+        message?.let { Event.addMessage(builder, it) }
+        event.amount?.let { Event.addAmount(builder, it) }
+        val body = Event.endEvent(builder)
+
+        val resp = GameResponse.createGameResponse(builder, GameResponseBody.Event, body)
+        builder.finish(resp)
+        return builder.dataBuffer()
+    }
+
+    private fun dimension(world: World): UByte? = when (world.registryKey) {
+        World.OVERWORLD -> Dimension.Overworld
+        World.NETHER -> Dimension.Nether
+        World.END -> Dimension.End
+        else -> null
+    }
+
+    private fun entityType(ty: net.minecraft.entity.EntityType<*>): String = Registry.ENTITY_TYPE.getId(ty).toString()
+}
@@ -18,22 +18,54 @@ class IpcChannel : Runnable {
     private val channel: ServerSocketChannel
     private val keepRunning = AtomicBoolean(true)
 
+    /** Current connection, written to by both the request loop and the event pusher */
+    @Volatile
+    private var client: SocketChannel? = null
+    private val writeLock = Any()
+
     @Throws(IOException::class)
     fun close() {
         channel.close()
         keepRunning.set(false)
     }
 
+    /**
+     * Pushes queued events to the current client in between responses
+     */
+    private fun pushEvents() {
+        val lenBuf = ByteBuffer.allocate(4).order(ByteOrder.LITTLE_ENDIAN)
+        val builder = FlatBufferBuilder(1024)
+        while (keepRunning.get()) {
+            val event = GameEvents.next(builder, 100) ?: continue
+            val client = client ?: continue
+
+            try {
+                synchronized(writeLock) {
+                    client.write(arrayOf(lenBuf.clear().putInt(event.remaining()).flip(), event))
+                }
+            } catch (e: IOException) {
+                MinecraftFsMod.LOGGER.catching(e)
+            }
+        }
+    }
+
     @ExperimentalUnsignedTypes
     override fun run() {
+        val pusher = Thread(this::pushEvents)
+        pusher.isDaemon = true
+        pusher.start()
+
         val lenBuf = ByteBuffer.wrap(ByteArray(4)).order(ByteOrder.LITTLE_ENDIAN)
         val buf = ByteBuffer.allocate(8192)
         val responseBuilder = FlatBufferBuilder(8192)
+        // optional scalars would otherwise be dropped if they match the default of 0
+        responseBuilder.forceDefaults(true)
         val executor = Executor(responseBuilder)
         while (keepRunning.get()) {
             var client: SocketChannel? = null
             try {
                 client = channel.accept()
+                this.client = client
 
                 while (true) {
                     // read len
@@ -66,10 +98,14 @@ class IpcChannel : Runnable {
 
                     // log bytes
                     // MinecraftFsMod.LOGGER.info(buf.array().copyOf(responseSize).joinToString() { b -> "%02x".format(b) })
-                    client.write(buf)
+                    synchronized(writeLock) {
+                        client.write(buf)
+                    }
                 }
             } catch (e: Exception) {
                 MinecraftFsMod.LOGGER.catching(e)
+                this.client = null
+                GameEvents.subscribed = false
                 if (client != null) {
                     try {
                         client.close()
@@ -0,0 +1,30 @@
+package ms.domwillia.mcfs.mcfs.mixin;
+
+import MCFS.EventType;
+import ms.domwillia.mcfs.ipc.GameEvents;
+import net.minecraft.item.BlockItem;
+import net.minecraft.item.ItemPlacementContext;
+import net.minecraft.util.ActionResult;
+import net.minecraft.util.math.BlockPos;
+import net.minecraft.util.registry.Registry;
+import net.minecraft.world.World;
+import org.spongepowered.asm.mixin.Mixin;
+import org.spongepowered.asm.mixin.injection.At;
+import org.spongepowered.asm.mixin.injection.Inject;
+import org.spongepowered.asm.mixin.injection.callback.CallbackInfoReturnable;
+
+@Mixin(BlockItem.class)
+public abstract class BlockItemMixin {
+    @Inject(method = "place(Lnet/minecraft/item/ItemPlacementContext;)Lnet/minecraft/util/ActionResult;", at = @At("RETURN"))
+    private void onPlace(ItemPlacementContext context, CallbackInfoReturnable<ActionResult> cir) {
+        if (!cir.getReturnValue().isAccepted()) {
+            return;
+        }
+
+        World world = context.getWorld();
+        BlockPos pos = context.getBlockPos();
+        Integer player = context.getPlayer() != null ? context.getPlayer().getId() : null;
+        String block = Registry.BLOCK.getId(world.getBlockState(pos).getBlock()).toString();
+        GameEvents.push(EventType.BlockPlace, world, player, pos, block);
+    }
+}
@@ -0,0 +1,28 @@
+package ms.domwillia.mcfs.mcfs.mixin;
+
+import MCFS.EventType;
+import ms.domwillia.mcfs.ipc.GameEvents;
+import net.minecraft.entity.LivingEntity;
+import net.minecraft.entity.damage.DamageSource;
+import org.spongepowered.asm.mixin.Mixin;
+import org.spongepowered.asm.mixin.injection.At;
+import org.spongepowered.asm.mixin.injection.Inject;
+import org.spongepowered.asm.mixin.injection.callback.CallbackInfo;
+import org.spongepowered.asm.mixin.injection.callback.CallbackInfoReturnable;
+
+@Mixin(LivingEntity.class)
+public abstract class LivingEntityMixin {
+    @Inject(method = "damage", at = @At("RETURN"))
+    private void onDamage(DamageSource source, float amount, CallbackInfoReturnable<Boolean> cir) {
+        if (cir.getReturnValueZ()) {
+            LivingEntity self = (LivingEntity) (Object) this;
+            GameEvents.push(EventType.Damage, self.world, self.getId(), null, source.getName(), amount);
+        }
+    }
+
+    @Inject(method = "onDeath", at = @At("HEAD"))
+    private void onDeath(DamageSource source, CallbackInfo ci) {
+        LivingEntity self = (LivingEntity) (Object) this;
+        GameEvents.push(EventType.Death, self.world, self.getId(), null, source.getName());
+    }
+}
@@ -0,0 +1,27 @@
+package ms.domwillia.mcfs.mcfs.mixin;
+
+import MCFS.EventType;
+import ms.domwillia.mcfs.ipc.GameEvents;
+import net.minecraft.server.filter.TextStream;
+import net.minecraft.server.network.ServerPlayNetworkHandler;
+import net.minecraft.server.network.ServerPlayerEntity;
+import org.spongepowered.asm.mixin.Mixin;
+import org.spongepowered.asm.mixin.Shadow;
+import org.spongepowered.asm.mixin.injection.At;
+import org.spongepowered.asm.mixin.injection.Inject;
+import org.spongepowered.asm.mixin.injection.callback.CallbackInfo;
+
+@Mixin(ServerPlayNetworkHandler.class)
+public abstract class ServerPlayNetworkHandlerMixin {
+    @Shadow
+    public ServerPlayerEntity player;
+
+    @Inject(method = "handleMessage", at = @At("HEAD"))
+    private void onChat(TextStream.Message message, CallbackInfo ci) {
+        String raw = message.getRaw();
+        // commands aren't chat
+        if (!raw.startsWith("/")) {
+            GameEvents.push(EventType.Chat, player.world, player.getId(), null, raw);
+        }
+    }
+}
@@ -1,15 +1,19 @@
 use std::borrow::Cow;
 use std::error::Error as StdError;
 use std::iter::once;
+use std::time::{Duration, Instant};
 
 use flatbuffers::FlatBufferBuilder;
 use log::*;
 use rand::{thread_rng, Rng};
 
 use ipc::generated::{
-    BlockDetails, BlockDetailsArgs, BlockPos, Command, CommandType, Dimension, EntityDetails,
-    Error, GameResponse, GameResponseArgs, GameResponseBody, Response, ResponseArgs, StateResponse,
-    StateResponseArgs, Vec3,
+    BlockDetails, BlockDetailsArgs, BlockPos, ChunkDetails, ChunkDetailsArgs, ColumnDetails,
+    ColumnDetailsArgs, ColumnPos, Command, CommandType, Dimension, EntityDetails,
+    EntityDetailsArgs, Error, Event, EventArgs, EventType, GameResponse, GameResponseArgs,
+    GameResponseBody, GameRuleDetails, GameRuleDetailsArgs, GameRuleType, InventoryDetails,
+    InventoryDetailsArgs, ObjectiveDetails, ObjectiveDetailsArgs, RegionDetails, RegionDetailsArgs,
+    Response, ResponseArgs, Rotation, StateResponse, StateResponseArgs, Vec3,
 };
 use ipc::{ConnectedIpcClient, IpcClient, IpcError};
 
@@ -30,13 +34,111 @@ enum ClientCommandResponse {
     Int(i32),
     String(Cow<'static, str>),
     Vec(Vec3),
+    Rotation(Rotation),
+    Bool(bool),
 }
 
 enum ClientResponse {
     Command(Option<ClientCommandResponse>),
-    State { target_block: Option<BlockPos> },
+    State {
+        target_block: Option<BlockPos>,
+        /// Some(is player) if requested
+        inventory: Option<bool>,
+        effects: bool,
+        attributes: bool,
+        /// (centre, radius) if requested
+        entities_near: Option<(Vec3, f32)>,
+        gamerules: bool,
+        /// (from, to) if requested
+        region: Option<(BlockPos, BlockPos)>,
+        /// (from, to) if requested
+        columns: Option<(ColumnPos, ColumnPos)>,
+        chunks: bool,
+        objectives: bool,
+    },
 }
 
+const FAKE_CHEST_SIZE: i32 = 27;
+
+/// Blocks at even x coordinates also have a block entity with an inventory, like a chest
+fn is_fake_chest(pos: &BlockPos) -> bool {
+    pos.x() % 2 == 0
+}
+
+/// Entity ids of a pig in a boat
+const FAKE_BOAT: i32 = 200;
+const FAKE_RIDER: i32 = 201;
+
+/// Every block is a stairs block, to have some block state properties
+const FAKE_BLOCK_PROPERTIES: [(&str, &str); 4] = [
+    ("facing", "north"),
+    ("half", "bottom"),
+    ("shape", "straight"),
+    ("waterlogged", "false"),
+];
+
+/// Sea level of the fake terrain
+const FAKE_SEA_LEVEL: i32 = 62;
+
+/// Deterministic rolling hills so maps always look the same, with beaches and snowy peaks. Returns
+/// the type and y of the top block
+fn fake_column(x: i32, z: i32) -> (&'static str, i32) {
+    let wave = |v: i32, period: i32| (v.rem_euclid(period) - period / 2).abs();
+    let y = 56 + wave(x, 32) / 2 + wave(z, 24) / 2;
+
+    match y {
+        _ if y < FAKE_SEA_LEVEL => ("minecraft:water", FAKE_SEA_LEVEL),
+        _ if y <= FAKE_SEA_LEVEL + 1 => ("minecraft:sand", y),
+        _ if y >= 68 => ("minecraft:snow", y),
+        _ => ("minecraft:grass_block", y),
+    }
+}
+
+/// The chunks within this many chunks of the origin are loaded
+const FAKE_CHUNK_RADIUS: i32 = 1;
+
+fn is_fake_chunk_loaded(pos: &ColumnPos) -> bool {
+    pos.x().abs() <= FAKE_CHUNK_RADIUS && pos.z().abs() <= FAKE_CHUNK_RADIUS
+}
+
+/// Every entity has these status effects, as (effect, amplifier, duration in seconds, ambient,
+/// show particles)
+const FAKE_EFFECTS: [(&str, i32, i32, bool, bool); 2] = [
+    ("minecraft:speed", 1, 90, false, true),
+    ("minecraft:night_vision", 0, 600, true, false),
+];
+
+/// Every entity has these attributes, as (attribute, base value, value with modifiers)
+const FAKE_ATTRIBUTES: [(&str, f32, f32); 4] = [
+    ("minecraft:generic.max_health", 20.0, 20.0),
+    ("minecraft:generic.movement_speed", 0.1, 0.13),
+    ("minecraft:generic.attack_damage", 1.0, 8.0),
+    ("minecraft:generic.armor", 0.0, 15.0),
+];
+
+/// A few of the real game rules with their default values
+const FAKE_GAMERULES: [(&str, GameRuleType, i32); 4] = [
+    ("doDaylightCycle", GameRuleType::Bool, 1),
+    ("keepInventory", GameRuleType::Bool, 0),
+    ("randomTickSpeed", GameRuleType::Int, 3),
+    ("spawnRadius", GameRuleType::Int, 10),
+];
+
+/// Every entity's data, as the game prints it
+const FAKE_ENTITY_NBT: &str =
+    r#"{Air:300s,Health:10.0f,Pos:[100.0d,64.0d,205.2d],Tags:["fake"],UUID:[I;1,2,3,4]}"#;
+
+/// Scoreboard objective, as (name, criteria, score of each holder)
+type FakeObjective = (&'static str, &'static str, &'static [(&'static str, i32)]);
+
+const FAKE_OBJECTIVES: [FakeObjective; 2] = [
+    ("kills", "playerKillCount", &[("Steve", 3), ("Alex", 5)]),
+    ("deaths", "deathCount", &[]),
+];
+
+/// How often an event is pushed while subscribed
+const EVENT_INTERVAL: Duration = Duration::from_secs(2);
+
 fn target_entity(cmd: &Command) -> Result<i32, Error> {
     cmd.target_entity()
         .or_else(|| {
@@ -52,17 +154,195 @@ fn target_entity(cmd: &Command) -> Result<i32, Error> {
         })
 }
 
+fn target_slot(cmd: &Command) -> Result<i32, Error> {
+    cmd.target_slot().ok_or_else(|| {
+        warn!("missing target slot");
+        Error::MalformedRequest
+    })
+}
+
+fn target_loaded_chunk(cmd: &Command) -> Result<ColumnPos, Error> {
+    match cmd.target_chunk() {
+        Some(pos) if is_fake_chunk_loaded(pos) => Ok(*pos),
+        Some(_) => Err(Error::NoSuchChunk),
+        None => {
+            warn!("missing target chunk");
+            Err(Error::MalformedRequest)
+        }
+    }
+}
+
+fn target_effect(cmd: &Command) -> Result<(i32, i32, bool, bool), Error> {
+    target_entity(cmd)?;
+    let name = cmd.target_effect().ok_or_else(|| {
+        warn!("missing target effect");
+        Error::MalformedRequest
+    })?;
+
+    FAKE_EFFECTS
+        .iter()
+        .find(|(effect, ..)| *effect == name)
+        .map(|(_, amplifier, duration, ambient, particles)| {
+            (*amplifier, *duration, *ambient, *particles)
+        })
+        .ok_or(Error::BadInput)
+}
+
+fn target_attribute(cmd: &Command) -> Result<(f32, f32), Error> {
+    target_entity(cmd)?;
+    let name = cmd.target_attribute().ok_or_else(|| {
+        warn!("missing target attribute");
+        Error::MalformedRequest
+    })?;
+
+    FAKE_ATTRIBUTES
+        .iter()
+        .find(|(attribute, ..)| *attribute == name)
+        .map(|(_, base, value)| (*base, *value))
+        .ok_or(Error::BadInput)
+}
+
+fn target_objective(cmd: &Command) -> Result<FakeObjective, Error> {
+    let name = cmd.target_objective().ok_or_else(|| {
+        warn!("missing target objective");
+        Error::MalformedRequest
+    })?;
+
+    FAKE_OBJECTIVES
+        .iter()
+        .find(|(objective, ..)| *objective == name)
+        .copied()
+        .ok_or(Error::BadInput)
+}
+
+/// Sword in the first slot, every 3rd slot has some dirt and the rest are empty
+fn fake_slot_item(slot: i32) -> &'static str {
+    match slot {
+        0 => "minecraft:diamond_sword",
+        _ if slot % 3 == 0 => "minecraft:dirt",
+        _ => "minecraft:air",
+    }
+}
+
+/// Players have a full inventory, other living entities only have equipment slots
+fn fake_inventory(is_player: bool) -> InventoryDetailsArgs {
+    if is_player {
+        InventoryDetailsArgs {
+            size: 41,
+            selected: Some(0),
+            mainhand: Some(0),
+            offhand: Some(40),
+            head: Some(39),
+            chest: Some(38),
+            legs: Some(37),
+            feet: Some(36),
+        }
+    } else {
+        InventoryDetailsArgs {
+            size: 6,
+            selected: None,
+            mainhand: Some(0),
+            offhand: Some(1),
+            head: Some(5),
+            chest: Some(4),
+            legs: Some(3),
+            feet: Some(2),
+        }
+    }
+}
+
+fn push_event(
+    client: &mut ConnectedIpcClient,
+    buf: &mut FlatBufferBuilder,
+) -> Result<(), IpcError> {
+    let mut rand = thread_rng();
+    let block = BlockPos::new(rand.gen_range(-20..20), 64, rand.gen_range(-20..20));
+    let mut event = EventArgs {
+        world: Some(Dimension::Overworld),
+        entity: Some(rand.gen_range(1..100)),
+        ..EventArgs::default()
+    };
+
+    match rand.gen_range(0..4) {
+        0 => {
+            event.ty = EventType::Chat;
+            event.entity = Some(0);
+            event.message = Some(buf.create_string("hello"));
+        }
+        1 => {
+            event.ty = EventType::Damage;
+            event.amount = Some(rand.gen_range(1..10) as f32);
+            event.message = Some(buf.create_string("fall"));
+        }
+        2 => {
+            event.ty = EventType::EntitySpawn;
+            event.message = Some(buf.create_string("minecraft:cow"));
+        }
+        _ => {
+            event.ty = EventType::BlockBreak;
+            event.entity = Some(0);
+            event.block = Some(&block);
+            event.message = Some(buf.create_string("minecraft:dirt"));
+        }
+    }
+
+    let event = Event::create(buf, &event);
+    let root = GameResponse::create(
+        buf,
+        &GameResponseArgs {
+            body_type: GameResponseBody::Event,
+            body: Some(event.as_union_value()),
+        },
+    );
+    buf.finish(root, None);
+    let result = client.send_response(buf.finished_data());
+    buf.reset();
+    result
+}
+
 fn handle_client(mut client: ConnectedIpcClient) -> Result<(), Box<dyn StdError>> {
     let mut buf = FlatBufferBuilder::with_capacity(1024);
+    let mut subscribed = false;
+    let mut next_event = Instant::now();
     loop {
+        if subscribed {
+            let timeout = next_event.saturating_duration_since(Instant::now());
+            if timeout.is_zero() || !client.wait_for_request(timeout)? {
+                push_event(&mut client, &mut buf)?;
+                next_event = Instant::now() + EVENT_INTERVAL;
+                continue;
+            }
+        }
+
         let msg = client.recv()?;
         debug!("handling msg '{:?}'", msg);
 
         let resp_body_type;
-        let resp = if let Some(cmd) = msg.body_as_command() {
+        let resp = if let Some(sub) = msg.body_as_event_subscription() {
+            resp_body_type = GameResponseBody::Response;
+            debug!("event subscription: {}", sub.subscribe());
+            subscribed = sub.subscribe();
+            ClientResponse::Command(None)
+        } else if let Some(cmd) = msg.body_as_command() {
             resp_body_type = GameResponseBody::Response;
 
-            if cmd.write().is_some() {
+            if cmd.cmd() == CommandType::WorldFill {
+                // pretend the whole region changed
+                let region = cmd.write().and_then(|w| w.string()).and_then(|input| {
+                    let coords = input
+                        .split(' ')
+                        .take(6)
+                        .map(|c| c.parse::<i32>().ok())
+                        .collect::<Option<Vec<_>>>()?;
+                    (coords.len() == 6).then_some(coords)
+                });
+                ClientResponse::Command(Some(match region {
+                    Some(c) => ClientCommandResponse::Int(
+                        (0..3).map(|i| (c[i] - c[i + 3]).abs() + 1).product(),
+                    ),
+                    None => ClientCommandResponse::Error(Error::MalformedRequest),
+                }))
+            } else if cmd.write().is_some() {
                 ClientResponse::Command(None)
             } else {
                 ClientResponse::Command(Some(match cmd.cmd() {
@@ -71,17 +351,190 @@ fn handle_client(mut client: ConnectedIpcClient) -> Result<(), Box<dyn StdError>
                     CommandType::EntityPosition => {
                         ClientCommandResponse::Vec(Vec3::new(100.0, 64.0, 205.2))
                     }
+                    CommandType::EntityRotation => {
+                        ClientCommandResponse::Rotation(Rotation::new(-90.0, 12.5))
+                    }
+                    CommandType::EntityVelocity => {
+                        ClientCommandResponse::Vec(Vec3::new(0.0, -0.0784, 0.0))
+                    }
+                    CommandType::EntityOnGround => ClientCommandResponse::Bool(true),
+                    CommandType::EntityEyePosition => {
+                        ClientCommandResponse::Vec(Vec3::new(100.0, 65.62, 205.2))
+                    }
+                    CommandType::EntityNbt => match target_entity(&cmd) {
+                        Ok(_) => ClientCommandResponse::String(FAKE_ENTITY_NBT.into()),
+                        Err(err) => ClientCommandResponse::Error(err),
+                    },
+                    CommandType::EntityCustomName => match target_entity(&cmd) {
+                        Ok(FAKE_BOAT) => {
+                            ClientCommandResponse::String(r#"{"text":"Boaty"}"#.into())
+                        }
+                        Ok(_) => ClientCommandResponse::String("".into()),
+                        Err(err) => ClientCommandResponse::Error(err),
+                    },
+                    CommandType::EntityTags => {
+                        ClientCommandResponse::String("fake\nfriendly\n".into())
+                    }
+                    CommandType::EntityGlowing | CommandType::EntitySilent => {
+                        ClientCommandResponse::Bool(false)
+                    }
+                    CommandType::EntityNoAi => ClientCommandResponse::Bool(true),
+                    CommandType::EntityTeam => ClientCommandResponse::String("blue".into()),
                     CommandType::EntityHealth => match target_entity(&cmd) {
                         Ok(_) => ClientCommandResponse::Float(10.0),
                         Err(err) => ClientCommandResponse::Error(err),
                     },
                     CommandType::BlockType => {
-                        ClientCommandResponse::String("minecraft:dirt".into())
+                        ClientCommandResponse::String("minecraft:oak_stairs".into())
+                    }
+                    CommandType::BlockNbt => match cmd.target_block() {
+                        Some(pos) if is_fake_chest(pos) => {
+                            ClientCommandResponse::String("{Items:[]}".into())
+                        }
+                        Some(_) => ClientCommandResponse::Error(Error::NoSuchBlock),
+                        None => ClientCommandResponse::Error(Error::MalformedRequest),
+                    },
+                    CommandType::BlockProperty => {
+                        let value = cmd.target_property().map(|prop| {
+                            FAKE_BLOCK_PROPERTIES
+                                .iter()
+                                .find(|(name, _)| *name == prop)
+                                .map(|(_, value)| *value)
+                        });
+                        match value {
+                            Some(Some(value)) => ClientCommandResponse::String(value.into()),
+                            Some(None) => ClientCommandResponse::Error(Error::BadInput),
+                            None => ClientCommandResponse::Error(Error::MalformedRequest),
+                        }
                     }
                     CommandType::WorldTime => ClientCommandResponse::Int(500),
+                    CommandType::WorldWeather => ClientCommandResponse::String("rain 300".into()),
+                    CommandType::ServerDifficulty => {
+                        ClientCommandResponse::String("normal unlocked".into())
+                    }
+                    CommandType::ServerPvp => ClientCommandResponse::Bool(true),
+                    CommandType::ServerGameRule => {
+                        let rule = cmd.target_gamerule().map(|name| {
+                            FAKE_GAMERULES
+                                .iter()
+                                .find(|(rule, _, _)| *rule == name)
+                                .map(|(_, ty, value)| (*ty, *value))
+                        });
+                        match rule {
+                            Some(Some((GameRuleType::Bool, value))) => {
+                                ClientCommandResponse::Bool(value != 0)
+                            }
+                            Some(Some((_, value))) => ClientCommandResponse::Int(value),
+                            Some(None) => ClientCommandResponse::Error(Error::BadInput),
+                            None => ClientCommandResponse::Error(Error::MalformedRequest),
+                        }
+                    }
+                    CommandType::InventorySlotItem => match target_slot(&cmd) {
+                        Ok(slot) => ClientCommandResponse::String(fake_slot_item(slot).into()),
+                        Err(err) => ClientCommandResponse::Error(err),
+                    },
+                    CommandType::InventorySlotCount => match target_slot(&cmd) {
+                        Ok(slot) => ClientCommandResponse::Int(match fake_slot_item(slot) {
+                            "minecraft:air" => 0,
+                            "minecraft:dirt" => slot,
+                            _ => 1,
+                        }),
+                        Err(err) => ClientCommandResponse::Error(err),
+                    },
+                    CommandType::InventorySlotNbt => match target_slot(&cmd) {
+                        Ok(0) => ClientCommandResponse::String("{Damage:12}".into()),
+                        Ok(_) => ClientCommandResponse::String("".into()),
+                        Err(err) => ClientCommandResponse::Error(err),
+                    },
+                    CommandType::EntityEffectAmplifier => match target_effect(&cmd) {
+                        Ok((amplifier, ..)) => ClientCommandResponse::Int(amplifier),
+                        Err(err) => ClientCommandResponse::Error(err),
+                    },
+                    CommandType::EntityEffectDuration => match target_effect(&cmd) {
+                        Ok((_, duration, ..)) => ClientCommandResponse::Int(duration),
+                        Err(err) => ClientCommandResponse::Error(err),
+                    },
+                    CommandType::EntityEffectAmbient => match target_effect(&cmd) {
+                        Ok((_, _, ambient, _)) => ClientCommandResponse::Bool(ambient),
+                        Err(err) => ClientCommandResponse::Error(err),
+                    },
+                    CommandType::EntityEffectParticles => match target_effect(&cmd) {
+                        Ok((.., particles)) => ClientCommandResponse::Bool(particles),
+                        Err(err) => ClientCommandResponse::Error(err),
+                    },
+                    CommandType::EntityAttributeBase => match target_attribute(&cmd) {
+                        Ok((base, _)) => ClientCommandResponse::Float(base),
+                        Err(err) => ClientCommandResponse::Error(err),
+                    },
+                    CommandType::EntityAttributeValue => match target_attribute(&cmd) {
+                        Ok((_, value)) => ClientCommandResponse::Float(value),
+                        Err(err) => ClientCommandResponse::Error(err),
+                    },
+                    CommandType::ChunkLoaded => match cmd.target_chunk() {
+                        Some(pos) => ClientCommandResponse::Bool(is_fake_chunk_loaded(pos)),
+                        None => ClientCommandResponse::Error(Error::MalformedRequest),
+                    },
+                    // caves below the surface
+                    CommandType::ChunkBiomes => match target_loaded_chunk(&cmd) {
+                        Ok(_) => ClientCommandResponse::String(
+                            (-64..320)
+                                .step_by(16)
+                                .map(|y| {
+                                    let biome = if y < 48 {
+                                        "minecraft:dripstone_caves"
+                                    } else {
+                                        "minecraft:plains"
+                                    };
+                                    format!("{} {}\n", y, biome)
+                                })
+                                .collect::<String>()
+                                .into(),
+                        ),
+                        Err(err) => ClientCommandResponse::Error(err),
+                    },
+                    CommandType::ChunkInhabitedTime => match target_loaded_chunk(&cmd) {
+                        Ok(pos) if pos.x() == 0 && pos.z() == 0 => {
+                            ClientCommandResponse::Int(72000)
+                        }
+                        Ok(_) => ClientCommandResponse::Int(1200),
+                        Err(err) => ClientCommandResponse::Error(err),
+                    },
+                    CommandType::ScoreboardObjectiveCriteria => match target_objective(&cmd) {
+                        Ok((_, criteria, _)) => ClientCommandResponse::String(criteria.into()),
+                        Err(err) => ClientCommandResponse::Error(err),
+                    },
+                    CommandType::ScoreboardObjectiveDisplayName => match target_objective(&cmd) {
+                        Ok((name, ..)) => {
+                            let json = format!(r#"{{"text":"{}"}}"#, name);
+                            ClientCommandResponse::String(json.into())
+                        }
+                        Err(err) => ClientCommandResponse::Error(err),
+                    },
+                    CommandType::ScoreboardObjectiveDisplaySlot => match target_objective(&cmd) {
+                        Ok(("kills", ..)) => ClientCommandResponse::String("sidebar\n".into()),
+                        Ok(_) => ClientCommandResponse::String("".into()),
+                        Err(err) => ClientCommandResponse::Error(err),
+                    },
+                    CommandType::ScoreboardScore => {
+                        let score = target_objective(&cmd).and_then(|(_, _, scores)| {
+                            let holder =
+                                cmd.target_score_holder().ok_or(Error::MalformedRequest)?;
+                            scores
+                                .iter()
+                                .find(|(name, _)| *name == holder)
+                                .map(|(_, score)| *score)
+                                .ok_or(Error::BadInput)
+                        });
+                        match score {
+                            Ok(score) => ClientCommandResponse::Int(score),
+                            Err(err) => ClientCommandResponse::Error(err),
+                        }
+                    }
                     CommandType::ControlSay
                     | CommandType::ControlJump
-                    | CommandType::ControlMove => continue,
+                    | CommandType::ControlMove
+                    | CommandType::ChunkForceLoad
+                    | CommandType::EntityEffectRemove => continue,
                     _ => ClientCommandResponse::Error(Error::UnknownCommand),
                 }))
             }
@@ -89,6 +542,25 @@ fn handle_client(mut client: ConnectedIpcClient) -> Result<(), Box<dyn StdError>
             resp_body_type = GameResponseBody::StateResponse;
             ClientResponse::State {
                 target_block: req.target_world().and_then(|_| req.target_block().copied()),
+                inventory: if req.inventory() {
+                    Some(req.target_player_entity() || req.target_entity() == Some(0))
+                } else {
+                    None
+                },
+                effects: req.effects()
+                    && (req.target_player_entity() || req.target_entity().is_some()),
+                attributes: req.attributes()
+                    && (req.target_player_entity() || req.target_entity().is_some()),
+                entities_near: req.entities_near().copied().zip(req.entities_radius()),
+                gamerules: req.gamerules(),
+                region: req
+                    .target_world()
+                    .and(req.region_from().copied().zip(req.region_to().copied())),
+                columns: req
+                    .target_world()
+                    .and(req.columns_from().copied().zip(req.columns_to().copied())),
+                chunks: req.chunks() && req.target_world().is_some(),
+                objectives: req.objectives(),
             }
         } else {
             unreachable!("bad msg type") // TODO send error?
@@ -105,31 +577,263 @@ fn handle_client(mut client: ConnectedIpcClient) -> Result<(), Box<dyn StdError>
                         body.string = Some(buf.create_string(val));
                     }
                     Some(ClientCommandResponse::Vec(val)) => body.vec = Some(val),
+                    Some(ClientCommandResponse::Rotation(val)) => body.rotation = Some(val),
+                    Some(ClientCommandResponse::Bool(val)) => body.bool = Some(*val),
                     None => {}
                 }
                 Response::create(&mut buf, &body).as_union_value()
             }
             ClientResponse::State {
                 target_block: requested_block,
+                inventory,
+                effects,
+                attributes,
+                entities_near,
+                gamerules,
+                region,
+                columns,
+                chunks,
+                objectives,
             } => {
                 let block = requested_block.map(|block| {
-                    BlockDetails::create(&mut buf, &BlockDetailsArgs { pos: Some(&block) })
+                    let properties = FAKE_BLOCK_PROPERTIES
+                        .iter()
+                        .map(|(name, _)| buf.create_string(name))
+                        .collect::<Vec<_>>();
+                    let properties = buf.create_vector(&properties);
+                    BlockDetails::create(
+                        &mut buf,
+                        &BlockDetailsArgs {
+                            pos: Some(&block),
+                            properties: Some(properties),
+                            has_block_entity: is_fake_chest(&block),
+                            container_size: is_fake_chest(&block).then_some(FAKE_CHEST_SIZE),
+                        },
+                    )
+                });
+
+                let inventory = inventory.map(|is_player| {
+                    InventoryDetails::create(&mut buf, &fake_inventory(is_player))
+                });
+
+                let effects = effects.then(|| {
+                    let effects = FAKE_EFFECTS
+                        .iter()
+                        .map(|(name, ..)| buf.create_string(name))
+                        .collect::<Vec<_>>();
+                    buf.create_vector(&effects)
+                });
+
+                let attributes = attributes.then(|| {
+                    let attributes = FAKE_ATTRIBUTES
+                        .iter()
+                        .map(|(name, ..)| buf.create_string(name))
+                        .collect::<Vec<_>>();
+                    buf.create_vector(&attributes)
+                });
+
+                let gamerules = gamerules.then(|| {
+                    let rules = FAKE_GAMERULES
+                        .iter()
+                        .map(|(name, ty, _)| {
+                            let name = buf.create_string(name);
+                            GameRuleDetails::create(
+                                &mut buf,
+                                &GameRuleDetailsArgs {
+                                    name: Some(name),
+                                    ty: *ty,
+                                },
+                            )
+                        })
+                        .collect::<Vec<_>>();
+                    buf.create_vector(&rules)
+                });
+
+                // every block is the same stairs, as for single blocks
+                let region = region.map(|(from, to)| {
+                    let volume =
+                        (to.x() - from.x() + 1) * (to.y() - from.y() + 1) * (to.z() - from.z() + 1);
+                    let properties = FAKE_BLOCK_PROPERTIES
+                        .iter()
+                        .map(|(name, value)| format!("{}={}", name, value))
+                        .collect::<Vec<_>>();
+                    let state = format!("minecraft:oak_stairs[{}]", properties.join(","));
+                    let palette = [buf.create_string(&state)];
+                    let palette = buf.create_vector(&palette);
+                    let blocks = buf.create_vector(&vec![0u16; volume as usize]);
+                    RegionDetails::create(
+                        &mut buf,
+                        &RegionDetailsArgs {
+                            from: Some(&from),
+                            to: Some(&to),
+                            palette: Some(palette),
+                            blocks: Some(blocks),
+                        },
+                    )
+                });
+
+                let columns = columns.map(|(from, to)| {
+                    let (mut palette, mut tops, mut heights) = (Vec::new(), Vec::new(), Vec::new());
+                    for z in from.z()..=to.z() {
+                        for x in from.x()..=to.x() {
+                            let (ty, y) = fake_column(x, z);
+                            let idx = match palette.iter().position(|t| *t == ty) {
+                                Some(idx) => idx,
+                                None => {
+                                    palette.push(ty);
+                                    palette.len() - 1
+                                }
+                            };
+                            tops.push(idx as u16);
+                            heights.push(y as i16);
+                        }
+                    }
+
+                    let palette = palette
+                        .into_iter()
+                        .map(|ty| buf.create_string(ty))
+                        .collect::<Vec<_>>();
+                    let palette = buf.create_vector(&palette);
+                    let tops = buf.create_vector(&tops);
+                    let heights = buf.create_vector(&heights);
+                    ColumnDetails::create(
+                        &mut buf,
+                        &ColumnDetailsArgs {
+                            from: Some(&from),
+                            to: Some(&to),
+                            palette: Some(palette),
+                            tops: Some(tops),
+                            heights: Some(heights),
+                        },
+                    )
+                });
+
+                // the player and the pig in a boat are in the origin chunk
+                let chunks = chunks.then(|| {
+                    let range = -FAKE_CHUNK_RADIUS..=FAKE_CHUNK_RADIUS;
+                    let chunks = range
+                        .clone()
+                        .flat_map(|z| range.clone().map(move |x| (x, z)))
+                        .map(|(x, z)| {
+                            let entities = if (x, z) == (0, 0) {
+                                buf.create_vector(&[0, FAKE_BOAT, FAKE_RIDER])
+                            } else {
+                                buf.create_vector::<i32>(&[])
+                            };
+                            ChunkDetails::create(
+                                &mut buf,
+                                &ChunkDetailsArgs {
+                                    x,
+                                    z,
+                                    entities: Some(entities),
+                                },
+                            )
+                        })
+                        .collect::<Vec<_>>();
+                    buf.create_vector(&chunks)
+                });
+
+                let objectives = objectives.then(|| {
+                    let objectives = FAKE_OBJECTIVES
+                        .iter()
+                        .map(|(name, _, scores)| {
+                            let name = buf.create_string(name);
+                            let holders = scores
+                                .iter()
+                                .map(|(holder, _)| buf.create_string(holder))
+                                .collect::<Vec<_>>();
+                            let holders = buf.create_vector(&holders);
+                            ObjectiveDetails::create(
+                                &mut buf,
+                                &ObjectiveDetailsArgs {
+                                    name: Some(name),
+                                    holders: Some(holders),
+                                },
+                            )
+                        })
+                        .collect::<Vec<_>>();
+                    buf.create_vector(&objectives)
                 });
 
                 let mut rand = thread_rng();
                 let n = rand.gen_range(3..10);
-                let entities =
-                    once(EntityDetails::new(0, true))
-                        .chain((1usize..n).map(|_| {
-                            EntityDetails::new(rand.gen_range(1..100), rand.gen_bool(0.5))
-                        }))
-                        .collect::<Vec<_>>();
+                let entities = once((0, true, "minecraft:player", Vec3::new(0.5, 64.0, 0.5)))
+                    .chain((1usize..n).map(|_| {
+                        let living = rand.gen_bool(0.5);
+                        let ty = if living {
+                            ["minecraft:cow", "minecraft:pig", "minecraft:zombie"]
+                        } else {
+                            ["minecraft:item", "minecraft:arrow", "minecraft:boat"]
+                        }[rand.gen_range(0..3)];
+                        let pos = Vec3::new(
+                            rand.gen_range(-30.0..30.0),
+                            rand.gen_range(60.0..70.0),
+                            rand.gen_range(-30.0..30.0),
+                        );
+                        (rand.gen_range(1..100), living, ty, pos)
+                    }))
+                    .chain([
+                        (
+                            FAKE_BOAT,
+                            false,
+                            "minecraft:boat",
+                            Vec3::new(5.5, 62.5, 5.5),
+                        ),
+                        (FAKE_RIDER, true, "minecraft:pig", Vec3::new(5.5, 62.9, 5.5)),
+                    ])
+                    .filter(|(_, _, _, pos)| match entities_near {
+                        Some((centre, radius)) => {
+                            let (dx, dy, dz) = (
+                                pos.x() - centre.x(),
+                                pos.y() - centre.y(),
+                                pos.z() - centre.z(),
+                            );
+                            dx * dx + dy * dy + dz * dz <= (radius * radius) as f64
+                        }
+                        None => true,
+                    })
+                    .collect::<Vec<_>>();
+
+                let entities = entities
+                    .into_iter()
+                    .map(|(id, living, ty, pos)| {
+                        let ty = buf.create_string(ty);
+                        let passengers = if id == FAKE_BOAT {
+                            Some(buf.create_vector(&[FAKE_RIDER]))
+                        } else {
+                            None
+                        };
+                        EntityDetails::create(
+                            &mut buf,
+                            &EntityDetailsArgs {
+                                id,
+                                living,
+                                ty: Some(ty),
+                                pos: Some(&pos),
+                                vehicle: if id == FAKE_RIDER {
+                                    Some(FAKE_BOAT)
+                                } else {
+                                    None
+                                },
+                                passengers,
+                            },
+                        )
+                    })
+                    .collect::<Vec<_>>();
 
                 let state = StateResponseArgs {
                     player_entity_id: Some(0),
                     player_world: Some(Dimension::Overworld),
-                    entities: Some(buf.create_vector_direct(&entities)),
+                    entities: Some(buf.create_vector(&entities)),
                     block,
+                    inventory,
+                    effects,
+                    attributes,
+                    gamerules,
+                    region,
+                    columns,
+                    chunks,
+                    objectives,
                 };
                 StateResponse::create(&mut buf, &state).as_union_value()
             }
@@ -176,3 +880,30 @@ fn main() -> Result<(), Box<dyn StdError>> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fake_terrain_is_stable() {
+        // maps rendered against the test client are compared byte for byte, so this can't drift
+        let row = (0..8).map(|x| fake_column(x * 4, 0)).collect::<Vec<_>>();
+        assert_eq!(
+            row,
+            [
+                ("minecraft:snow", 70),
+                ("minecraft:snow", 68),
+                ("minecraft:grass_block", 66),
+                ("minecraft:grass_block", 64),
+                ("minecraft:sand", 62),
+                ("minecraft:grass_block", 64),
+                ("minecraft:grass_block", 66),
+                ("minecraft:snow", 68),
+            ]
+        );
+
+        assert_eq!(fake_column(16, 12), ("minecraft:water", FAKE_SEA_LEVEL));
+        assert_eq!(fake_column(-16, -12), fake_column(16, 12));
+    }
+}